@@ -21,6 +21,14 @@ mod tme;
 #[unstable(feature = "stdarch_aarch64_tme", issue = "117216")]
 pub use self::tme::*;
 
+mod sve;
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub use self::sve::*;
+
+mod sme;
+#[unstable(feature = "stdarch_aarch64_sme", issue = "none")]
+pub use self::sme::*;
+
 mod prefetch;
 #[unstable(feature = "stdarch_aarch64_prefetch", issue = "117217")]
 pub use self::prefetch::*;
@@ -17268,6 +17268,1181 @@ pub unsafe fn vsrid_n_u64<const N: i32>(a: u64, b: u64) -> u64 {
     transmute(vsri_n_u64::<N>(transmute(a), transmute(b)))
 }
 
+/// Floating-point divide
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vdiv_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fdiv))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vdiv_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    simd_div(a, b)
+}
+
+/// Floating-point divide
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vdivq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fdiv))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vdivq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    simd_div(a, b)
+}
+
+/// Floating-point multiply extended
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vmulx_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmulx))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vmulx_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fmulx.v4f16")]
+        fn vmulx_f16_(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+    }
+    vmulx_f16_(a, b)
+}
+
+/// Floating-point multiply extended
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vmulxq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmulx))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vmulxq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fmulx.v8f16")]
+        fn vmulxq_f16_(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+    }
+    vmulxq_f16_(a, b)
+}
+
+/// Floating-point square root
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vsqrt_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fsqrt))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vsqrt_f16(a: float16x4_t) -> float16x4_t {
+    simd_fsqrt(a)
+}
+
+/// Floating-point square root
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vsqrtq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fsqrt))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vsqrtq_f16(a: float16x8_t) -> float16x8_t {
+    simd_fsqrt(a)
+}
+
+/// Floating-point fused multiply-add to accumulator
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfma_n_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmla))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfma_n_f16(a: float16x4_t, b: float16x4_t, c: f16) -> float16x4_t {
+    vfma_f16(a, b, vdup_n_f16(c))
+}
+
+/// Floating-point fused multiply-add to accumulator
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmaq_n_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmla))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmaq_n_f16(a: float16x8_t, b: float16x8_t, c: f16) -> float16x8_t {
+    vfmaq_f16(a, b, vdupq_n_f16(c))
+}
+
+/// Floating-point fused multiply-subtract from accumulator
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfms_n_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmls))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfms_n_f16(a: float16x4_t, b: float16x4_t, c: f16) -> float16x4_t {
+    vfms_f16(a, b, vdup_n_f16(c))
+}
+
+/// Floating-point fused multiply-subtract from accumulator
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmsq_n_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmls))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmsq_n_f16(a: float16x8_t, b: float16x8_t, c: f16) -> float16x8_t {
+    vfmsq_f16(a, b, vdupq_n_f16(c))
+}
+
+/// Floating-point fused multiply-add to accumulator
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfma_lane_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmla, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfma_lane_f16<const LANE: i32>(a: float16x4_t, b: float16x4_t, c: float16x4_t) -> float16x4_t {
+    static_assert_uimm_bits!(LANE, 2);
+    vfma_f16(a, b, vdup_n_f16(simd_extract!(c, LANE as u32)))
+}
+
+/// Floating-point fused multiply-add to accumulator
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfma_laneq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmla, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfma_laneq_f16<const LANE: i32>(a: float16x4_t, b: float16x4_t, c: float16x8_t) -> float16x4_t {
+    static_assert_uimm_bits!(LANE, 3);
+    vfma_f16(a, b, vdup_n_f16(simd_extract!(c, LANE as u32)))
+}
+
+/// Floating-point fused multiply-add to accumulator
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmaq_lane_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmla, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmaq_lane_f16<const LANE: i32>(a: float16x8_t, b: float16x8_t, c: float16x4_t) -> float16x8_t {
+    static_assert_uimm_bits!(LANE, 2);
+    vfmaq_f16(a, b, vdupq_n_f16(simd_extract!(c, LANE as u32)))
+}
+
+/// Floating-point fused multiply-add to accumulator
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmaq_laneq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmla, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmaq_laneq_f16<const LANE: i32>(a: float16x8_t, b: float16x8_t, c: float16x8_t) -> float16x8_t {
+    static_assert_uimm_bits!(LANE, 3);
+    vfmaq_f16(a, b, vdupq_n_f16(simd_extract!(c, LANE as u32)))
+}
+
+/// Floating-point fused multiply-subtract to accumulator
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfms_lane_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmls, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfms_lane_f16<const LANE: i32>(a: float16x4_t, b: float16x4_t, c: float16x4_t) -> float16x4_t {
+    static_assert_uimm_bits!(LANE, 2);
+    vfms_f16(a, b, vdup_n_f16(simd_extract!(c, LANE as u32)))
+}
+
+/// Floating-point fused multiply-subtract to accumulator
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfms_laneq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmls, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfms_laneq_f16<const LANE: i32>(a: float16x4_t, b: float16x4_t, c: float16x8_t) -> float16x4_t {
+    static_assert_uimm_bits!(LANE, 3);
+    vfms_f16(a, b, vdup_n_f16(simd_extract!(c, LANE as u32)))
+}
+
+/// Floating-point fused multiply-subtract to accumulator
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmsq_lane_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmls, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmsq_lane_f16<const LANE: i32>(a: float16x8_t, b: float16x8_t, c: float16x4_t) -> float16x8_t {
+    static_assert_uimm_bits!(LANE, 2);
+    vfmsq_f16(a, b, vdupq_n_f16(simd_extract!(c, LANE as u32)))
+}
+
+/// Floating-point fused multiply-subtract to accumulator
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmsq_laneq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmls, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmsq_laneq_f16<const LANE: i32>(a: float16x8_t, b: float16x8_t, c: float16x8_t) -> float16x8_t {
+    static_assert_uimm_bits!(LANE, 3);
+    vfmsq_f16(a, b, vdupq_n_f16(simd_extract!(c, LANE as u32)))
+}
+
+/// Floating-point convert to lower precision narrow
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcvt_high_f16_f32)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fcvtn))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vcvt_high_f16_f32(a: float16x4_t, b: float32x4_t) -> float16x8_t {
+    simd_shuffle!(a, simd_cast(b), [0, 1, 2, 3, 4, 5, 6, 7])
+}
+
+/// Floating-point convert to higher precision long
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcvt_high_f32_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fcvtl))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vcvt_high_f32_f16(a: float16x8_t) -> float32x4_t {
+    let b: float16x4_t = simd_shuffle!(a, a, [4, 5, 6, 7]);
+    simd_cast(b)
+}
+
+/// Floating-point round to integral, toward zero
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vrnd_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(frintz))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vrnd_f16(a: float16x4_t) -> float16x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.trunc.v4f16")]
+        fn vrnd_f16_(a: float16x4_t) -> float16x4_t;
+    }
+    vrnd_f16_(a)
+}
+
+/// Floating-point round to integral, toward zero
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vrndq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(frintz))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vrndq_f16(a: float16x8_t) -> float16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.trunc.v8f16")]
+        fn vrndq_f16_(a: float16x8_t) -> float16x8_t;
+    }
+    vrndq_f16_(a)
+}
+
+/// Floating-point round to integral, to nearest with ties to away
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vrnda_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(frinta))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vrnda_f16(a: float16x4_t) -> float16x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.round.v4f16")]
+        fn vrnda_f16_(a: float16x4_t) -> float16x4_t;
+    }
+    vrnda_f16_(a)
+}
+
+/// Floating-point round to integral, to nearest with ties to away
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vrndaq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(frinta))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vrndaq_f16(a: float16x8_t) -> float16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.round.v8f16")]
+        fn vrndaq_f16_(a: float16x8_t) -> float16x8_t;
+    }
+    vrndaq_f16_(a)
+}
+
+/// Floating-point round to integral, toward minus infinity
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vrndm_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(frintm))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vrndm_f16(a: float16x4_t) -> float16x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.floor.v4f16")]
+        fn vrndm_f16_(a: float16x4_t) -> float16x4_t;
+    }
+    vrndm_f16_(a)
+}
+
+/// Floating-point round to integral, toward minus infinity
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vrndmq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(frintm))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vrndmq_f16(a: float16x8_t) -> float16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.floor.v8f16")]
+        fn vrndmq_f16_(a: float16x8_t) -> float16x8_t;
+    }
+    vrndmq_f16_(a)
+}
+
+/// Floating-point round to integral, toward plus infinity
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vrndp_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(frintp))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vrndp_f16(a: float16x4_t) -> float16x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.ceil.v4f16")]
+        fn vrndp_f16_(a: float16x4_t) -> float16x4_t;
+    }
+    vrndp_f16_(a)
+}
+
+/// Floating-point round to integral, toward plus infinity
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vrndpq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(frintp))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vrndpq_f16(a: float16x8_t) -> float16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.ceil.v8f16")]
+        fn vrndpq_f16_(a: float16x8_t) -> float16x8_t;
+    }
+    vrndpq_f16_(a)
+}
+
+/// Floating-point round to integral exact, using current rounding mode
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vrndx_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(frintx))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vrndx_f16(a: float16x4_t) -> float16x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.rint.v4f16")]
+        fn vrndx_f16_(a: float16x4_t) -> float16x4_t;
+    }
+    vrndx_f16_(a)
+}
+
+/// Floating-point round to integral exact, using current rounding mode
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vrndxq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(frintx))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vrndxq_f16(a: float16x8_t) -> float16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.rint.v8f16")]
+        fn vrndxq_f16_(a: float16x8_t) -> float16x8_t;
+    }
+    vrndxq_f16_(a)
+}
+
+/// Floating-point round to integral, using current rounding mode
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vrndi_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(frinti))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vrndi_f16(a: float16x4_t) -> float16x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.nearbyint.v4f16")]
+        fn vrndi_f16_(a: float16x4_t) -> float16x4_t;
+    }
+    vrndi_f16_(a)
+}
+
+/// Floating-point round to integral, using current rounding mode
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vrndiq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(frinti))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vrndiq_f16(a: float16x8_t) -> float16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.nearbyint.v8f16")]
+        fn vrndiq_f16_(a: float16x8_t) -> float16x8_t;
+    }
+    vrndiq_f16_(a)
+}
+
+/// Floating-point add pairwise
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vpaddq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(faddp))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vpaddq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.faddp.v8f16")]
+        fn vpaddq_f16_(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+    }
+    vpaddq_f16_(a, b)
+}
+
+/// Floating-point maximum pairwise
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vpmaxq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmaxp))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vpmaxq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fmaxp.v8f16")]
+        fn vpmaxq_f16_(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+    }
+    vpmaxq_f16_(a, b)
+}
+
+/// Floating-point minimum pairwise
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vpminq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fminp))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vpminq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fminp.v8f16")]
+        fn vpminq_f16_(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+    }
+    vpminq_f16_(a, b)
+}
+
+/// Floating-point maximum number pairwise
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vpmaxnmq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmaxnmp))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vpmaxnmq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fmaxnmp.v8f16")]
+        fn vpmaxnmq_f16_(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+    }
+    vpmaxnmq_f16_(a, b)
+}
+
+/// Floating-point minimum number pairwise
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vpminnmq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fminnmp))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vpminnmq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fminnmp.v8f16")]
+        fn vpminnmq_f16_(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+    }
+    vpminnmq_f16_(a, b)
+}
+
+/// Floating-point maximum across vector
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vmaxv_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmaxv))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vmaxv_f16(a: float16x4_t) -> f16 {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fmaxv.f16.v4f16")]
+        fn vmaxv_f16_(a: float16x4_t) -> f16;
+    }
+    vmaxv_f16_(a)
+}
+
+/// Floating-point maximum across vector
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vmaxvq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmaxv))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vmaxvq_f16(a: float16x8_t) -> f16 {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fmaxv.f16.v8f16")]
+        fn vmaxvq_f16_(a: float16x8_t) -> f16;
+    }
+    vmaxvq_f16_(a)
+}
+
+/// Floating-point minimum across vector
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vminv_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fminv))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vminv_f16(a: float16x4_t) -> f16 {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fminv.f16.v4f16")]
+        fn vminv_f16_(a: float16x4_t) -> f16;
+    }
+    vminv_f16_(a)
+}
+
+/// Floating-point minimum across vector
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vminvq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fminv))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vminvq_f16(a: float16x8_t) -> f16 {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fminv.f16.v8f16")]
+        fn vminvq_f16_(a: float16x8_t) -> f16;
+    }
+    vminvq_f16_(a)
+}
+
+/// Floating-point maximum number across vector
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vmaxnmv_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmaxnmv))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vmaxnmv_f16(a: float16x4_t) -> f16 {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fmaxnmv.f16.v4f16")]
+        fn vmaxnmv_f16_(a: float16x4_t) -> f16;
+    }
+    vmaxnmv_f16_(a)
+}
+
+/// Floating-point maximum number across vector
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vmaxnmvq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmaxnmv))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vmaxnmvq_f16(a: float16x8_t) -> f16 {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fmaxnmv.f16.v8f16")]
+        fn vmaxnmvq_f16_(a: float16x8_t) -> f16;
+    }
+    vmaxnmvq_f16_(a)
+}
+
+/// Floating-point minimum number across vector
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vminnmv_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fminnmv))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vminnmv_f16(a: float16x4_t) -> f16 {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fminnmv.f16.v4f16")]
+        fn vminnmv_f16_(a: float16x4_t) -> f16;
+    }
+    vminnmv_f16_(a)
+}
+
+/// Floating-point minimum number across vector
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vminnmvq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fminnmv))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vminnmvq_f16(a: float16x8_t) -> f16 {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fminnmv.f16.v8f16")]
+        fn vminnmvq_f16_(a: float16x8_t) -> f16;
+    }
+    vminnmvq_f16_(a)
+}
+
+/// Floating-point fused multiply-add long to accumulator (low part)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmlal_low_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmlal))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmlal_low_f16(a: float32x2_t, b: float16x4_t, c: float16x4_t) -> float32x2_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fmlal.v2f32.v4f16")]
+        fn vfmlal_low_f16_(a: float32x2_t, b: float16x4_t, c: float16x4_t) -> float32x2_t;
+    }
+    vfmlal_low_f16_(a, b, c)
+}
+
+/// Floating-point fused multiply-add long to accumulator (low part)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmlalq_low_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmlal))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmlalq_low_f16(a: float32x4_t, b: float16x8_t, c: float16x8_t) -> float32x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fmlal.v4f32.v8f16")]
+        fn vfmlalq_low_f16_(a: float32x4_t, b: float16x8_t, c: float16x8_t) -> float32x4_t;
+    }
+    vfmlalq_low_f16_(a, b, c)
+}
+
+/// Floating-point fused multiply-add long to accumulator (high part)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmlal_high_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmlal2))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmlal_high_f16(a: float32x2_t, b: float16x4_t, c: float16x4_t) -> float32x2_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fmlal2.v2f32.v4f16")]
+        fn vfmlal_high_f16_(a: float32x2_t, b: float16x4_t, c: float16x4_t) -> float32x2_t;
+    }
+    vfmlal_high_f16_(a, b, c)
+}
+
+/// Floating-point fused multiply-add long to accumulator (high part)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmlalq_high_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmlal2))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmlalq_high_f16(a: float32x4_t, b: float16x8_t, c: float16x8_t) -> float32x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fmlal2.v4f32.v8f16")]
+        fn vfmlalq_high_f16_(a: float32x4_t, b: float16x8_t, c: float16x8_t) -> float32x4_t;
+    }
+    vfmlalq_high_f16_(a, b, c)
+}
+
+/// Floating-point fused multiply-subtract long from accumulator (low part)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmlsl_low_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmlsl))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmlsl_low_f16(a: float32x2_t, b: float16x4_t, c: float16x4_t) -> float32x2_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fmlsl.v2f32.v4f16")]
+        fn vfmlsl_low_f16_(a: float32x2_t, b: float16x4_t, c: float16x4_t) -> float32x2_t;
+    }
+    vfmlsl_low_f16_(a, b, c)
+}
+
+/// Floating-point fused multiply-subtract long from accumulator (low part)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmlslq_low_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmlsl))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmlslq_low_f16(a: float32x4_t, b: float16x8_t, c: float16x8_t) -> float32x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fmlsl.v4f32.v8f16")]
+        fn vfmlslq_low_f16_(a: float32x4_t, b: float16x8_t, c: float16x8_t) -> float32x4_t;
+    }
+    vfmlslq_low_f16_(a, b, c)
+}
+
+/// Floating-point fused multiply-subtract long from accumulator (high part)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmlsl_high_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmlsl2))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmlsl_high_f16(a: float32x2_t, b: float16x4_t, c: float16x4_t) -> float32x2_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fmlsl2.v2f32.v4f16")]
+        fn vfmlsl_high_f16_(a: float32x2_t, b: float16x4_t, c: float16x4_t) -> float32x2_t;
+    }
+    vfmlsl_high_f16_(a, b, c)
+}
+
+/// Floating-point fused multiply-subtract long from accumulator (high part)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmlslq_high_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmlsl2))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmlslq_high_f16(a: float32x4_t, b: float16x8_t, c: float16x8_t) -> float32x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fmlsl2.v4f32.v8f16")]
+        fn vfmlslq_high_f16_(a: float32x4_t, b: float16x8_t, c: float16x8_t) -> float32x4_t;
+    }
+    vfmlslq_high_f16_(a, b, c)
+}
+
+/// Floating-point fused multiply-add long to accumulator (low part)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmlal_lane_low_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmlal, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmlal_lane_low_f16<const LANE: i32>(a: float32x2_t, b: float16x4_t, c: float16x4_t) -> float32x2_t {
+    static_assert_uimm_bits!(LANE, 2);
+    vfmlal_low_f16(a, b, vdup_n_f16(simd_extract!(c, LANE as u32)))
+}
+
+/// Floating-point fused multiply-add long to accumulator (low part)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmlal_laneq_low_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmlal, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmlal_laneq_low_f16<const LANE: i32>(a: float32x2_t, b: float16x4_t, c: float16x8_t) -> float32x2_t {
+    static_assert_uimm_bits!(LANE, 3);
+    vfmlal_low_f16(a, b, vdup_n_f16(simd_extract!(c, LANE as u32)))
+}
+
+/// Floating-point fused multiply-add long to accumulator (low part)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmlalq_lane_low_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmlal, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmlalq_lane_low_f16<const LANE: i32>(a: float32x4_t, b: float16x8_t, c: float16x4_t) -> float32x4_t {
+    static_assert_uimm_bits!(LANE, 2);
+    vfmlalq_low_f16(a, b, vdupq_n_f16(simd_extract!(c, LANE as u32)))
+}
+
+/// Floating-point fused multiply-add long to accumulator (low part)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmlalq_laneq_low_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmlal, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmlalq_laneq_low_f16<const LANE: i32>(a: float32x4_t, b: float16x8_t, c: float16x8_t) -> float32x4_t {
+    static_assert_uimm_bits!(LANE, 3);
+    vfmlalq_low_f16(a, b, vdupq_n_f16(simd_extract!(c, LANE as u32)))
+}
+
+/// Floating-point fused multiply-add long to accumulator (high part)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmlal_lane_high_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmlal2, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmlal_lane_high_f16<const LANE: i32>(a: float32x2_t, b: float16x4_t, c: float16x4_t) -> float32x2_t {
+    static_assert_uimm_bits!(LANE, 2);
+    vfmlal_high_f16(a, b, vdup_n_f16(simd_extract!(c, LANE as u32)))
+}
+
+/// Floating-point fused multiply-add long to accumulator (high part)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmlal_laneq_high_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmlal2, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmlal_laneq_high_f16<const LANE: i32>(a: float32x2_t, b: float16x4_t, c: float16x8_t) -> float32x2_t {
+    static_assert_uimm_bits!(LANE, 3);
+    vfmlal_high_f16(a, b, vdup_n_f16(simd_extract!(c, LANE as u32)))
+}
+
+/// Floating-point fused multiply-add long to accumulator (high part)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmlalq_lane_high_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmlal2, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmlalq_lane_high_f16<const LANE: i32>(a: float32x4_t, b: float16x8_t, c: float16x4_t) -> float32x4_t {
+    static_assert_uimm_bits!(LANE, 2);
+    vfmlalq_high_f16(a, b, vdupq_n_f16(simd_extract!(c, LANE as u32)))
+}
+
+/// Floating-point fused multiply-add long to accumulator (high part)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmlalq_laneq_high_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmlal2, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmlalq_laneq_high_f16<const LANE: i32>(a: float32x4_t, b: float16x8_t, c: float16x8_t) -> float32x4_t {
+    static_assert_uimm_bits!(LANE, 3);
+    vfmlalq_high_f16(a, b, vdupq_n_f16(simd_extract!(c, LANE as u32)))
+}
+
+/// Floating-point fused multiply-subtract long from accumulator (low part)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmlsl_lane_low_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmlsl, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmlsl_lane_low_f16<const LANE: i32>(a: float32x2_t, b: float16x4_t, c: float16x4_t) -> float32x2_t {
+    static_assert_uimm_bits!(LANE, 2);
+    vfmlsl_low_f16(a, b, vdup_n_f16(simd_extract!(c, LANE as u32)))
+}
+
+/// Floating-point fused multiply-subtract long from accumulator (low part)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmlsl_laneq_low_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmlsl, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmlsl_laneq_low_f16<const LANE: i32>(a: float32x2_t, b: float16x4_t, c: float16x8_t) -> float32x2_t {
+    static_assert_uimm_bits!(LANE, 3);
+    vfmlsl_low_f16(a, b, vdup_n_f16(simd_extract!(c, LANE as u32)))
+}
+
+/// Floating-point fused multiply-subtract long from accumulator (low part)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmlslq_lane_low_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmlsl, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmlslq_lane_low_f16<const LANE: i32>(a: float32x4_t, b: float16x8_t, c: float16x4_t) -> float32x4_t {
+    static_assert_uimm_bits!(LANE, 2);
+    vfmlslq_low_f16(a, b, vdupq_n_f16(simd_extract!(c, LANE as u32)))
+}
+
+/// Floating-point fused multiply-subtract long from accumulator (low part)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmlslq_laneq_low_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmlsl, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmlslq_laneq_low_f16<const LANE: i32>(a: float32x4_t, b: float16x8_t, c: float16x8_t) -> float32x4_t {
+    static_assert_uimm_bits!(LANE, 3);
+    vfmlslq_low_f16(a, b, vdupq_n_f16(simd_extract!(c, LANE as u32)))
+}
+
+/// Floating-point fused multiply-subtract long from accumulator (high part)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmlsl_lane_high_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmlsl2, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmlsl_lane_high_f16<const LANE: i32>(a: float32x2_t, b: float16x4_t, c: float16x4_t) -> float32x2_t {
+    static_assert_uimm_bits!(LANE, 2);
+    vfmlsl_high_f16(a, b, vdup_n_f16(simd_extract!(c, LANE as u32)))
+}
+
+/// Floating-point fused multiply-subtract long from accumulator (high part)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmlsl_laneq_high_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmlsl2, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmlsl_laneq_high_f16<const LANE: i32>(a: float32x2_t, b: float16x4_t, c: float16x8_t) -> float32x2_t {
+    static_assert_uimm_bits!(LANE, 3);
+    vfmlsl_high_f16(a, b, vdup_n_f16(simd_extract!(c, LANE as u32)))
+}
+
+/// Floating-point fused multiply-subtract long from accumulator (high part)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmlslq_lane_high_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmlsl2, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmlslq_lane_high_f16<const LANE: i32>(a: float32x4_t, b: float16x8_t, c: float16x4_t) -> float32x4_t {
+    static_assert_uimm_bits!(LANE, 2);
+    vfmlslq_high_f16(a, b, vdupq_n_f16(simd_extract!(c, LANE as u32)))
+}
+
+/// Floating-point fused multiply-subtract long from accumulator (high part)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmlslq_laneq_high_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(fmlsl2, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vfmlslq_laneq_high_f16<const LANE: i32>(a: float32x4_t, b: float16x8_t, c: float16x8_t) -> float32x4_t {
+    static_assert_uimm_bits!(LANE, 3);
+    vfmlslq_high_f16(a, b, vdupq_n_f16(simd_extract!(c, LANE as u32)))
+}
+
+/// BFloat16 floating-point dot product
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vbfdot_f32)
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(bfdot))]
+#[unstable(feature = "stdarch_neon_bf16", issue = "none")]
+pub unsafe fn vbfdot_f32(a: float32x2_t, b: bfloat16x4_t, c: bfloat16x4_t) -> float32x2_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.bfdot.v2f32.v4bf16")]
+        fn vbfdot_f32_(a: float32x2_t, b: bfloat16x4_t, c: bfloat16x4_t) -> float32x2_t;
+    }
+    vbfdot_f32_(a, b, c)
+}
+
+/// BFloat16 floating-point dot product
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vbfdotq_f32)
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(bfdot))]
+#[unstable(feature = "stdarch_neon_bf16", issue = "none")]
+pub unsafe fn vbfdotq_f32(a: float32x4_t, b: bfloat16x8_t, c: bfloat16x8_t) -> float32x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.bfdot.v4f32.v8bf16")]
+        fn vbfdotq_f32_(a: float32x4_t, b: bfloat16x8_t, c: bfloat16x8_t) -> float32x4_t;
+    }
+    vbfdotq_f32_(a, b, c)
+}
+
+/// BFloat16 floating-point dot product
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vbfdot_lane_f32)
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(bfdot, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_bf16", issue = "none")]
+pub unsafe fn vbfdot_lane_f32<const LANE: i32>(a: float32x2_t, b: bfloat16x4_t, c: bfloat16x4_t) -> float32x2_t {
+    static_assert_uimm_bits!(LANE, 1);
+    let c: float32x2_t = transmute(c);
+    let c: float32x2_t = simd_shuffle!(c, c, [LANE as u32, LANE as u32]);
+    vbfdot_f32(a, b, transmute(c))
+}
+
+/// BFloat16 floating-point dot product
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vbfdot_laneq_f32)
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(bfdot, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_bf16", issue = "none")]
+pub unsafe fn vbfdot_laneq_f32<const LANE: i32>(a: float32x2_t, b: bfloat16x4_t, c: bfloat16x8_t) -> float32x2_t {
+    static_assert_uimm_bits!(LANE, 2);
+    let c: float32x4_t = transmute(c);
+    let c: float32x2_t = simd_shuffle!(c, c, [LANE as u32, LANE as u32]);
+    vbfdot_f32(a, b, transmute(c))
+}
+
+/// BFloat16 floating-point dot product
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vbfdotq_lane_f32)
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(bfdot, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_bf16", issue = "none")]
+pub unsafe fn vbfdotq_lane_f32<const LANE: i32>(a: float32x4_t, b: bfloat16x8_t, c: bfloat16x4_t) -> float32x4_t {
+    static_assert_uimm_bits!(LANE, 1);
+    let c: float32x2_t = transmute(c);
+    let c: float32x4_t = simd_shuffle!(c, c, [LANE as u32, LANE as u32, LANE as u32, LANE as u32]);
+    vbfdotq_f32(a, b, transmute(c))
+}
+
+/// BFloat16 floating-point dot product
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vbfdotq_laneq_f32)
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(bfdot, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_bf16", issue = "none")]
+pub unsafe fn vbfdotq_laneq_f32<const LANE: i32>(a: float32x4_t, b: bfloat16x8_t, c: bfloat16x8_t) -> float32x4_t {
+    static_assert_uimm_bits!(LANE, 2);
+    let c: float32x4_t = transmute(c);
+    let c: float32x4_t = simd_shuffle!(c, c, [LANE as u32, LANE as u32, LANE as u32, LANE as u32]);
+    vbfdotq_f32(a, b, transmute(c))
+}
+
+/// BFloat16 floating-point matrix multiply-accumulate
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vbfmmlaq_f32)
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(bfmmla))]
+#[unstable(feature = "stdarch_neon_bf16", issue = "none")]
+pub unsafe fn vbfmmlaq_f32(a: float32x4_t, b: bfloat16x8_t, c: bfloat16x8_t) -> float32x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.bfmmla")]
+        fn vbfmmlaq_f32_(a: float32x4_t, b: bfloat16x8_t, c: bfloat16x8_t) -> float32x4_t;
+    }
+    vbfmmlaq_f32_(a, b, c)
+}
+
+/// BFloat16 floating-point widening multiply-add long (bottom)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vbfmlalbq_f32)
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(bfmlalb))]
+#[unstable(feature = "stdarch_neon_bf16", issue = "none")]
+pub unsafe fn vbfmlalbq_f32(a: float32x4_t, b: bfloat16x8_t, c: bfloat16x8_t) -> float32x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.bfmlalb")]
+        fn vbfmlalbq_f32_(a: float32x4_t, b: bfloat16x8_t, c: bfloat16x8_t) -> float32x4_t;
+    }
+    vbfmlalbq_f32_(a, b, c)
+}
+
+/// BFloat16 floating-point widening multiply-add long (top)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vbfmlaltq_f32)
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(bfmlalt))]
+#[unstable(feature = "stdarch_neon_bf16", issue = "none")]
+pub unsafe fn vbfmlaltq_f32(a: float32x4_t, b: bfloat16x8_t, c: bfloat16x8_t) -> float32x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.bfmlalt")]
+        fn vbfmlaltq_f32_(a: float32x4_t, b: bfloat16x8_t, c: bfloat16x8_t) -> float32x4_t;
+    }
+    vbfmlaltq_f32_(a, b, c)
+}
+
+/// BFloat16 floating-point widening multiply-add long (bottom)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vbfmlalbq_lane_f32)
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(bfmlalb, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_bf16", issue = "none")]
+pub unsafe fn vbfmlalbq_lane_f32<const LANE: i32>(a: float32x4_t, b: bfloat16x8_t, c: bfloat16x4_t) -> float32x4_t {
+    static_assert_uimm_bits!(LANE, 2);
+    vbfmlalbq_f32(a, b, simd_shuffle!(c, c, [LANE as u32, LANE as u32, LANE as u32, LANE as u32, LANE as u32, LANE as u32, LANE as u32, LANE as u32]))
+}
+
+/// BFloat16 floating-point widening multiply-add long (bottom)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vbfmlalbq_laneq_f32)
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(bfmlalb, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_bf16", issue = "none")]
+pub unsafe fn vbfmlalbq_laneq_f32<const LANE: i32>(a: float32x4_t, b: bfloat16x8_t, c: bfloat16x8_t) -> float32x4_t {
+    static_assert_uimm_bits!(LANE, 3);
+    vbfmlalbq_f32(a, b, simd_shuffle!(c, c, [LANE as u32, LANE as u32, LANE as u32, LANE as u32, LANE as u32, LANE as u32, LANE as u32, LANE as u32]))
+}
+
+/// BFloat16 floating-point widening multiply-add long (top)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vbfmlaltq_lane_f32)
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(bfmlalt, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_bf16", issue = "none")]
+pub unsafe fn vbfmlaltq_lane_f32<const LANE: i32>(a: float32x4_t, b: bfloat16x8_t, c: bfloat16x4_t) -> float32x4_t {
+    static_assert_uimm_bits!(LANE, 2);
+    vbfmlaltq_f32(a, b, simd_shuffle!(c, c, [LANE as u32, LANE as u32, LANE as u32, LANE as u32, LANE as u32, LANE as u32, LANE as u32, LANE as u32]))
+}
+
+/// BFloat16 floating-point widening multiply-add long (top)
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vbfmlaltq_laneq_f32)
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(bfmlalt, LANE = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_neon_bf16", issue = "none")]
+pub unsafe fn vbfmlaltq_laneq_f32<const LANE: i32>(a: float32x4_t, b: bfloat16x8_t, c: bfloat16x8_t) -> float32x4_t {
+    static_assert_uimm_bits!(LANE, 3);
+    vbfmlaltq_f32(a, b, simd_shuffle!(c, c, [LANE as u32, LANE as u32, LANE as u32, LANE as u32, LANE as u32, LANE as u32, LANE as u32, LANE as u32]))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -28657,4 +29832,768 @@ mod test {
         let r: u64 = vsrid_n_u64::<2>(a, b);
         assert_eq!(r, e);
     }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vdiv_f16() {
+        let a: f16x4 = f16x4::new(2.0, 6.0, 4.0, 10.0);
+        let b: f16x4 = f16x4::new(1.0, 2.0, 1.0, 2.0);
+        let e: f16x4 = f16x4::new(2.0, 3.0, 4.0, 5.0);
+        let r: f16x4 = transmute(vdiv_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vdivq_f16() {
+        let a: f16x8 = f16x8::new(2.0, 6.0, 4.0, 10.0, 12.0, 14.0, 16.0, 18.0);
+        let b: f16x8 = f16x8::new(1.0, 2.0, 1.0, 2.0, 4.0, 2.0, 8.0, 4.0);
+        let e: f16x8 = f16x8::new(2.0, 3.0, 4.0, 5.0, 3.0, 7.0, 2.0, 4.5);
+        let r: f16x8 = transmute(vdivq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmulx_f16() {
+        let a: f16x4 = f16x4::new(1.0, 2.0, 3.0, 4.0);
+        let b: f16x4 = f16x4::new(2.0, 2.0, 2.0, 2.0);
+        let e: f16x4 = f16x4::new(2.0, 4.0, 6.0, 8.0);
+        let r: f16x4 = transmute(vmulx_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmulxq_f16() {
+        let a: f16x8 = f16x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let b: f16x8 = f16x8::new(2.0, 2.0, 2.0, 2.0, 2.0, 2.0, 2.0, 2.0);
+        let e: f16x8 = f16x8::new(2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0, 16.0);
+        let r: f16x8 = transmute(vmulxq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vsqrt_f16() {
+        let a: f16x4 = f16x4::new(4.0, 9.0, 16.0, 25.0);
+        let e: f16x4 = f16x4::new(2.0, 3.0, 4.0, 5.0);
+        let r: f16x4 = transmute(vsqrt_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vsqrtq_f16() {
+        let a: f16x8 = f16x8::new(4.0, 9.0, 16.0, 25.0, 36.0, 49.0, 64.0, 81.0);
+        let e: f16x8 = f16x8::new(2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        let r: f16x8 = transmute(vsqrtq_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfma_n_f16() {
+        let a: f16x4 = f16x4::new(2.0, 3.0, 4.0, 5.0);
+        let b: f16x4 = f16x4::new(6.0, 4.0, 7.0, 8.0);
+        let c: f16 = 8.0;
+        let e: f16x4 = f16x4::new(50.0, 35.0, 60.0, 69.0);
+        let r: f16x4 = transmute(vfma_n_f16(transmute(a), transmute(b), c));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfmaq_n_f16() {
+        let a: f16x8 = f16x8::new(2.0, 3.0, 4.0, 5.0, 2.0, 3.0, 4.0, 5.0);
+        let b: f16x8 = f16x8::new(6.0, 4.0, 7.0, 8.0, 6.0, 4.0, 7.0, 8.0);
+        let c: f16 = 8.0;
+        let e: f16x8 = f16x8::new(50.0, 35.0, 60.0, 69.0, 50.0, 35.0, 60.0, 69.0);
+        let r: f16x8 = transmute(vfmaq_n_f16(transmute(a), transmute(b), c));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfms_n_f16() {
+        let a: f16x4 = f16x4::new(50.0, 35.0, 60.0, 69.0);
+        let b: f16x4 = f16x4::new(6.0, 4.0, 7.0, 8.0);
+        let c: f16 = 8.0;
+        let e: f16x4 = f16x4::new(2.0, 3.0, 4.0, 5.0);
+        let r: f16x4 = transmute(vfms_n_f16(transmute(a), transmute(b), c));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfmsq_n_f16() {
+        let a: f16x8 = f16x8::new(50.0, 35.0, 60.0, 69.0, 50.0, 35.0, 60.0, 69.0);
+        let b: f16x8 = f16x8::new(6.0, 4.0, 7.0, 8.0, 6.0, 4.0, 7.0, 8.0);
+        let c: f16 = 8.0;
+        let e: f16x8 = f16x8::new(2.0, 3.0, 4.0, 5.0, 2.0, 3.0, 4.0, 5.0);
+        let r: f16x8 = transmute(vfmsq_n_f16(transmute(a), transmute(b), c));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfma_lane_f16() {
+        let a: f16x4 = f16x4::new(2.0, 3.0, 4.0, 5.0);
+        let b: f16x4 = f16x4::new(6.0, 4.0, 7.0, 8.0);
+        let c: f16x4 = f16x4::new(2.0, 0.0, 0.0, 0.0);
+        let e: f16x4 = f16x4::new(14.0, 11.0, 18.0, 21.0);
+        let r: f16x4 = transmute(vfma_lane_f16::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfma_laneq_f16() {
+        let a: f16x4 = f16x4::new(2.0, 3.0, 4.0, 5.0);
+        let b: f16x4 = f16x4::new(6.0, 4.0, 7.0, 8.0);
+        let c: f16x8 = f16x8::new(2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        let e: f16x4 = f16x4::new(14.0, 11.0, 18.0, 21.0);
+        let r: f16x4 = transmute(vfma_laneq_f16::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfmaq_lane_f16() {
+        let a: f16x8 = f16x8::new(2.0, 3.0, 4.0, 5.0, 2.0, 3.0, 4.0, 5.0);
+        let b: f16x8 = f16x8::new(6.0, 4.0, 7.0, 8.0, 6.0, 4.0, 7.0, 8.0);
+        let c: f16x4 = f16x4::new(2.0, 0.0, 0.0, 0.0);
+        let e: f16x8 = f16x8::new(14.0, 11.0, 18.0, 21.0, 14.0, 11.0, 18.0, 21.0);
+        let r: f16x8 = transmute(vfmaq_lane_f16::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfmaq_laneq_f16() {
+        let a: f16x8 = f16x8::new(2.0, 3.0, 4.0, 5.0, 2.0, 3.0, 4.0, 5.0);
+        let b: f16x8 = f16x8::new(6.0, 4.0, 7.0, 8.0, 6.0, 4.0, 7.0, 8.0);
+        let c: f16x8 = f16x8::new(2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        let e: f16x8 = f16x8::new(14.0, 11.0, 18.0, 21.0, 14.0, 11.0, 18.0, 21.0);
+        let r: f16x8 = transmute(vfmaq_laneq_f16::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfms_lane_f16() {
+        let a: f16x4 = f16x4::new(14.0, 11.0, 18.0, 21.0);
+        let b: f16x4 = f16x4::new(6.0, 4.0, 7.0, 8.0);
+        let c: f16x4 = f16x4::new(2.0, 0.0, 0.0, 0.0);
+        let e: f16x4 = f16x4::new(2.0, 3.0, 4.0, 5.0);
+        let r: f16x4 = transmute(vfms_lane_f16::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfms_laneq_f16() {
+        let a: f16x4 = f16x4::new(14.0, 11.0, 18.0, 21.0);
+        let b: f16x4 = f16x4::new(6.0, 4.0, 7.0, 8.0);
+        let c: f16x8 = f16x8::new(2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        let e: f16x4 = f16x4::new(2.0, 3.0, 4.0, 5.0);
+        let r: f16x4 = transmute(vfms_laneq_f16::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfmsq_lane_f16() {
+        let a: f16x8 = f16x8::new(14.0, 11.0, 18.0, 21.0, 14.0, 11.0, 18.0, 21.0);
+        let b: f16x8 = f16x8::new(6.0, 4.0, 7.0, 8.0, 6.0, 4.0, 7.0, 8.0);
+        let c: f16x4 = f16x4::new(2.0, 0.0, 0.0, 0.0);
+        let e: f16x8 = f16x8::new(2.0, 3.0, 4.0, 5.0, 2.0, 3.0, 4.0, 5.0);
+        let r: f16x8 = transmute(vfmsq_lane_f16::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfmsq_laneq_f16() {
+        let a: f16x8 = f16x8::new(14.0, 11.0, 18.0, 21.0, 14.0, 11.0, 18.0, 21.0);
+        let b: f16x8 = f16x8::new(6.0, 4.0, 7.0, 8.0, 6.0, 4.0, 7.0, 8.0);
+        let c: f16x8 = f16x8::new(2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        let e: f16x8 = f16x8::new(2.0, 3.0, 4.0, 5.0, 2.0, 3.0, 4.0, 5.0);
+        let r: f16x8 = transmute(vfmsq_laneq_f16::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvt_high_f16_f32() {
+        let a: f16x4 = f16x4::new(-1.5, 1.5, 2.25, 3.75);
+        let b: f32x4 = f32x4::new(-2.5, 4.5, 6.25, 7.75);
+        let e: f16x8 = f16x8::new(-1.5, 1.5, 2.25, 3.75, -2.5, 4.5, 6.25, 7.75);
+        let r: f16x8 = transmute(vcvt_high_f16_f32(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvt_high_f32_f16() {
+        let a: f16x8 = f16x8::new(-1.5, 1.5, 2.25, 3.75, -2.5, 4.5, 6.25, 7.75);
+        let e: f32x4 = f32x4::new(-2.5, 4.5, 6.25, 7.75);
+        let r: f32x4 = transmute(vcvt_high_f32_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrnd_f16() {
+        let a: f16x4 = f16x4::new(-1.5, 0.5, 1.5, 2.5);
+        let e: f16x4 = f16x4::new(-1.0, 0.0, 1.0, 2.0);
+        let r: f16x4 = transmute(vrnd_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndq_f16() {
+        let a: f16x8 = f16x8::new(-1.5, 0.5, 1.5, 2.5, -2.5, 3.5, -0.5, 4.5);
+        let e: f16x8 = f16x8::new(-1.0, 0.0, 1.0, 2.0, -2.0, 3.0, -0.0, 4.0);
+        let r: f16x8 = transmute(vrndq_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrnda_f16() {
+        let a: f16x4 = f16x4::new(-1.5, 0.5, 1.5, 2.5);
+        let e: f16x4 = f16x4::new(-2.0, 1.0, 2.0, 3.0);
+        let r: f16x4 = transmute(vrnda_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndaq_f16() {
+        let a: f16x8 = f16x8::new(-1.5, 0.5, 1.5, 2.5, -2.5, 3.5, -0.5, 4.5);
+        let e: f16x8 = f16x8::new(-2.0, 1.0, 2.0, 3.0, -3.0, 4.0, -1.0, 5.0);
+        let r: f16x8 = transmute(vrndaq_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndm_f16() {
+        let a: f16x4 = f16x4::new(-1.5, 0.5, 1.5, 2.5);
+        let e: f16x4 = f16x4::new(-2.0, 0.0, 1.0, 2.0);
+        let r: f16x4 = transmute(vrndm_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndmq_f16() {
+        let a: f16x8 = f16x8::new(-1.5, 0.5, 1.5, 2.5, -2.5, 3.5, -0.5, 4.5);
+        let e: f16x8 = f16x8::new(-2.0, 0.0, 1.0, 2.0, -3.0, 3.0, -1.0, 4.0);
+        let r: f16x8 = transmute(vrndmq_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndp_f16() {
+        let a: f16x4 = f16x4::new(-1.5, 0.5, 1.5, 2.5);
+        let e: f16x4 = f16x4::new(-1.0, 1.0, 2.0, 3.0);
+        let r: f16x4 = transmute(vrndp_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndpq_f16() {
+        let a: f16x8 = f16x8::new(-1.5, 0.5, 1.5, 2.5, -2.5, 3.5, -0.5, 4.5);
+        let e: f16x8 = f16x8::new(-1.0, 1.0, 2.0, 3.0, -2.0, 4.0, -0.0, 5.0);
+        let r: f16x8 = transmute(vrndpq_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndx_f16() {
+        let a: f16x4 = f16x4::new(-1.5, 0.5, 1.5, 2.5);
+        let e: f16x4 = f16x4::new(-2.0, 0.0, 2.0, 2.0);
+        let r: f16x4 = transmute(vrndx_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndxq_f16() {
+        let a: f16x8 = f16x8::new(-1.5, 0.5, 1.5, 2.5, -2.5, 3.5, -0.5, 4.5);
+        let e: f16x8 = f16x8::new(-2.0, 0.0, 2.0, 2.0, -2.0, 4.0, -0.0, 4.0);
+        let r: f16x8 = transmute(vrndxq_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndi_f16() {
+        let a: f16x4 = f16x4::new(-1.5, 0.5, 1.5, 2.5);
+        let e: f16x4 = f16x4::new(-2.0, 0.0, 2.0, 2.0);
+        let r: f16x4 = transmute(vrndi_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndiq_f16() {
+        let a: f16x8 = f16x8::new(-1.5, 0.5, 1.5, 2.5, -2.5, 3.5, -0.5, 4.5);
+        let e: f16x8 = f16x8::new(-2.0, 0.0, 2.0, 2.0, -2.0, 4.0, -0.0, 4.0);
+        let r: f16x8 = transmute(vrndiq_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vpaddq_f16() {
+        let a: f16x8 = f16x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let b: f16x8 = f16x8::new(3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0);
+        let e: f16x8 = f16x8::new(3.0, 7.0, 11.0, 15.0, 7.0, 11.0, 15.0, 19.0);
+        let r: f16x8 = transmute(vpaddq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vpmaxq_f16() {
+        let a: f16x8 = f16x8::new(1.0, -2.0, 3.0, 4.0, 5.0, 6.0, -7.0, -8.0);
+        let b: f16x8 = f16x8::new(0.0, 3.0, -4.0, 8.0, 9.0, 1.0, 2.0, 2.5);
+        let e: f16x8 = f16x8::new(1.0, 4.0, 6.0, -7.0, 3.0, 8.0, 9.0, 2.5);
+        let r: f16x8 = transmute(vpmaxq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vpminq_f16() {
+        let a: f16x8 = f16x8::new(1.0, -2.0, 3.0, 4.0, 5.0, 6.0, -7.0, -8.0);
+        let b: f16x8 = f16x8::new(0.0, 3.0, -4.0, 8.0, 9.0, 1.0, 2.0, 2.5);
+        let e: f16x8 = f16x8::new(-2.0, 3.0, 5.0, -8.0, 0.0, -4.0, 1.0, 2.0);
+        let r: f16x8 = transmute(vpminq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vpmaxnmq_f16() {
+        let a: f16x8 = f16x8::new(1.0, 2.0, 3.0, -4.0, 5.0, 6.0, 7.0, -8.0);
+        let b: f16x8 = f16x8::new(8.0, 16.0, -1.0, 6.0, 4.0, 8.0, -2.0, 0.0);
+        let e: f16x8 = f16x8::new(2.0, 3.0, 16.0, 6.0, 6.0, 7.0, 8.0, 0.0);
+        let r: f16x8 = transmute(vpmaxnmq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vpminnmq_f16() {
+        let a: f16x8 = f16x8::new(1.0, 2.0, 3.0, -4.0, 5.0, 6.0, 7.0, -8.0);
+        let b: f16x8 = f16x8::new(8.0, 16.0, -1.0, 6.0, 4.0, 8.0, -2.0, 0.0);
+        let e: f16x8 = f16x8::new(1.0, -4.0, 5.0, -8.0, 8.0, -1.0, 4.0, -2.0);
+        let r: f16x8 = transmute(vpminnmq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmaxv_f16() {
+        let a: f16x4 = f16x4::new(1.0, 2.0, 0.0, 1.0);
+        let e: f16 = 2.0;
+        let r: f16 = vmaxv_f16(transmute(a));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmaxvq_f16() {
+        let a: f16x8 = f16x8::new(1.0, 2.0, 0.0, 1.0, 1.5, 0.5, 1.0, 0.25);
+        let e: f16 = 2.0;
+        let r: f16 = vmaxvq_f16(transmute(a));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vminv_f16() {
+        let a: f16x4 = f16x4::new(1.0, 2.0, 0.0, 1.0);
+        let e: f16 = 0.0;
+        let r: f16 = vminv_f16(transmute(a));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vminvq_f16() {
+        let a: f16x8 = f16x8::new(1.0, 2.0, 0.0, 1.0, 1.5, 0.5, 1.0, 0.25);
+        let e: f16 = 0.0;
+        let r: f16 = vminvq_f16(transmute(a));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmaxnmv_f16() {
+        let a: f16x4 = f16x4::new(1.0, 2.0, 0.0, 1.0);
+        let e: f16 = 2.0;
+        let r: f16 = vmaxnmv_f16(transmute(a));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmaxnmvq_f16() {
+        let a: f16x8 = f16x8::new(1.0, 2.0, 0.0, 1.0, 1.5, 0.5, 1.0, 0.25);
+        let e: f16 = 2.0;
+        let r: f16 = vmaxnmvq_f16(transmute(a));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vminnmv_f16() {
+        let a: f16x4 = f16x4::new(1.0, 2.0, 0.0, 1.0);
+        let e: f16 = 0.0;
+        let r: f16 = vminnmv_f16(transmute(a));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vminnmvq_f16() {
+        let a: f16x8 = f16x8::new(1.0, 2.0, 0.0, 1.0, 1.5, 0.5, 1.0, 0.25);
+        let e: f16 = 0.0;
+        let r: f16 = vminnmvq_f16(transmute(a));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlal_low_f16() {
+        let a: f32x2 = f32x2::new(1.0, 1.0);
+        let b: f16x4 = f16x4::new(1.0, 2.0, 3.0, 4.0);
+        let c: f16x4 = f16x4::new(2.0, 2.0, 2.0, 2.0);
+        let e: f32x2 = f32x2::new(3.0, 5.0);
+        let r: f32x2 = transmute(vfmlal_low_f16(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlalq_low_f16() {
+        let a: f32x4 = f32x4::new(1.0, 1.0, 1.0, 1.0);
+        let b: f16x8 = f16x8::new(1.0, 2.0, 3.0, 4.0, 3.0, 4.0, 5.0, 6.0);
+        let c: f16x8 = f16x8::new(2.0, 2.0, 2.0, 2.0, 2.0, 2.0, 2.0, 2.0);
+        let e: f32x4 = f32x4::new(3.0, 5.0, 7.0, 9.0);
+        let r: f32x4 = transmute(vfmlalq_low_f16(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlal_high_f16() {
+        let a: f32x2 = f32x2::new(1.0, 1.0);
+        let b: f16x4 = f16x4::new(1.0, 2.0, 3.0, 4.0);
+        let c: f16x4 = f16x4::new(2.0, 2.0, 2.0, 2.0);
+        let e: f32x2 = f32x2::new(7.0, 9.0);
+        let r: f32x2 = transmute(vfmlal_high_f16(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlalq_high_f16() {
+        let a: f32x4 = f32x4::new(1.0, 1.0, 1.0, 1.0);
+        let b: f16x8 = f16x8::new(1.0, 2.0, 3.0, 4.0, 3.0, 4.0, 5.0, 6.0);
+        let c: f16x8 = f16x8::new(2.0, 2.0, 2.0, 2.0, 2.0, 2.0, 2.0, 2.0);
+        let e: f32x4 = f32x4::new(7.0, 9.0, 11.0, 13.0);
+        let r: f32x4 = transmute(vfmlalq_high_f16(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlsl_low_f16() {
+        let a: f32x2 = f32x2::new(10.0, 10.0);
+        let b: f16x4 = f16x4::new(1.0, 2.0, 3.0, 4.0);
+        let c: f16x4 = f16x4::new(2.0, 2.0, 2.0, 2.0);
+        let e: f32x2 = f32x2::new(8.0, 6.0);
+        let r: f32x2 = transmute(vfmlsl_low_f16(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlslq_low_f16() {
+        let a: f32x4 = f32x4::new(10.0, 10.0, 10.0, 10.0);
+        let b: f16x8 = f16x8::new(1.0, 2.0, 3.0, 4.0, 3.0, 4.0, 5.0, 6.0);
+        let c: f16x8 = f16x8::new(2.0, 2.0, 2.0, 2.0, 2.0, 2.0, 2.0, 2.0);
+        let e: f32x4 = f32x4::new(8.0, 6.0, 4.0, 2.0);
+        let r: f32x4 = transmute(vfmlslq_low_f16(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlsl_high_f16() {
+        let a: f32x2 = f32x2::new(10.0, 10.0);
+        let b: f16x4 = f16x4::new(1.0, 2.0, 3.0, 4.0);
+        let c: f16x4 = f16x4::new(2.0, 2.0, 2.0, 2.0);
+        let e: f32x2 = f32x2::new(4.0, 2.0);
+        let r: f32x2 = transmute(vfmlsl_high_f16(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlslq_high_f16() {
+        let a: f32x4 = f32x4::new(10.0, 10.0, 10.0, 10.0);
+        let b: f16x8 = f16x8::new(1.0, 2.0, 3.0, 4.0, 3.0, 4.0, 5.0, 6.0);
+        let c: f16x8 = f16x8::new(2.0, 2.0, 2.0, 2.0, 2.0, 2.0, 2.0, 2.0);
+        let e: f32x4 = f32x4::new(4.0, 2.0, 0.0, -2.0);
+        let r: f32x4 = transmute(vfmlslq_high_f16(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlal_lane_low_f16() {
+        let a: f32x2 = f32x2::new(1.0, 1.0);
+        let b: f16x4 = f16x4::new(1.0, 2.0, 3.0, 4.0);
+        let c: f16x4 = f16x4::new(2.0, 0.0, 0.0, 0.0);
+        let e: f32x2 = f32x2::new(3.0, 5.0);
+        let r: f32x2 = transmute(vfmlal_lane_low_f16::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlal_laneq_low_f16() {
+        let a: f32x2 = f32x2::new(1.0, 1.0);
+        let b: f16x4 = f16x4::new(1.0, 2.0, 3.0, 4.0);
+        let c: f16x8 = f16x8::new(2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        let e: f32x2 = f32x2::new(3.0, 5.0);
+        let r: f32x2 = transmute(vfmlal_laneq_low_f16::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlalq_lane_low_f16() {
+        let a: f32x4 = f32x4::new(1.0, 1.0, 1.0, 1.0);
+        let b: f16x8 = f16x8::new(1.0, 2.0, 3.0, 4.0, 3.0, 4.0, 5.0, 6.0);
+        let c: f16x4 = f16x4::new(2.0, 0.0, 0.0, 0.0);
+        let e: f32x4 = f32x4::new(3.0, 5.0, 7.0, 9.0);
+        let r: f32x4 = transmute(vfmlalq_lane_low_f16::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlalq_laneq_low_f16() {
+        let a: f32x4 = f32x4::new(1.0, 1.0, 1.0, 1.0);
+        let b: f16x8 = f16x8::new(1.0, 2.0, 3.0, 4.0, 3.0, 4.0, 5.0, 6.0);
+        let c: f16x8 = f16x8::new(2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        let e: f32x4 = f32x4::new(3.0, 5.0, 7.0, 9.0);
+        let r: f32x4 = transmute(vfmlalq_laneq_low_f16::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlal_lane_high_f16() {
+        let a: f32x2 = f32x2::new(1.0, 1.0);
+        let b: f16x4 = f16x4::new(1.0, 2.0, 3.0, 4.0);
+        let c: f16x4 = f16x4::new(2.0, 0.0, 0.0, 0.0);
+        let e: f32x2 = f32x2::new(7.0, 9.0);
+        let r: f32x2 = transmute(vfmlal_lane_high_f16::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlal_laneq_high_f16() {
+        let a: f32x2 = f32x2::new(1.0, 1.0);
+        let b: f16x4 = f16x4::new(1.0, 2.0, 3.0, 4.0);
+        let c: f16x8 = f16x8::new(2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        let e: f32x2 = f32x2::new(7.0, 9.0);
+        let r: f32x2 = transmute(vfmlal_laneq_high_f16::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlalq_lane_high_f16() {
+        let a: f32x4 = f32x4::new(1.0, 1.0, 1.0, 1.0);
+        let b: f16x8 = f16x8::new(1.0, 2.0, 3.0, 4.0, 3.0, 4.0, 5.0, 6.0);
+        let c: f16x4 = f16x4::new(2.0, 0.0, 0.0, 0.0);
+        let e: f32x4 = f32x4::new(7.0, 9.0, 11.0, 13.0);
+        let r: f32x4 = transmute(vfmlalq_lane_high_f16::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlalq_laneq_high_f16() {
+        let a: f32x4 = f32x4::new(1.0, 1.0, 1.0, 1.0);
+        let b: f16x8 = f16x8::new(1.0, 2.0, 3.0, 4.0, 3.0, 4.0, 5.0, 6.0);
+        let c: f16x8 = f16x8::new(2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        let e: f32x4 = f32x4::new(7.0, 9.0, 11.0, 13.0);
+        let r: f32x4 = transmute(vfmlalq_laneq_high_f16::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlsl_lane_low_f16() {
+        let a: f32x2 = f32x2::new(10.0, 10.0);
+        let b: f16x4 = f16x4::new(1.0, 2.0, 3.0, 4.0);
+        let c: f16x4 = f16x4::new(2.0, 0.0, 0.0, 0.0);
+        let e: f32x2 = f32x2::new(8.0, 6.0);
+        let r: f32x2 = transmute(vfmlsl_lane_low_f16::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlsl_laneq_low_f16() {
+        let a: f32x2 = f32x2::new(10.0, 10.0);
+        let b: f16x4 = f16x4::new(1.0, 2.0, 3.0, 4.0);
+        let c: f16x8 = f16x8::new(2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        let e: f32x2 = f32x2::new(8.0, 6.0);
+        let r: f32x2 = transmute(vfmlsl_laneq_low_f16::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlslq_lane_low_f16() {
+        let a: f32x4 = f32x4::new(10.0, 10.0, 10.0, 10.0);
+        let b: f16x8 = f16x8::new(1.0, 2.0, 3.0, 4.0, 3.0, 4.0, 5.0, 6.0);
+        let c: f16x4 = f16x4::new(2.0, 0.0, 0.0, 0.0);
+        let e: f32x4 = f32x4::new(8.0, 6.0, 4.0, 2.0);
+        let r: f32x4 = transmute(vfmlslq_lane_low_f16::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlslq_laneq_low_f16() {
+        let a: f32x4 = f32x4::new(10.0, 10.0, 10.0, 10.0);
+        let b: f16x8 = f16x8::new(1.0, 2.0, 3.0, 4.0, 3.0, 4.0, 5.0, 6.0);
+        let c: f16x8 = f16x8::new(2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        let e: f32x4 = f32x4::new(8.0, 6.0, 4.0, 2.0);
+        let r: f32x4 = transmute(vfmlslq_laneq_low_f16::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlsl_lane_high_f16() {
+        let a: f32x2 = f32x2::new(10.0, 10.0);
+        let b: f16x4 = f16x4::new(1.0, 2.0, 3.0, 4.0);
+        let c: f16x4 = f16x4::new(2.0, 0.0, 0.0, 0.0);
+        let e: f32x2 = f32x2::new(4.0, 2.0);
+        let r: f32x2 = transmute(vfmlsl_lane_high_f16::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlsl_laneq_high_f16() {
+        let a: f32x2 = f32x2::new(10.0, 10.0);
+        let b: f16x4 = f16x4::new(1.0, 2.0, 3.0, 4.0);
+        let c: f16x8 = f16x8::new(2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        let e: f32x2 = f32x2::new(4.0, 2.0);
+        let r: f32x2 = transmute(vfmlsl_laneq_high_f16::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlslq_lane_high_f16() {
+        let a: f32x4 = f32x4::new(10.0, 10.0, 10.0, 10.0);
+        let b: f16x8 = f16x8::new(1.0, 2.0, 3.0, 4.0, 3.0, 4.0, 5.0, 6.0);
+        let c: f16x4 = f16x4::new(2.0, 0.0, 0.0, 0.0);
+        let e: f32x4 = f32x4::new(4.0, 2.0, 0.0, -2.0);
+        let r: f32x4 = transmute(vfmlslq_lane_high_f16::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlslq_laneq_high_f16() {
+        let a: f32x4 = f32x4::new(10.0, 10.0, 10.0, 10.0);
+        let b: f16x8 = f16x8::new(1.0, 2.0, 3.0, 4.0, 3.0, 4.0, 5.0, 6.0);
+        let c: f16x8 = f16x8::new(2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        let e: f32x4 = f32x4::new(4.0, 2.0, 0.0, -2.0);
+        let r: f32x4 = transmute(vfmlslq_laneq_high_f16::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vbfdot_f32() {
+        let a: f32x2 = f32x2::new(1.0, 2.0);
+        let b: u16x4 = u16x4::new(0x3F80, 0x4000, 0x4040, 0x4080);
+        let c: u16x4 = u16x4::new(0x3F80, 0x3F80, 0x4000, 0x4000);
+        let e: f32x2 = f32x2::new(4.0, 16.0);
+        let r: f32x2 = transmute(vbfdot_f32(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vbfdotq_f32() {
+        let a: f32x4 = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let b: u16x8 = u16x8::new(0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100);
+        let c: u16x8 = u16x8::new(0x3F80, 0x3F80, 0x4000, 0x4000, 0x3F80, 0x3F80, 0x4000, 0x4000);
+        let e: f32x4 = f32x4::new(4.0, 16.0, 14.0, 34.0);
+        let r: f32x4 = transmute(vbfdotq_f32(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vbfdot_lane_f32() {
+        let a: f32x2 = f32x2::new(1.0, 2.0);
+        let b: u16x4 = u16x4::new(0x3F80, 0x4000, 0x4040, 0x4080);
+        let c: u16x4 = u16x4::new(0x3F80, 0x3F80, 0x4000, 0x4000);
+        let e: f32x2 = f32x2::new(4.0, 9.0);
+        let r: f32x2 = transmute(vbfdot_lane_f32::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vbfdot_laneq_f32() {
+        let a: f32x2 = f32x2::new(1.0, 2.0);
+        let b: u16x4 = u16x4::new(0x3F80, 0x4000, 0x4040, 0x4080);
+        let c: u16x8 = u16x8::new(0x3F80, 0x3F80, 0x4000, 0x4000, 0x3F80, 0x3F80, 0x4000, 0x4000);
+        let e: f32x2 = f32x2::new(4.0, 9.0);
+        let r: f32x2 = transmute(vbfdot_laneq_f32::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vbfdotq_lane_f32() {
+        let a: f32x4 = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let b: u16x8 = u16x8::new(0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100);
+        let c: u16x4 = u16x4::new(0x3F80, 0x3F80, 0x4000, 0x4000);
+        let e: f32x4 = f32x4::new(4.0, 9.0, 14.0, 19.0);
+        let r: f32x4 = transmute(vbfdotq_lane_f32::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vbfdotq_laneq_f32() {
+        let a: f32x4 = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let b: u16x8 = u16x8::new(0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100);
+        let c: u16x8 = u16x8::new(0x3F80, 0x3F80, 0x4000, 0x4000, 0x3F80, 0x3F80, 0x4000, 0x4000);
+        let e: f32x4 = f32x4::new(4.0, 9.0, 14.0, 19.0);
+        let r: f32x4 = transmute(vbfdotq_laneq_f32::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vbfmmlaq_f32() {
+        let a: f32x4 = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let b: u16x8 = u16x8::new(0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100);
+        let c: u16x8 = u16x8::new(0x3F80, 0x3F80, 0x3F80, 0x3F80, 0x3F80, 0x4000, 0x3F80, 0x4000);
+        let e: f32x4 = f32x4::new(11.0, 18.0, 29.0, 44.0);
+        let r: f32x4 = transmute(vbfmmlaq_f32(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vbfmlalbq_f32() {
+        let a: f32x4 = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let b: u16x8 = u16x8::new(0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100);
+        let c: u16x8 = u16x8::new(0x3F80, 0x3F80, 0x4000, 0x4000, 0x3F80, 0x3F80, 0x4000, 0x4000);
+        let e: f32x4 = f32x4::new(2.0, 8.0, 8.0, 18.0);
+        let r: f32x4 = transmute(vbfmlalbq_f32(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vbfmlaltq_f32() {
+        let a: f32x4 = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let b: u16x8 = u16x8::new(0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100);
+        let c: u16x8 = u16x8::new(0x3F80, 0x3F80, 0x4000, 0x4000, 0x3F80, 0x3F80, 0x4000, 0x4000);
+        let e: f32x4 = f32x4::new(3.0, 10.0, 9.0, 20.0);
+        let r: f32x4 = transmute(vbfmlaltq_f32(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vbfmlalbq_lane_f32() {
+        let a: f32x4 = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let b: u16x8 = u16x8::new(0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100);
+        let c: u16x4 = u16x4::new(0x3F80, 0x4000, 0x4040, 0x4080);
+        let e: f32x4 = f32x4::new(2.0, 5.0, 8.0, 11.0);
+        let r: f32x4 = transmute(vbfmlalbq_lane_f32::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vbfmlalbq_laneq_f32() {
+        let a: f32x4 = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let b: u16x8 = u16x8::new(0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100);
+        let c: u16x8 = u16x8::new(0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100);
+        let e: f32x4 = f32x4::new(2.0, 5.0, 8.0, 11.0);
+        let r: f32x4 = transmute(vbfmlalbq_laneq_f32::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vbfmlaltq_lane_f32() {
+        let a: f32x4 = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let b: u16x8 = u16x8::new(0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100);
+        let c: u16x4 = u16x4::new(0x3F80, 0x4000, 0x4040, 0x4080);
+        let e: f32x4 = f32x4::new(3.0, 6.0, 9.0, 12.0);
+        let r: f32x4 = transmute(vbfmlaltq_lane_f32::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vbfmlaltq_laneq_f32() {
+        let a: f32x4 = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let b: u16x8 = u16x8::new(0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100);
+        let c: u16x8 = u16x8::new(0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100);
+        let e: f32x4 = f32x4::new(3.0, 6.0, 9.0, 12.0);
+        let r: f32x4 = transmute(vbfmlaltq_laneq_f32::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
 }
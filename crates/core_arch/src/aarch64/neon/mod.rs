@@ -705,6 +705,24 @@ pub unsafe fn vld1q_f32(ptr: *const f32) -> float32x4_t {
     read_unaligned(ptr.cast())
 }
 
+/// Load multiple single-element structures to one, two, three, or four registers.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(ldr))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vld1_f16(ptr: *const f16) -> float16x4_t {
+    read_unaligned(ptr.cast())
+}
+
+/// Load multiple single-element structures to one, two, three, or four registers.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(ldr))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vld1q_f16(ptr: *const f16) -> float16x8_t {
+    read_unaligned(ptr.cast())
+}
+
 /// Load multiple single-element structures to one, two, three, or four registers.
 #[inline]
 #[target_feature(enable = "neon")]
@@ -1008,6 +1026,26 @@ pub unsafe fn vst1q_f32(ptr: *mut f32, a: float32x4_t) {
     write_unaligned(ptr.cast(), a);
 }
 
+// Store multiple single-element structures from one, two, three, or four registers.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(str))]
+#[allow(clippy::cast_ptr_alignment)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vst1_f16(ptr: *mut f16, a: float16x4_t) {
+    write_unaligned(ptr.cast(), a);
+}
+
+// Store multiple single-element structures from one, two, three, or four registers.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(test, assert_instr(str))]
+#[allow(clippy::cast_ptr_alignment)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vst1q_f16(ptr: *mut f16, a: float16x8_t) {
+    write_unaligned(ptr.cast(), a);
+}
+
 // Store multiple single-element structures from one, two, three, or four registers.
 #[inline]
 #[target_feature(enable = "neon")]
@@ -3521,6 +3559,57 @@ pub unsafe fn vxarq_u64<const IMM6: i32>(a: uint64x2_t, b: uint64x2_t) -> uint64
     vxarq_u64_(a, b, IMM6 as i64)
 }
 
+/// Convert from single-precision floating-point to BFloat16
+///
+/// LLVM only exposes the conversion as a `bfloat` vector operation, which has
+/// no Rust counterpart, so the instruction is emitted directly.
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(test, assert_instr(bfcvtn))]
+#[unstable(feature = "stdarch_neon_bf16", issue = "none")]
+pub unsafe fn vcvt_bf16_f32(a: float32x4_t) -> bfloat16x4_t {
+    let r: bfloat16x4_t;
+    crate::arch::asm!(
+        "bfcvtn {0:v}.4h, {1:v}.4s",
+        out(vreg) r,
+        in(vreg) a,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Convert from single-precision floating-point to BFloat16
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(test, assert_instr(bfcvtn))]
+#[unstable(feature = "stdarch_neon_bf16", issue = "none")]
+pub unsafe fn vcvtq_low_bf16_f32(a: float32x4_t) -> bfloat16x8_t {
+    let r: bfloat16x8_t;
+    crate::arch::asm!(
+        "bfcvtn {0:v}.4h, {1:v}.4s",
+        out(vreg) r,
+        in(vreg) a,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
+/// Convert from single-precision floating-point to BFloat16
+#[inline]
+#[target_feature(enable = "neon,bf16")]
+#[cfg_attr(test, assert_instr(bfcvtn2))]
+#[unstable(feature = "stdarch_neon_bf16", issue = "none")]
+pub unsafe fn vcvtq_high_bf16_f32(inactive: bfloat16x8_t, a: float32x4_t) -> bfloat16x8_t {
+    let mut r = inactive;
+    crate::arch::asm!(
+        "bfcvtn2 {0:v}.8h, {1:v}.4s",
+        inout(vreg) r,
+        in(vreg) a,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    r
+}
+
 #[cfg(test)]
 mod tests {
     use crate::core_arch::aarch64::test_support::*;
@@ -5207,6 +5296,32 @@ mod tests {
         let r: u64x2 = transmute(vxarq_u64::<0>(transmute(a), transmute(b)));
         assert_eq!(r, e);
     }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vcvt_bf16_f32() {
+        // 1.00390625 is halfway between two `bf16` values and rounds to even.
+        let a: f32x4 = f32x4::new(1.0, -2.0, 1.00390625, f32::INFINITY);
+        let e: u16x4 = u16x4::new(0x3F80, 0xC000, 0x3F80, 0x7F80);
+        let r: u16x4 = transmute(vcvt_bf16_f32(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vcvtq_low_bf16_f32() {
+        let a: f32x4 = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let e: u16x8 = u16x8::new(0x3F80, 0x4000, 0x4040, 0x4080, 0, 0, 0, 0);
+        let r: u16x8 = transmute(vcvtq_low_bf16_f32(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,bf16")]
+    unsafe fn test_vcvtq_high_bf16_f32() {
+        let inactive: u16x8 = u16x8::new(1, 2, 3, 4, 5, 6, 7, 8);
+        let a: f32x4 = f32x4::new(5.0, 6.0, 7.0, 8.0);
+        let e: u16x8 = u16x8::new(1, 2, 3, 4, 0x40A0, 0x40C0, 0x40E0, 0x4100);
+        let r: u16x8 = transmute(vcvtq_high_bf16_f32(transmute(inactive), transmute(a)));
+        assert_eq!(r, e);
+    }
 }
 
 #[cfg(test)]
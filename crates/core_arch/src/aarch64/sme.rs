@@ -0,0 +1,250 @@
+//! Arm's Scalable Matrix Extension (SME) intrinsics.
+//!
+//! The reference is the [ACLE for SME][acle_sme].
+//!
+//! In C, the transitions into and out of streaming mode and the enabling of
+//! the ZA storage are inserted by the compiler around functions declared with
+//! the `__arm_streaming` and `__arm_new("za")` keyword attributes. Rust has no
+//! equivalent of these attributes, and the outer-product (`svmopa`, `svmops`)
+//! and slice load/store intrinsics take sizeless vector and predicate types,
+//! which Rust cannot represent yet.
+//!
+//! The intrinsics provided here are the ones that only use scalars: querying
+//! the streaming mode and the streaming vector length, and zeroing, loading
+//! and storing whole ZA array vectors. The ones that access ZA require it to
+//! be enabled, for example with `asm!("smstart za")`, and to be disabled again
+//! with `asm!("smstop za")` before returning to code that does not expect ZA to
+//! be active.
+//!
+//! [acle_sme]: https://arm-software.github.io/acle/main/acle.html#scalable-matrix-extension-sme
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Returns `true` if the processor is in streaming SVE mode.
+///
+/// [ACLE documentation](https://arm-software.github.io/acle/main/acle.html#arm_in_streaming_mode)
+#[inline]
+#[target_feature(enable = "sme")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(mrs))]
+#[unstable(feature = "stdarch_aarch64_sme", issue = "none")]
+pub unsafe fn __arm_in_streaming_mode() -> bool {
+    let svcr: u64;
+    // mrs svcr, SVCR
+    asm!("mrs {}, S3_3_C4_C2_2", out(reg) svcr, options(nomem, nostack, preserves_flags));
+    svcr & 1 != 0
+}
+
+/// Count the number of 8-bit elements in a streaming vector.
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/svcntsb)
+#[inline]
+#[target_feature(enable = "sme")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(rdsvl))]
+#[unstable(feature = "stdarch_aarch64_sme", issue = "none")]
+pub unsafe fn svcntsb() -> u64 {
+    let bytes: u64;
+    asm!("rdsvl {}, #1", out(reg) bytes, options(pure, nomem, nostack, preserves_flags));
+    bytes
+}
+
+/// Count the number of 16-bit elements in a streaming vector.
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/svcntsh)
+#[inline]
+#[target_feature(enable = "sme")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(rdsvl))]
+#[unstable(feature = "stdarch_aarch64_sme", issue = "none")]
+pub unsafe fn svcntsh() -> u64 {
+    svcntsb() / 2
+}
+
+/// Count the number of 32-bit elements in a streaming vector.
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/svcntsw)
+#[inline]
+#[target_feature(enable = "sme")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(rdsvl))]
+#[unstable(feature = "stdarch_aarch64_sme", issue = "none")]
+pub unsafe fn svcntsw() -> u64 {
+    svcntsb() / 4
+}
+
+/// Count the number of 64-bit elements in a streaming vector.
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/svcntsd)
+#[inline]
+#[target_feature(enable = "sme")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(rdsvl))]
+#[unstable(feature = "stdarch_aarch64_sme", issue = "none")]
+pub unsafe fn svcntsd() -> u64 {
+    svcntsb() / 8
+}
+
+/// Zero the whole ZA array.
+///
+/// ZA must be enabled.
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/svzero_za)
+#[inline]
+#[target_feature(enable = "sme")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(zero))]
+#[unstable(feature = "stdarch_aarch64_sme", issue = "none")]
+pub unsafe fn svzero_za() {
+    asm!("zero {{za}}", options(nomem, nostack, preserves_flags));
+}
+
+/// Zero the 64-bit ZA tiles selected by the bits of `MASK`.
+///
+/// Bit `n` of `MASK` selects tile `ZAn.D`; the tiles of the other element
+/// sizes overlap with these as described in the SME specification. ZA must be
+/// enabled.
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/svzero_mask_za)
+#[inline]
+#[target_feature(enable = "sme")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(zero, MASK = 0xff))]
+#[rustc_legacy_const_generics(0)]
+#[unstable(feature = "stdarch_aarch64_sme", issue = "none")]
+pub unsafe fn svzero_mask_za<const MASK: u64>() {
+    static_assert_uimm_bits!(MASK, 8);
+    // The ZERO instruction takes the tile list as an 8-bit immediate, one bit
+    // per 64-bit tile, which is exactly what `MASK` holds.
+    asm!(
+        ".inst {}",
+        const 0xc008_0000_u32 | MASK as u32,
+        options(nomem, nostack, preserves_flags)
+    );
+}
+
+/// Load the ZA array vector `slice` from the memory at `ptr`.
+///
+/// Reads `svcntsb()` bytes. ZA must be enabled.
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/svldr_za)
+#[inline]
+#[target_feature(enable = "sme")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(ldr))]
+#[unstable(feature = "stdarch_aarch64_sme", issue = "none")]
+pub unsafe fn svldr_za(slice: u32, ptr: *const u8) {
+    // The slice index of LDR (array vector) must be held in one of w12-w15.
+    asm!(
+        "ldr za[w12, 0], [{ptr}]",
+        ptr = in(reg) ptr,
+        in("w12") slice,
+        options(readonly, nostack, preserves_flags)
+    );
+}
+
+/// Store the ZA array vector `slice` to the memory at `ptr`.
+///
+/// Writes `svcntsb()` bytes. ZA must be enabled.
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/svstr_za)
+#[inline]
+#[target_feature(enable = "sme")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(str))]
+#[unstable(feature = "stdarch_aarch64_sme", issue = "none")]
+pub unsafe fn svstr_za(slice: u32, ptr: *mut u8) {
+    // The slice index of STR (array vector) must be held in one of w12-w15.
+    asm!(
+        "str za[w12, 0], [{ptr}]",
+        ptr = in(reg) ptr,
+        in("w12") slice,
+        options(nostack, preserves_flags)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use stdarch_test::simd_test;
+
+    use crate::arch::asm;
+    use crate::core_arch::aarch64::*;
+    use std::vec;
+
+    #[simd_test(enable = "sme")]
+    unsafe fn test_arm_in_streaming_mode() {
+        assert!(!__arm_in_streaming_mode());
+    }
+
+    #[simd_test(enable = "sme")]
+    unsafe fn test_svcntsb() {
+        let n = svcntsb();
+        // The streaming vector length is a power of two between 128 and 2048 bits.
+        assert!(n >= 16 && n <= 256 && n.is_power_of_two());
+    }
+
+    #[simd_test(enable = "sme")]
+    unsafe fn test_svcntsh() {
+        assert_eq!(svcntsh() * 2, svcntsb());
+    }
+
+    #[simd_test(enable = "sme")]
+    unsafe fn test_svcntsw() {
+        assert_eq!(svcntsw() * 4, svcntsb());
+    }
+
+    #[simd_test(enable = "sme")]
+    unsafe fn test_svcntsd() {
+        assert_eq!(svcntsd() * 8, svcntsb());
+    }
+
+    #[simd_test(enable = "sme")]
+    unsafe fn test_svldr_za() {
+        let n = svcntsb() as usize;
+        let src: vec::Vec<u8> = (0..n).map(|i| i as u8).collect();
+        let mut dst = vec![0u8; n];
+        asm!("smstart za");
+        svldr_za(3, src.as_ptr());
+        svstr_za(3, dst.as_mut_ptr());
+        asm!("smstop za");
+        assert_eq!(src, dst);
+    }
+
+    #[simd_test(enable = "sme")]
+    unsafe fn test_svstr_za() {
+        let n = svcntsb() as usize;
+        let src = vec![0xa5u8; n];
+        let mut dst = vec![0u8; n];
+        asm!("smstart za");
+        svldr_za(0, src.as_ptr());
+        svstr_za(0, dst.as_mut_ptr());
+        asm!("smstop za");
+        assert_eq!(src, dst);
+    }
+
+    #[simd_test(enable = "sme")]
+    unsafe fn test_svzero_za() {
+        let n = svcntsb() as usize;
+        let src = vec![0xffu8; n];
+        let mut dst = vec![0xffu8; n];
+        asm!("smstart za");
+        svldr_za(1, src.as_ptr());
+        svzero_za();
+        svstr_za(1, dst.as_mut_ptr());
+        asm!("smstop za");
+        assert!(dst.iter().all(|&b| b == 0));
+    }
+
+    #[simd_test(enable = "sme")]
+    unsafe fn test_svzero_mask_za() {
+        let n = svcntsb() as usize;
+        let src = vec![0xffu8; n];
+        let mut kept = vec![0u8; n];
+        let mut zeroed = vec![0xffu8; n];
+        asm!("smstart za");
+        // Horizontal slice 0 of ZA0.D is array vector 0, and slice 0 of ZA1.D
+        // is array vector 1.
+        svldr_za(0, src.as_ptr());
+        svldr_za(1, src.as_ptr());
+        svzero_mask_za::<0b10>();
+        svstr_za(0, kept.as_mut_ptr());
+        svstr_za(1, zeroed.as_mut_ptr());
+        asm!("smstop za");
+        assert_eq!(kept, src);
+        assert!(zeroed.iter().all(|&b| b == 0));
+    }
+}
@@ -0,0 +1,272 @@
+//! Arm's Scalable Vector Extension (SVE) intrinsics.
+//!
+//! The reference is the [ACLE for SVE][acle_sve].
+//!
+//! Most SVE intrinsics operate on sizeless types such as `svint32_t` and
+//! `svbool_t`, whose size is only known at run-time. Rust cannot represent
+//! these types yet, so only the intrinsics that take and return scalars are
+//! provided here: the element counts that are used to size buffers and to
+//! strip-mine loops around inline assembly.
+//!
+//! This is not general SVE support. The module only covers `svcntb`,
+//! `svcnth`, `svcntw` and `svcntd`, their `_pat` variants, and the `SV_*`
+//! predicate pattern constants those variants take.
+//!
+//! [acle_sve]: https://arm-software.github.io/acle/main/acle.html#scalable-vector-extension-sve
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+extern "unadjusted" {
+    #[link_name = "llvm.aarch64.sve.cntb"]
+    fn sve_cntb(pattern: i32) -> i64;
+    #[link_name = "llvm.aarch64.sve.cnth"]
+    fn sve_cnth(pattern: i32) -> i64;
+    #[link_name = "llvm.aarch64.sve.cntw"]
+    fn sve_cntw(pattern: i32) -> i64;
+    #[link_name = "llvm.aarch64.sve.cntd"]
+    fn sve_cntd(pattern: i32) -> i64;
+}
+
+/// Predicate pattern: the largest power of two.
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub const SV_POW2: i32 = 0;
+
+/// Predicate pattern: exactly 1 element, if available.
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub const SV_VL1: i32 = 1;
+
+/// Predicate pattern: exactly 2 elements, if available.
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub const SV_VL2: i32 = 2;
+
+/// Predicate pattern: exactly 3 elements, if available.
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub const SV_VL3: i32 = 3;
+
+/// Predicate pattern: exactly 4 elements, if available.
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub const SV_VL4: i32 = 4;
+
+/// Predicate pattern: exactly 5 elements, if available.
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub const SV_VL5: i32 = 5;
+
+/// Predicate pattern: exactly 6 elements, if available.
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub const SV_VL6: i32 = 6;
+
+/// Predicate pattern: exactly 7 elements, if available.
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub const SV_VL7: i32 = 7;
+
+/// Predicate pattern: exactly 8 elements, if available.
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub const SV_VL8: i32 = 8;
+
+/// Predicate pattern: exactly 16 elements, if available.
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub const SV_VL16: i32 = 9;
+
+/// Predicate pattern: exactly 32 elements, if available.
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub const SV_VL32: i32 = 10;
+
+/// Predicate pattern: exactly 64 elements, if available.
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub const SV_VL64: i32 = 11;
+
+/// Predicate pattern: exactly 128 elements, if available.
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub const SV_VL128: i32 = 12;
+
+/// Predicate pattern: exactly 256 elements, if available.
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub const SV_VL256: i32 = 13;
+
+/// Predicate pattern: the largest multiple of 4.
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub const SV_MUL4: i32 = 29;
+
+/// Predicate pattern: the largest multiple of 3.
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub const SV_MUL3: i32 = 30;
+
+/// Predicate pattern: all available elements.
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub const SV_ALL: i32 = 31;
+
+/// Count the number of 8-bit elements in a vector.
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/svcntb)
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(rdvl))]
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub unsafe fn svcntb() -> u64 {
+    sve_cntb(SV_ALL) as u64
+}
+
+/// Count the number of 8-bit elements in a vector that are selected by the predicate
+/// pattern `PATTERN`, which is one of the `SV_*` constants.
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/svcntb_pat)
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(
+    all(test, not(target_env = "msvc")),
+    assert_instr(cntb, PATTERN = SV_POW2)
+)]
+#[rustc_legacy_const_generics(0)]
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub unsafe fn svcntb_pat<const PATTERN: i32>() -> u64 {
+    static_assert!(matches!(PATTERN, 0..=13 | 29..=31));
+    sve_cntb(PATTERN) as u64
+}
+
+/// Count the number of 16-bit elements in a vector.
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/svcnth)
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(cnth))]
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub unsafe fn svcnth() -> u64 {
+    sve_cnth(SV_ALL) as u64
+}
+
+/// Count the number of 16-bit elements in a vector that are selected by the predicate
+/// pattern `PATTERN`, which is one of the `SV_*` constants.
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/svcnth_pat)
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(
+    all(test, not(target_env = "msvc")),
+    assert_instr(cnth, PATTERN = SV_POW2)
+)]
+#[rustc_legacy_const_generics(0)]
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub unsafe fn svcnth_pat<const PATTERN: i32>() -> u64 {
+    static_assert!(matches!(PATTERN, 0..=13 | 29..=31));
+    sve_cnth(PATTERN) as u64
+}
+
+/// Count the number of 32-bit elements in a vector.
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/svcntw)
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(cntw))]
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub unsafe fn svcntw() -> u64 {
+    sve_cntw(SV_ALL) as u64
+}
+
+/// Count the number of 32-bit elements in a vector that are selected by the predicate
+/// pattern `PATTERN`, which is one of the `SV_*` constants.
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/svcntw_pat)
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(
+    all(test, not(target_env = "msvc")),
+    assert_instr(cntw, PATTERN = SV_POW2)
+)]
+#[rustc_legacy_const_generics(0)]
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub unsafe fn svcntw_pat<const PATTERN: i32>() -> u64 {
+    static_assert!(matches!(PATTERN, 0..=13 | 29..=31));
+    sve_cntw(PATTERN) as u64
+}
+
+/// Count the number of 64-bit elements in a vector.
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/svcntd)
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(all(test, not(target_env = "msvc")), assert_instr(cntd))]
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub unsafe fn svcntd() -> u64 {
+    sve_cntd(SV_ALL) as u64
+}
+
+/// Count the number of 64-bit elements in a vector that are selected by the predicate
+/// pattern `PATTERN`, which is one of the `SV_*` constants.
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/svcntd_pat)
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(
+    all(test, not(target_env = "msvc")),
+    assert_instr(cntd, PATTERN = SV_POW2)
+)]
+#[rustc_legacy_const_generics(0)]
+#[unstable(feature = "stdarch_aarch64_sve", issue = "none")]
+pub unsafe fn svcntd_pat<const PATTERN: i32>() -> u64 {
+    static_assert!(matches!(PATTERN, 0..=13 | 29..=31));
+    sve_cntd(PATTERN) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use stdarch_test::simd_test;
+
+    use crate::core_arch::aarch64::*;
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svcntb() {
+        let n = svcntb();
+        // The vector length is a multiple of 128 bits, and at most 2048 bits.
+        assert!(n >= 16 && n <= 256 && n % 16 == 0);
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svcnth() {
+        assert_eq!(svcnth() * 2, svcntb());
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svcntw() {
+        assert_eq!(svcntw() * 4, svcntb());
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svcntd() {
+        assert_eq!(svcntd() * 8, svcntb());
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svcntb_pat() {
+        assert_eq!(svcntb_pat::<SV_ALL>(), svcntb());
+        assert_eq!(svcntb_pat::<SV_VL16>(), 16);
+        assert_eq!(svcntb_pat::<SV_MUL3>(), svcntb() / 3 * 3);
+        assert!(svcntb_pat::<SV_POW2>().is_power_of_two());
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svcnth_pat() {
+        assert_eq!(svcnth_pat::<SV_ALL>(), svcnth());
+        assert_eq!(svcnth_pat::<SV_VL8>(), 8);
+        assert_eq!(svcnth_pat::<SV_MUL4>(), svcnth() / 4 * 4);
+        // A pattern asking for more elements than a vector holds selects none.
+        assert_eq!(
+            svcnth_pat::<SV_VL256>(),
+            if svcnth() >= 256 { 256 } else { 0 }
+        );
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svcntw_pat() {
+        assert_eq!(svcntw_pat::<SV_ALL>(), svcntw());
+        assert_eq!(svcntw_pat::<SV_VL4>(), 4);
+        assert!(svcntw_pat::<SV_POW2>().is_power_of_two());
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svcntd_pat() {
+        assert_eq!(svcntd_pat::<SV_ALL>(), svcntd());
+        assert_eq!(svcntd_pat::<SV_VL2>(), 2);
+        assert_eq!(svcntd_pat::<SV_VL3>(), if svcntd() >= 3 { 3 } else { 0 });
+    }
+}
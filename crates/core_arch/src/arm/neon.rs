@@ -98,6 +98,10 @@ extern "unadjusted" {
     fn vld1_v2f32(addr: *const i8, align: i32) -> float32x2_t;
     #[link_name = "llvm.arm.neon.vld1.v4f32.p0i8"]
     fn vld1q_v4f32(addr: *const i8, align: i32) -> float32x4_t;
+    #[link_name = "llvm.arm.neon.vld1.v4f16.p0i8"]
+    fn vld1_v4f16(addr: *const i8, align: i32) -> float16x4_t;
+    #[link_name = "llvm.arm.neon.vld1.v8f16.p0i8"]
+    fn vld1q_v8f16(addr: *const i8, align: i32) -> float16x8_t;
 
     #[link_name = "llvm.arm.neon.vst1.p0i8.v8i8"]
     fn vst1_v8i8(addr: *const i8, val: int8x8_t, align: i32);
@@ -119,6 +123,10 @@ extern "unadjusted" {
     fn vst1_v2f32(addr: *const i8, val: float32x2_t, align: i32);
     #[link_name = "llvm.arm.neon.vst1.p0i8.v4f32"]
     fn vst1q_v4f32(addr: *const i8, val: float32x4_t, align: i32);
+    #[link_name = "llvm.arm.neon.vst1.p0i8.v4f16"]
+    fn vst1_v4f16(addr: *const i8, val: float16x4_t, align: i32);
+    #[link_name = "llvm.arm.neon.vst1.p0i8.v8f16"]
+    fn vst1q_v8f16(addr: *const i8, val: float16x8_t, align: i32);
 }
 
 /// Load multiple single-element structures to one, two, three, or four registers.
@@ -341,6 +349,24 @@ pub unsafe fn vld1q_f32(ptr: *const f32) -> float32x4_t {
     vld1q_v4f32(ptr as *const i8, align_of::<f32>() as i32)
 }
 
+/// Load multiple single-element structures to one, two, three, or four registers.
+#[inline]
+#[target_feature(enable = "neon,v7")]
+#[cfg_attr(test, assert_instr("vld1.16"))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vld1_f16(ptr: *const f16) -> float16x4_t {
+    vld1_v4f16(ptr as *const i8, align_of::<f16>() as i32)
+}
+
+/// Load multiple single-element structures to one, two, three, or four registers.
+#[inline]
+#[target_feature(enable = "neon,v7")]
+#[cfg_attr(test, assert_instr("vld1.16"))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vld1q_f16(ptr: *const f16) -> float16x8_t {
+    vld1q_v8f16(ptr as *const i8, align_of::<f16>() as i32)
+}
+
 /// Store multiple single-element structures from one, two, three, or four registers.
 #[inline]
 #[target_feature(enable = "neon,v7")]
@@ -561,6 +587,24 @@ pub unsafe fn vst1q_f32(ptr: *mut f32, a: float32x4_t) {
     vst1q_v4f32(ptr as *const i8, a, align_of::<f32>() as i32)
 }
 
+// Store multiple single-element structures from one, two, three, or four registers.
+#[inline]
+#[target_feature(enable = "neon,v7")]
+#[cfg_attr(test, assert_instr("vst1.16"))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vst1_f16(ptr: *mut f16, a: float16x4_t) {
+    vst1_v4f16(ptr as *const i8, a, align_of::<f16>() as i32)
+}
+
+// Store multiple single-element structures from one, two, three, or four registers.
+#[inline]
+#[target_feature(enable = "neon,v7")]
+#[cfg_attr(test, assert_instr("vst1.16"))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vst1q_f16(ptr: *mut f16, a: float16x8_t) {
+    vst1q_v8f16(ptr as *const i8, a, align_of::<f16>() as i32)
+}
+
 /// Table look-up
 #[inline]
 #[target_feature(enable = "neon,v7")]
@@ -31495,6 +31495,1272 @@ pub unsafe fn vqabsq_s32(a: int32x4_t) -> int32x4_t {
 vqabsq_s32_(a)
 }
 
+/// Floating-point add
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vadd_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vadd.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fadd))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vadd_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    simd_add(a, b)
+}
+
+/// Floating-point add
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vaddq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vadd.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fadd))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vaddq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    simd_add(a, b)
+}
+
+/// Floating-point subtract
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vsub_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vsub.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fsub))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vsub_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    simd_sub(a, b)
+}
+
+/// Floating-point subtract
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vsubq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vsub.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fsub))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vsubq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    simd_sub(a, b)
+}
+
+/// Floating-point multiply
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vmul_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vmul.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fmul))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vmul_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    simd_mul(a, b)
+}
+
+/// Floating-point multiply
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vmulq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vmul.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fmul))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vmulq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    simd_mul(a, b)
+}
+
+/// Floating-point multiply by scalar
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vmul_n_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vmul))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fmul))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vmul_n_f16(a: float16x4_t, b: f16) -> float16x4_t {
+    simd_mul(a, vdup_n_f16(b))
+}
+
+/// Floating-point multiply by scalar
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vmulq_n_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vmul))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fmul))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vmulq_n_f16(a: float16x8_t, b: f16) -> float16x8_t {
+    simd_mul(a, vdupq_n_f16(b))
+}
+
+/// Floating-point multiply
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vmul_lane_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vmul, LANE = 0))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fmul, LANE = 0))]
+#[rustc_legacy_const_generics(2)]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vmul_lane_f16<const LANE: i32>(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    static_assert_uimm_bits!(LANE, 2);
+    simd_mul(a, simd_shuffle!(b, b, [LANE as u32, LANE as u32, LANE as u32, LANE as u32]))
+}
+
+/// Floating-point multiply
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vmul_laneq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vmul, LANE = 0))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fmul, LANE = 0))]
+#[rustc_legacy_const_generics(2)]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vmul_laneq_f16<const LANE: i32>(a: float16x4_t, b: float16x8_t) -> float16x4_t {
+    static_assert_uimm_bits!(LANE, 3);
+    simd_mul(a, simd_shuffle!(b, b, [LANE as u32, LANE as u32, LANE as u32, LANE as u32]))
+}
+
+/// Floating-point multiply
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vmulq_lane_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vmul, LANE = 0))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fmul, LANE = 0))]
+#[rustc_legacy_const_generics(2)]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vmulq_lane_f16<const LANE: i32>(a: float16x8_t, b: float16x4_t) -> float16x8_t {
+    static_assert_uimm_bits!(LANE, 2);
+    simd_mul(a, simd_shuffle!(b, b, [LANE as u32, LANE as u32, LANE as u32, LANE as u32, LANE as u32, LANE as u32, LANE as u32, LANE as u32]))
+}
+
+/// Floating-point multiply
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vmulq_laneq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vmul, LANE = 0))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fmul, LANE = 0))]
+#[rustc_legacy_const_generics(2)]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vmulq_laneq_f16<const LANE: i32>(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    static_assert_uimm_bits!(LANE, 3);
+    simd_mul(a, simd_shuffle!(b, b, [LANE as u32, LANE as u32, LANE as u32, LANE as u32, LANE as u32, LANE as u32, LANE as u32, LANE as u32]))
+}
+
+/// Floating-point absolute value
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vabs_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vabs))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fabs))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vabs_f16(a: float16x4_t) -> float16x4_t {
+    simd_fabs(a)
+}
+
+/// Floating-point absolute value
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vabsq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vabs))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fabs))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vabsq_f16(a: float16x8_t) -> float16x8_t {
+    simd_fabs(a)
+}
+
+/// Floating-point negate
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vneg_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vneg.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fneg))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vneg_f16(a: float16x4_t) -> float16x4_t {
+    simd_neg(a)
+}
+
+/// Floating-point negate
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vnegq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vneg.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fneg))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vnegq_f16(a: float16x8_t) -> float16x8_t {
+    simd_neg(a)
+}
+
+/// Floating-point absolute difference
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vabd_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vabd.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fabd))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vabd_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vabds.v4f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fabd.v4f16")]
+        fn vabd_f16_(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+    }
+vabd_f16_(a, b)
+}
+
+/// Floating-point absolute difference
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vabdq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vabd.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fabd))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vabdq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vabds.v8f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fabd.v8f16")]
+        fn vabdq_f16_(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+    }
+vabdq_f16_(a, b)
+}
+
+/// Floating-point maximum
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vmax_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vmax))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fmax))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vmax_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vmaxs.v4f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fmax.v4f16")]
+        fn vmax_f16_(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+    }
+vmax_f16_(a, b)
+}
+
+/// Floating-point maximum
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vmaxq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vmax))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fmax))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vmaxq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vmaxs.v8f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fmax.v8f16")]
+        fn vmaxq_f16_(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+    }
+vmaxq_f16_(a, b)
+}
+
+/// Floating-point minimum
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vmin_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vmin))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fmin))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vmin_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vmins.v4f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fmin.v4f16")]
+        fn vmin_f16_(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+    }
+vmin_f16_(a, b)
+}
+
+/// Floating-point minimum
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vminq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vmin))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fmin))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vminq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vmins.v8f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fmin.v8f16")]
+        fn vminq_f16_(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+    }
+vminq_f16_(a, b)
+}
+
+/// Floating-point maximum number
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vmaxnm_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vmaxnm))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fmaxnm))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vmaxnm_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vmaxnm.v4f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fmaxnm.v4f16")]
+        fn vmaxnm_f16_(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+    }
+vmaxnm_f16_(a, b)
+}
+
+/// Floating-point maximum number
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vmaxnmq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vmaxnm))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fmaxnm))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vmaxnmq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vmaxnm.v8f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fmaxnm.v8f16")]
+        fn vmaxnmq_f16_(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+    }
+vmaxnmq_f16_(a, b)
+}
+
+/// Floating-point minimum number
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vminnm_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vminnm))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fminnm))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vminnm_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vminnm.v4f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fminnm.v4f16")]
+        fn vminnm_f16_(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+    }
+vminnm_f16_(a, b)
+}
+
+/// Floating-point minimum number
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vminnmq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vminnm))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fminnm))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vminnmq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vminnm.v8f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fminnm.v8f16")]
+        fn vminnmq_f16_(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+    }
+vminnmq_f16_(a, b)
+}
+
+/// Floating-point fused multiply-add to accumulator
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfma_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vfma))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fmla))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vfma_f16(a: float16x4_t, b: float16x4_t, c: float16x4_t) -> float16x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.fma.v4f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.fma.v4f16")]
+        fn vfma_f16_(a: float16x4_t, b: float16x4_t, c: float16x4_t) -> float16x4_t;
+    }
+vfma_f16_(b, c, a)
+}
+
+/// Floating-point fused multiply-add to accumulator
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmaq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vfma))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fmla))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vfmaq_f16(a: float16x8_t, b: float16x8_t, c: float16x8_t) -> float16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.fma.v8f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.fma.v8f16")]
+        fn vfmaq_f16_(a: float16x8_t, b: float16x8_t, c: float16x8_t) -> float16x8_t;
+    }
+vfmaq_f16_(b, c, a)
+}
+
+/// Floating-point fused multiply-subtract from accumulator
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfms_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vfms))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fmls))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vfms_f16(a: float16x4_t, b: float16x4_t, c: float16x4_t) -> float16x4_t {
+    let b: float16x4_t = simd_neg(b);
+    vfma_f16(a, b, c)
+}
+
+/// Floating-point fused multiply-subtract from accumulator
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vfmsq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vfms))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fmls))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vfmsq_f16(a: float16x8_t, b: float16x8_t, c: float16x8_t) -> float16x8_t {
+    let b: float16x8_t = simd_neg(b);
+    vfmaq_f16(a, b, c)
+}
+
+/// Floating-point compare equal
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vceq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vceq.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fcmeq))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vceq_f16(a: float16x4_t, b: float16x4_t) -> uint16x4_t {
+    simd_eq(a, b)
+}
+
+/// Floating-point compare equal
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vceqq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vceq.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fcmeq))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vceqq_f16(a: float16x8_t, b: float16x8_t) -> uint16x8_t {
+    simd_eq(a, b)
+}
+
+/// Floating-point compare greater than or equal
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcge_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vcge.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fcmge))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vcge_f16(a: float16x4_t, b: float16x4_t) -> uint16x4_t {
+    simd_ge(a, b)
+}
+
+/// Floating-point compare greater than or equal
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcgeq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vcge.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fcmge))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vcgeq_f16(a: float16x8_t, b: float16x8_t) -> uint16x8_t {
+    simd_ge(a, b)
+}
+
+/// Floating-point compare greater than
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcgt_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vcgt.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fcmgt))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vcgt_f16(a: float16x4_t, b: float16x4_t) -> uint16x4_t {
+    simd_gt(a, b)
+}
+
+/// Floating-point compare greater than
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcgtq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vcgt.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fcmgt))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vcgtq_f16(a: float16x8_t, b: float16x8_t) -> uint16x8_t {
+    simd_gt(a, b)
+}
+
+/// Floating-point compare less than or equal
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcle_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vcge.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fcmge))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vcle_f16(a: float16x4_t, b: float16x4_t) -> uint16x4_t {
+    simd_le(a, b)
+}
+
+/// Floating-point compare less than or equal
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcleq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vcge.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fcmge))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vcleq_f16(a: float16x8_t, b: float16x8_t) -> uint16x8_t {
+    simd_le(a, b)
+}
+
+/// Floating-point compare less than
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vclt_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vcgt.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fcmgt))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vclt_f16(a: float16x4_t, b: float16x4_t) -> uint16x4_t {
+    simd_lt(a, b)
+}
+
+/// Floating-point compare less than
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcltq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vcgt.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fcmgt))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vcltq_f16(a: float16x8_t, b: float16x8_t) -> uint16x8_t {
+    simd_lt(a, b)
+}
+
+/// Floating-point absolute compare greater than or equal
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcage_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vacge.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(facge))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vcage_f16(a: float16x4_t, b: float16x4_t) -> uint16x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vacge.v4i16.v4f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.facge.v4i16.v4f16")]
+        fn vcage_f16_(a: float16x4_t, b: float16x4_t) -> uint16x4_t;
+    }
+vcage_f16_(a, b)
+}
+
+/// Floating-point absolute compare greater than or equal
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcageq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vacge.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(facge))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vcageq_f16(a: float16x8_t, b: float16x8_t) -> uint16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vacge.v8i16.v8f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.facge.v8i16.v8f16")]
+        fn vcageq_f16_(a: float16x8_t, b: float16x8_t) -> uint16x8_t;
+    }
+vcageq_f16_(a, b)
+}
+
+/// Floating-point absolute compare greater than
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcagt_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vacgt.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(facgt))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vcagt_f16(a: float16x4_t, b: float16x4_t) -> uint16x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vacgt.v4i16.v4f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.facgt.v4i16.v4f16")]
+        fn vcagt_f16_(a: float16x4_t, b: float16x4_t) -> uint16x4_t;
+    }
+vcagt_f16_(a, b)
+}
+
+/// Floating-point absolute compare greater than
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcagtq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vacgt.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(facgt))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vcagtq_f16(a: float16x8_t, b: float16x8_t) -> uint16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vacgt.v8i16.v8f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.facgt.v8i16.v8f16")]
+        fn vcagtq_f16_(a: float16x8_t, b: float16x8_t) -> uint16x8_t;
+    }
+vcagtq_f16_(a, b)
+}
+
+/// Floating-point absolute compare less than or equal
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcale_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vacge.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(facge))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vcale_f16(a: float16x4_t, b: float16x4_t) -> uint16x4_t {
+    vcage_f16(b, a)
+}
+
+/// Floating-point absolute compare less than or equal
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcaleq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vacge.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(facge))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vcaleq_f16(a: float16x8_t, b: float16x8_t) -> uint16x8_t {
+    vcageq_f16(b, a)
+}
+
+/// Floating-point absolute compare less than
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcalt_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vacgt.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(facgt))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vcalt_f16(a: float16x4_t, b: float16x4_t) -> uint16x4_t {
+    vcagt_f16(b, a)
+}
+
+/// Floating-point absolute compare less than
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcaltq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vacgt.f16"))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(facgt))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vcaltq_f16(a: float16x8_t, b: float16x8_t) -> uint16x8_t {
+    vcagtq_f16(b, a)
+}
+
+/// Floating-point convert to lower precision narrow
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcvt_f16_f32)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vcvt))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fcvtn))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vcvt_f16_f32(a: float32x4_t) -> float16x4_t {
+    simd_cast(a)
+}
+
+/// Floating-point convert to higher precision long
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcvt_f32_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vcvt))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fcvtl))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vcvt_f32_f16(a: float16x4_t) -> float32x4_t {
+    simd_cast(a)
+}
+
+/// Fixed-point convert to floating-point
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcvt_f16_s16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vcvt))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(scvtf))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vcvt_f16_s16(a: int16x4_t) -> float16x4_t {
+    simd_cast(a)
+}
+
+/// Fixed-point convert to floating-point
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcvtq_f16_s16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vcvt))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(scvtf))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vcvtq_f16_s16(a: int16x8_t) -> float16x8_t {
+    simd_cast(a)
+}
+
+/// Fixed-point convert to floating-point
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcvt_f16_u16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vcvt))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(ucvtf))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vcvt_f16_u16(a: uint16x4_t) -> float16x4_t {
+    simd_cast(a)
+}
+
+/// Fixed-point convert to floating-point
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcvtq_f16_u16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vcvt))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(ucvtf))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vcvtq_f16_u16(a: uint16x8_t) -> float16x8_t {
+    simd_cast(a)
+}
+
+/// Floating-point convert to signed fixed-point, rounding toward zero
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcvt_s16_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vcvt))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fcvtzs))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vcvt_s16_f16(a: float16x4_t) -> int16x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.fptosi.sat.v4i16.v4f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.fptosi.sat.v4i16.v4f16")]
+        fn vcvt_s16_f16_(a: float16x4_t) -> int16x4_t;
+    }
+vcvt_s16_f16_(a)
+}
+
+/// Floating-point convert to signed fixed-point, rounding toward zero
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcvtq_s16_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vcvt))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fcvtzs))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vcvtq_s16_f16(a: float16x8_t) -> int16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.fptosi.sat.v8i16.v8f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.fptosi.sat.v8i16.v8f16")]
+        fn vcvtq_s16_f16_(a: float16x8_t) -> int16x8_t;
+    }
+vcvtq_s16_f16_(a)
+}
+
+/// Floating-point convert to unsigned fixed-point, rounding toward zero
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcvt_u16_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vcvt))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fcvtzu))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vcvt_u16_f16(a: float16x4_t) -> uint16x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.fptoui.sat.v4i16.v4f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.fptoui.sat.v4i16.v4f16")]
+        fn vcvt_u16_f16_(a: float16x4_t) -> uint16x4_t;
+    }
+vcvt_u16_f16_(a)
+}
+
+/// Floating-point convert to unsigned fixed-point, rounding toward zero
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vcvtq_u16_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vcvt))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fcvtzu))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vcvtq_u16_f16(a: float16x8_t) -> uint16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.fptoui.sat.v8i16.v8f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.fptoui.sat.v8i16.v8f16")]
+        fn vcvtq_u16_f16_(a: float16x8_t) -> uint16x8_t;
+    }
+vcvtq_u16_f16_(a)
+}
+
+/// Reciprocal estimate
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vrecpe_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vrecpe))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(frecpe))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vrecpe_f16(a: float16x4_t) -> float16x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vrecpe.v4f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.frecpe.v4f16")]
+        fn vrecpe_f16_(a: float16x4_t) -> float16x4_t;
+    }
+vrecpe_f16_(a)
+}
+
+/// Reciprocal estimate
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vrecpeq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vrecpe))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(frecpe))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vrecpeq_f16(a: float16x8_t) -> float16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vrecpe.v8f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.frecpe.v8f16")]
+        fn vrecpeq_f16_(a: float16x8_t) -> float16x8_t;
+    }
+vrecpeq_f16_(a)
+}
+
+/// Floating-point reciprocal step
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vrecps_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vrecps))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(frecps))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vrecps_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vrecps.v4f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.frecps.v4f16")]
+        fn vrecps_f16_(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+    }
+vrecps_f16_(a, b)
+}
+
+/// Floating-point reciprocal step
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vrecpsq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vrecps))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(frecps))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vrecpsq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vrecps.v8f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.frecps.v8f16")]
+        fn vrecpsq_f16_(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+    }
+vrecpsq_f16_(a, b)
+}
+
+/// Reciprocal square-root estimate
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vrsqrte_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vrsqrte))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(frsqrte))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vrsqrte_f16(a: float16x4_t) -> float16x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vrsqrte.v4f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.frsqrte.v4f16")]
+        fn vrsqrte_f16_(a: float16x4_t) -> float16x4_t;
+    }
+vrsqrte_f16_(a)
+}
+
+/// Reciprocal square-root estimate
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vrsqrteq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vrsqrte))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(frsqrte))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vrsqrteq_f16(a: float16x8_t) -> float16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vrsqrte.v8f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.frsqrte.v8f16")]
+        fn vrsqrteq_f16_(a: float16x8_t) -> float16x8_t;
+    }
+vrsqrteq_f16_(a)
+}
+
+/// Floating-point reciprocal square root step
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vrsqrts_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vrsqrts))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(frsqrts))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vrsqrts_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vrsqrts.v4f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.frsqrts.v4f16")]
+        fn vrsqrts_f16_(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+    }
+vrsqrts_f16_(a, b)
+}
+
+/// Floating-point reciprocal square root step
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vrsqrtsq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vrsqrts))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(frsqrts))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vrsqrtsq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vrsqrts.v8f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.frsqrts.v8f16")]
+        fn vrsqrtsq_f16_(a: float16x8_t, b: float16x8_t) -> float16x8_t;
+    }
+vrsqrtsq_f16_(a, b)
+}
+
+/// Floating-point round to integral, to nearest with ties to even
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vrndn_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vrintn))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(frintn))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vrndn_f16(a: float16x4_t) -> float16x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vrintn.v4f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.frintn.v4f16")]
+        fn vrndn_f16_(a: float16x4_t) -> float16x4_t;
+    }
+vrndn_f16_(a)
+}
+
+/// Floating-point round to integral, to nearest with ties to even
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vrndnq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vrintn))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(frintn))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vrndnq_f16(a: float16x8_t) -> float16x8_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vrintn.v8f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.frintn.v8f16")]
+        fn vrndnq_f16_(a: float16x8_t) -> float16x8_t;
+    }
+vrndnq_f16_(a)
+}
+
+/// Floating-point add pairwise
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vpadd_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vpadd))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(faddp))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vpadd_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vpadd.v4f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.faddp.v4f16")]
+        fn vpadd_f16_(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+    }
+vpadd_f16_(a, b)
+}
+
+/// Floating-point maximum pairwise
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vpmax_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vpmax))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fmaxp))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vpmax_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vpmaxs.v4f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fmaxp.v4f16")]
+        fn vpmax_f16_(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+    }
+vpmax_f16_(a, b)
+}
+
+/// Floating-point minimum pairwise
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vpmin_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr(vpmin))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(fminp))]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vpmin_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    #[allow(improper_ctypes)]
+    extern "unadjusted" {
+        #[cfg_attr(target_arch = "arm", link_name = "llvm.arm.neon.vpmins.v4f16")]
+        #[cfg_attr(any(target_arch = "aarch64", target_arch = "arm64ec"), link_name = "llvm.aarch64.neon.fminp.v4f16")]
+        fn vpmin_f16_(a: float16x4_t, b: float16x4_t) -> float16x4_t;
+    }
+vpmin_f16_(a, b)
+}
+
+/// Set all vector lanes to the same value
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vdup_lane_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vdup.16", N = 2))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(dup, N = 2))]
+#[rustc_legacy_const_generics(1)]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vdup_lane_f16<const N: i32>(a: float16x4_t) -> float16x4_t {
+    static_assert_uimm_bits!(N, 2);
+    simd_shuffle!(a, a, [N as u32, N as u32, N as u32, N as u32])
+}
+
+/// Set all vector lanes to the same value
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vdupq_laneq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vdup.16", N = 4))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(dup, N = 4))]
+#[rustc_legacy_const_generics(1)]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vdupq_laneq_f16<const N: i32>(a: float16x8_t) -> float16x8_t {
+    static_assert_uimm_bits!(N, 3);
+    simd_shuffle!(a, a, [N as u32, N as u32, N as u32, N as u32, N as u32, N as u32, N as u32, N as u32])
+}
+
+/// Set all vector lanes to the same value
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vdup_laneq_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vdup.16", N = 4))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(dup, N = 4))]
+#[rustc_legacy_const_generics(1)]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vdup_laneq_f16<const N: i32>(a: float16x8_t) -> float16x4_t {
+    static_assert_uimm_bits!(N, 3);
+    simd_shuffle!(a, a, [N as u32, N as u32, N as u32, N as u32])
+}
+
+/// Set all vector lanes to the same value
+///
+/// [Arm's documentation](https://developer.arm.com/architectures/instruction-sets/intrinsics/vdupq_lane_f16)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v8"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vdup.16", N = 2))]
+#[cfg_attr(all(test, any(target_arch = "aarch64", target_arch = "arm64ec")), assert_instr(dup, N = 2))]
+#[rustc_legacy_const_generics(1)]
+#[cfg_attr(not(target_arch = "arm"), unstable(feature = "stdarch_neon_f16", issue = "136306"))]
+#[cfg_attr(target_arch = "arm", unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800"))]
+pub unsafe fn vdupq_lane_f16<const N: i32>(a: float16x4_t) -> float16x8_t {
+    static_assert_uimm_bits!(N, 2);
+    simd_shuffle!(a, a, [N as u32, N as u32, N as u32, N as u32, N as u32, N as u32, N as u32, N as u32])
+}
+
 #[cfg(test)]
 #[allow(overflowing_literals)]
 mod test {
@@ -46930,4 +48196,659 @@ mod test {
         let r: i32x4 = transmute(vqabsq_s32(transmute(a)));
         assert_eq!(r, e);
     }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vadd_f16() {
+        let a: f16x4 = f16x4::new(1.0, 2.0, 3.0, 4.0);
+        let b: f16x4 = f16x4::new(8.0, 7.0, 6.0, 5.0);
+        let e: f16x4 = f16x4::new(9.0, 9.0, 9.0, 9.0);
+        let r: f16x4 = transmute(vadd_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vaddq_f16() {
+        let a: f16x8 = f16x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let b: f16x8 = f16x8::new(8.0, 7.0, 6.0, 5.0, 4.0, 3.0, 2.0, 1.0);
+        let e: f16x8 = f16x8::new(9.0, 9.0, 9.0, 9.0, 9.0, 9.0, 9.0, 9.0);
+        let r: f16x8 = transmute(vaddq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vsub_f16() {
+        let a: f16x4 = f16x4::new(1.0, 4.0, 3.0, 8.0);
+        let b: f16x4 = f16x4::new(1.0, 2.0, 3.0, 4.0);
+        let e: f16x4 = f16x4::new(0.0, 2.0, 0.0, 4.0);
+        let r: f16x4 = transmute(vsub_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vsubq_f16() {
+        let a: f16x8 = f16x8::new(1.0, 4.0, 3.0, 8.0, 5.0, 12.0, 7.0, 16.0);
+        let b: f16x8 = f16x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let e: f16x8 = f16x8::new(0.0, 2.0, 0.0, 4.0, 0.0, 6.0, 0.0, 8.0);
+        let r: f16x8 = transmute(vsubq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmul_f16() {
+        let a: f16x4 = f16x4::new(1.0, 2.0, 1.0, 2.0);
+        let b: f16x4 = f16x4::new(2.0, 3.0, 4.0, 5.0);
+        let e: f16x4 = f16x4::new(2.0, 6.0, 4.0, 10.0);
+        let r: f16x4 = transmute(vmul_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmulq_f16() {
+        let a: f16x8 = f16x8::new(1.0, 2.0, 1.0, 2.0, 3.0, 4.0, 3.0, 4.0);
+        let b: f16x8 = f16x8::new(2.0, 3.0, 4.0, 5.0, 2.0, 3.0, 4.0, 5.0);
+        let e: f16x8 = f16x8::new(2.0, 6.0, 4.0, 10.0, 6.0, 12.0, 12.0, 20.0);
+        let r: f16x8 = transmute(vmulq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmul_n_f16() {
+        let a: f16x4 = f16x4::new(1.0, 2.0, 3.0, 4.0);
+        let b: f16 = 2.0;
+        let e: f16x4 = f16x4::new(2.0, 4.0, 6.0, 8.0);
+        let r: f16x4 = transmute(vmul_n_f16(transmute(a), b));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmulq_n_f16() {
+        let a: f16x8 = f16x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let b: f16 = 2.0;
+        let e: f16x8 = f16x8::new(2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0, 16.0);
+        let r: f16x8 = transmute(vmulq_n_f16(transmute(a), b));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmul_lane_f16() {
+        let a: f16x4 = f16x4::new(1.0, 2.0, 3.0, 4.0);
+        let b: f16x4 = f16x4::new(2.0, 0.0, 0.0, 0.0);
+        let e: f16x4 = f16x4::new(2.0, 4.0, 6.0, 8.0);
+        let r: f16x4 = transmute(vmul_lane_f16::<0>(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmul_laneq_f16() {
+        let a: f16x4 = f16x4::new(1.0, 2.0, 3.0, 4.0);
+        let b: f16x8 = f16x8::new(2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        let e: f16x4 = f16x4::new(2.0, 4.0, 6.0, 8.0);
+        let r: f16x4 = transmute(vmul_laneq_f16::<0>(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmulq_lane_f16() {
+        let a: f16x8 = f16x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let b: f16x4 = f16x4::new(2.0, 0.0, 0.0, 0.0);
+        let e: f16x8 = f16x8::new(2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0, 16.0);
+        let r: f16x8 = transmute(vmulq_lane_f16::<0>(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmulq_laneq_f16() {
+        let a: f16x8 = f16x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let b: f16x8 = f16x8::new(2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        let e: f16x8 = f16x8::new(2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0, 16.0);
+        let r: f16x8 = transmute(vmulq_laneq_f16::<0>(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vabs_f16() {
+        let a: f16x4 = f16x4::new(-0.5, -2.25, -3.5, -6.75);
+        let e: f16x4 = f16x4::new(0.5, 2.25, 3.5, 6.75);
+        let r: f16x4 = transmute(vabs_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vabsq_f16() {
+        let a: f16x8 = f16x8::new(-0.5, -2.25, -3.5, -6.75, 0.5, 2.25, 3.5, 6.75);
+        let e: f16x8 = f16x8::new(0.5, 2.25, 3.5, 6.75, 0.5, 2.25, 3.5, 6.75);
+        let r: f16x8 = transmute(vabsq_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vneg_f16() {
+        let a: f16x4 = f16x4::new(0.0, 1.0, -1.0, 2.0);
+        let e: f16x4 = f16x4::new(0.0, -1.0, 1.0, -2.0);
+        let r: f16x4 = transmute(vneg_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vnegq_f16() {
+        let a: f16x8 = f16x8::new(0.0, 1.0, -1.0, 2.0, -2.0, 3.0, -3.0, 4.0);
+        let e: f16x8 = f16x8::new(0.0, -1.0, 1.0, -2.0, 2.0, -3.0, 3.0, -4.0);
+        let r: f16x8 = transmute(vnegq_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vabd_f16() {
+        let a: f16x4 = f16x4::new(1.0, 2.0, 5.0, -4.0);
+        let b: f16x4 = f16x4::new(9.0, 3.0, 2.0, 8.0);
+        let e: f16x4 = f16x4::new(8.0, 1.0, 3.0, 12.0);
+        let r: f16x4 = transmute(vabd_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vabdq_f16() {
+        let a: f16x8 = f16x8::new(1.0, 2.0, 5.0, -4.0, 3.0, 0.5, -1.0, 8.0);
+        let b: f16x8 = f16x8::new(9.0, 3.0, 2.0, 8.0, 3.0, 2.0, 1.0, -8.0);
+        let e: f16x8 = f16x8::new(8.0, 1.0, 3.0, 12.0, 0.0, 1.5, 2.0, 16.0);
+        let r: f16x8 = transmute(vabdq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmax_f16() {
+        let a: f16x4 = f16x4::new(1.0, -2.0, 3.0, -4.0);
+        let b: f16x4 = f16x4::new(0.0, 3.0, 2.0, 8.0);
+        let e: f16x4 = f16x4::new(1.0, 3.0, 3.0, 8.0);
+        let r: f16x4 = transmute(vmax_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmaxq_f16() {
+        let a: f16x8 = f16x8::new(1.0, -2.0, 3.0, -4.0, 5.0, -6.0, 7.0, -8.0);
+        let b: f16x8 = f16x8::new(0.0, 3.0, 2.0, 8.0, 4.0, -7.0, 8.0, 0.0);
+        let e: f16x8 = f16x8::new(1.0, 3.0, 3.0, 8.0, 5.0, -6.0, 8.0, 0.0);
+        let r: f16x8 = transmute(vmaxq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmin_f16() {
+        let a: f16x4 = f16x4::new(1.0, -2.0, 3.0, -4.0);
+        let b: f16x4 = f16x4::new(0.0, 3.0, 2.0, 8.0);
+        let e: f16x4 = f16x4::new(0.0, -2.0, 2.0, -4.0);
+        let r: f16x4 = transmute(vmin_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vminq_f16() {
+        let a: f16x8 = f16x8::new(1.0, -2.0, 3.0, -4.0, 5.0, -6.0, 7.0, -8.0);
+        let b: f16x8 = f16x8::new(0.0, 3.0, 2.0, 8.0, 4.0, -7.0, 8.0, 0.0);
+        let e: f16x8 = f16x8::new(0.0, -2.0, 2.0, -4.0, 4.0, -7.0, 7.0, -8.0);
+        let r: f16x8 = transmute(vminq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmaxnm_f16() {
+        let a: f16x4 = f16x4::new(1.0, 2.0, 3.0, -4.0);
+        let b: f16x4 = f16x4::new(8.0, 16.0, -1.0, 6.0);
+        let e: f16x4 = f16x4::new(8.0, 16.0, 3.0, 6.0);
+        let r: f16x4 = transmute(vmaxnm_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmaxnmq_f16() {
+        let a: f16x8 = f16x8::new(1.0, 2.0, 3.0, -4.0, 5.0, 6.0, 7.0, -8.0);
+        let b: f16x8 = f16x8::new(8.0, 16.0, -1.0, 6.0, 4.0, 8.0, -2.0, 0.0);
+        let e: f16x8 = f16x8::new(8.0, 16.0, 3.0, 6.0, 5.0, 8.0, 7.0, 0.0);
+        let r: f16x8 = transmute(vmaxnmq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vminnm_f16() {
+        let a: f16x4 = f16x4::new(1.0, 2.0, 3.0, -4.0);
+        let b: f16x4 = f16x4::new(8.0, 16.0, -1.0, 6.0);
+        let e: f16x4 = f16x4::new(1.0, 2.0, -1.0, -4.0);
+        let r: f16x4 = transmute(vminnm_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vminnmq_f16() {
+        let a: f16x8 = f16x8::new(1.0, 2.0, 3.0, -4.0, 5.0, 6.0, 7.0, -8.0);
+        let b: f16x8 = f16x8::new(8.0, 16.0, -1.0, 6.0, 4.0, 8.0, -2.0, 0.0);
+        let e: f16x8 = f16x8::new(1.0, 2.0, -1.0, -4.0, 4.0, 6.0, -2.0, -8.0);
+        let r: f16x8 = transmute(vminnmq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfma_f16() {
+        let a: f16x4 = f16x4::new(8.0, 18.0, 12.0, 10.0);
+        let b: f16x4 = f16x4::new(6.0, 4.0, 7.0, 8.0);
+        let c: f16x4 = f16x4::new(2.0, 3.0, 4.0, 5.0);
+        let e: f16x4 = f16x4::new(20.0, 30.0, 40.0, 50.0);
+        let r: f16x4 = transmute(vfma_f16(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfmaq_f16() {
+        let a: f16x8 = f16x8::new(8.0, 18.0, 12.0, 10.0, 8.0, 18.0, 12.0, 10.0);
+        let b: f16x8 = f16x8::new(6.0, 4.0, 7.0, 8.0, 6.0, 4.0, 7.0, 8.0);
+        let c: f16x8 = f16x8::new(2.0, 3.0, 4.0, 5.0, 2.0, 3.0, 4.0, 5.0);
+        let e: f16x8 = f16x8::new(20.0, 30.0, 40.0, 50.0, 20.0, 30.0, 40.0, 50.0);
+        let r: f16x8 = transmute(vfmaq_f16(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfms_f16() {
+        let a: f16x4 = f16x4::new(20.0, 30.0, 40.0, 50.0);
+        let b: f16x4 = f16x4::new(6.0, 4.0, 7.0, 8.0);
+        let c: f16x4 = f16x4::new(2.0, 3.0, 4.0, 5.0);
+        let e: f16x4 = f16x4::new(8.0, 18.0, 12.0, 10.0);
+        let r: f16x4 = transmute(vfms_f16(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfmsq_f16() {
+        let a: f16x8 = f16x8::new(20.0, 30.0, 40.0, 50.0, 20.0, 30.0, 40.0, 50.0);
+        let b: f16x8 = f16x8::new(6.0, 4.0, 7.0, 8.0, 6.0, 4.0, 7.0, 8.0);
+        let c: f16x8 = f16x8::new(2.0, 3.0, 4.0, 5.0, 2.0, 3.0, 4.0, 5.0);
+        let e: f16x8 = f16x8::new(8.0, 18.0, 12.0, 10.0, 8.0, 18.0, 12.0, 10.0);
+        let r: f16x8 = transmute(vfmsq_f16(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vceq_f16() {
+        let a: f16x4 = f16x4::new(1.25, 3.5, 5.75, 7.0);
+        let b: f16x4 = f16x4::new(1.25, 3.5, 5.75, 7.0);
+        let e: u16x4 = u16x4::new(0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF);
+        let r: u16x4 = transmute(vceq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vceqq_f16() {
+        let a: f16x8 = f16x8::new(1.25, 3.5, 5.75, 7.0, 1.25, 3.5, 5.75, 7.0);
+        let b: f16x8 = f16x8::new(1.25, 3.5, 5.75, 7.0, 1.25, 3.5, 5.75, 7.0);
+        let e: u16x8 = u16x8::new(0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF);
+        let r: u16x8 = transmute(vceqq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcge_f16() {
+        let a: f16x4 = f16x4::new(1.25, 2.25, 3.5, 4.5);
+        let b: f16x4 = f16x4::new(0.25, 1.25, 2.25, 3.5);
+        let e: u16x4 = u16x4::new(0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF);
+        let r: u16x4 = transmute(vcge_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcgeq_f16() {
+        let a: f16x8 = f16x8::new(1.25, 2.25, 3.5, 4.5, 5.5, 6.75, 7.75, 8.75);
+        let b: f16x8 = f16x8::new(0.25, 1.25, 2.25, 3.5, 4.5, 5.5, 6.75, 7.75);
+        let e: u16x8 = u16x8::new(0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF);
+        let r: u16x8 = transmute(vcgeq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcgt_f16() {
+        let a: f16x4 = f16x4::new(1.25, 2.25, 3.5, 4.5);
+        let b: f16x4 = f16x4::new(0.25, 1.25, 2.25, 3.5);
+        let e: u16x4 = u16x4::new(0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF);
+        let r: u16x4 = transmute(vcgt_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcgtq_f16() {
+        let a: f16x8 = f16x8::new(1.25, 2.25, 3.5, 4.5, 5.5, 6.75, 7.75, 8.75);
+        let b: f16x8 = f16x8::new(0.25, 1.25, 2.25, 3.5, 4.5, 5.5, 6.75, 7.75);
+        let e: u16x8 = u16x8::new(0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF);
+        let r: u16x8 = transmute(vcgtq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcle_f16() {
+        let a: f16x4 = f16x4::new(0.25, 1.25, 2.25, 3.5);
+        let b: f16x4 = f16x4::new(1.25, 2.25, 3.5, 4.5);
+        let e: u16x4 = u16x4::new(0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF);
+        let r: u16x4 = transmute(vcle_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcleq_f16() {
+        let a: f16x8 = f16x8::new(0.25, 1.25, 2.25, 3.5, 4.5, 5.5, 6.75, 7.75);
+        let b: f16x8 = f16x8::new(1.25, 2.25, 3.5, 4.5, 5.5, 6.75, 7.75, 8.75);
+        let e: u16x8 = u16x8::new(0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF);
+        let r: u16x8 = transmute(vcleq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vclt_f16() {
+        let a: f16x4 = f16x4::new(0.25, 1.25, 2.25, 3.5);
+        let b: f16x4 = f16x4::new(1.25, 2.25, 3.5, 4.5);
+        let e: u16x4 = u16x4::new(0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF);
+        let r: u16x4 = transmute(vclt_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcltq_f16() {
+        let a: f16x8 = f16x8::new(0.25, 1.25, 2.25, 3.5, 4.5, 5.5, 6.75, 7.75);
+        let b: f16x8 = f16x8::new(1.25, 2.25, 3.5, 4.5, 5.5, 6.75, 7.75, 8.75);
+        let e: u16x8 = u16x8::new(0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF, 0xFF_FF);
+        let r: u16x8 = transmute(vcltq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcage_f16() {
+        let a: f16x4 = f16x4::new(-1.25, 0.0, 1.25, 2.25);
+        let b: f16x4 = f16x4::new(-1.0, 0.0, 1.0, 2.5);
+        let e: u16x4 = u16x4::new(0xFF_FF, 0xFF_FF, 0xFF_FF, 0);
+        let r: u16x4 = transmute(vcage_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcageq_f16() {
+        let a: f16x8 = f16x8::new(-1.25, 0.0, 1.25, 2.25, 3.5, 4.5, 5.5, 6.5);
+        let b: f16x8 = f16x8::new(-1.0, 0.0, 1.0, 2.5, 3.25, 4.75, 5.25, 6.75);
+        let e: u16x8 = u16x8::new(0xFF_FF, 0xFF_FF, 0xFF_FF, 0, 0xFF_FF, 0, 0xFF_FF, 0);
+        let r: u16x8 = transmute(vcageq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcagt_f16() {
+        let a: f16x4 = f16x4::new(-1.25, 0.0, 1.25, 2.25);
+        let b: f16x4 = f16x4::new(-1.0, 0.0, 1.0, 2.5);
+        let e: u16x4 = u16x4::new(0xFF_FF, 0, 0xFF_FF, 0);
+        let r: u16x4 = transmute(vcagt_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcagtq_f16() {
+        let a: f16x8 = f16x8::new(-1.25, 0.0, 1.25, 2.25, 3.5, 4.5, 5.5, 6.5);
+        let b: f16x8 = f16x8::new(-1.0, 0.0, 1.0, 2.5, 3.25, 4.75, 5.25, 6.75);
+        let e: u16x8 = u16x8::new(0xFF_FF, 0, 0xFF_FF, 0, 0xFF_FF, 0, 0xFF_FF, 0);
+        let r: u16x8 = transmute(vcagtq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcale_f16() {
+        let a: f16x4 = f16x4::new(-1.25, 0.0, 1.25, 2.25);
+        let b: f16x4 = f16x4::new(-1.0, 0.0, 1.0, 2.5);
+        let e: u16x4 = u16x4::new(0, 0xFF_FF, 0, 0xFF_FF);
+        let r: u16x4 = transmute(vcale_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcaleq_f16() {
+        let a: f16x8 = f16x8::new(-1.25, 0.0, 1.25, 2.25, 3.5, 4.5, 5.5, 6.5);
+        let b: f16x8 = f16x8::new(-1.0, 0.0, 1.0, 2.5, 3.25, 4.75, 5.25, 6.75);
+        let e: u16x8 = u16x8::new(0, 0xFF_FF, 0, 0xFF_FF, 0, 0xFF_FF, 0, 0xFF_FF);
+        let r: u16x8 = transmute(vcaleq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcalt_f16() {
+        let a: f16x4 = f16x4::new(-1.25, 0.0, 1.25, 2.25);
+        let b: f16x4 = f16x4::new(-1.0, 0.0, 1.0, 2.5);
+        let e: u16x4 = u16x4::new(0, 0, 0, 0xFF_FF);
+        let r: u16x4 = transmute(vcalt_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcaltq_f16() {
+        let a: f16x8 = f16x8::new(-1.25, 0.0, 1.25, 2.25, 3.5, 4.5, 5.5, 6.5);
+        let b: f16x8 = f16x8::new(-1.0, 0.0, 1.0, 2.5, 3.25, 4.75, 5.25, 6.75);
+        let e: u16x8 = u16x8::new(0, 0, 0, 0xFF_FF, 0, 0xFF_FF, 0, 0xFF_FF);
+        let r: u16x8 = transmute(vcaltq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvt_f16_f32() {
+        let a: f32x4 = f32x4::new(-1.5, 1.5, 2.25, 3.75);
+        let e: f16x4 = f16x4::new(-1.5, 1.5, 2.25, 3.75);
+        let r: f16x4 = transmute(vcvt_f16_f32(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvt_f32_f16() {
+        let a: f16x4 = f16x4::new(-1.5, 1.5, 2.25, 3.75);
+        let e: f32x4 = f32x4::new(-1.5, 1.5, 2.25, 3.75);
+        let r: f32x4 = transmute(vcvt_f32_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvt_f16_s16() {
+        let a: i16x4 = i16x4::new(1, 2, 3, 4);
+        let e: f16x4 = f16x4::new(1.0, 2.0, 3.0, 4.0);
+        let r: f16x4 = transmute(vcvt_f16_s16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtq_f16_s16() {
+        let a: i16x8 = i16x8::new(1, 2, 3, 4, 5, 6, 7, 8);
+        let e: f16x8 = f16x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let r: f16x8 = transmute(vcvtq_f16_s16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvt_f16_u16() {
+        let a: u16x4 = u16x4::new(1, 2, 3, 4);
+        let e: f16x4 = f16x4::new(1.0, 2.0, 3.0, 4.0);
+        let r: f16x4 = transmute(vcvt_f16_u16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtq_f16_u16() {
+        let a: u16x8 = u16x8::new(1, 2, 3, 4, 5, 6, 7, 8);
+        let e: f16x8 = f16x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let r: f16x8 = transmute(vcvtq_f16_u16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvt_s16_f16() {
+        let a: f16x4 = f16x4::new(-1.25, 2.25, -2.75, 3.75);
+        let e: i16x4 = i16x4::new(-1, 2, -2, 3);
+        let r: i16x4 = transmute(vcvt_s16_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtq_s16_f16() {
+        let a: f16x8 = f16x8::new(-1.25, 2.25, -2.75, 3.75, -4.5, 5.5, -6.0, 7.0);
+        let e: i16x8 = i16x8::new(-1, 2, -2, 3, -4, 5, -6, 7);
+        let r: i16x8 = transmute(vcvtq_s16_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvt_u16_f16() {
+        let a: f16x4 = f16x4::new(1.25, 2.25, 2.75, 3.75);
+        let e: u16x4 = u16x4::new(1, 2, 2, 3);
+        let r: u16x4 = transmute(vcvt_u16_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vcvtq_u16_f16() {
+        let a: f16x8 = f16x8::new(1.25, 2.25, 2.75, 3.75, 4.5, 5.5, 6.0, 7.0);
+        let e: u16x8 = u16x8::new(1, 2, 2, 3, 4, 5, 6, 7);
+        let r: u16x8 = transmute(vcvtq_u16_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrecpe_f16() {
+        let a: f16x4 = f16x4::new(4.0, 3.0, 2.0, 1.0);
+        let e: f16x4 = f16x4::new(0.24951171875, 0.3330078125, 0.4990234375, 0.998046875);
+        let r: f16x4 = transmute(vrecpe_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrecpeq_f16() {
+        let a: f16x8 = f16x8::new(4.0, 3.0, 2.0, 1.0, 4.0, 3.0, 2.0, 1.0);
+        let e: f16x8 = f16x8::new(0.24951171875, 0.3330078125, 0.4990234375, 0.998046875, 0.24951171875, 0.3330078125, 0.4990234375, 0.998046875);
+        let r: f16x8 = transmute(vrecpeq_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrecps_f16() {
+        let a: f16x4 = f16x4::new(4.0, 3.0, 2.0, 1.0);
+        let b: f16x4 = f16x4::new(4.0, 3.0, 2.0, 1.0);
+        let e: f16x4 = f16x4::new(-14.0, -7.0, -2.0, 1.0);
+        let r: f16x4 = transmute(vrecps_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrecpsq_f16() {
+        let a: f16x8 = f16x8::new(4.0, 3.0, 2.0, 1.0, 4.0, 3.0, 2.0, 1.0);
+        let b: f16x8 = f16x8::new(4.0, 3.0, 2.0, 1.0, 4.0, 3.0, 2.0, 1.0);
+        let e: f16x8 = f16x8::new(-14.0, -7.0, -2.0, 1.0, -14.0, -7.0, -2.0, 1.0);
+        let r: f16x8 = transmute(vrecpsq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrsqrte_f16() {
+        let a: f16x4 = f16x4::new(1.0, 2.0, 3.0, 4.0);
+        let e: f16x4 = f16x4::new(0.998046875, 0.705078125, 0.576171875, 0.4990234375);
+        let r: f16x4 = transmute(vrsqrte_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrsqrteq_f16() {
+        let a: f16x8 = f16x8::new(1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0);
+        let e: f16x8 = f16x8::new(0.998046875, 0.705078125, 0.576171875, 0.4990234375, 0.998046875, 0.705078125, 0.576171875, 0.4990234375);
+        let r: f16x8 = transmute(vrsqrteq_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrsqrts_f16() {
+        let a: f16x4 = f16x4::new(1.0, 2.0, 3.0, 4.0);
+        let b: f16x4 = f16x4::new(1.0, 2.0, 3.0, 4.0);
+        let e: f16x4 = f16x4::new(1.0, -0.5, -3.0, -6.5);
+        let r: f16x4 = transmute(vrsqrts_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrsqrtsq_f16() {
+        let a: f16x8 = f16x8::new(1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0);
+        let b: f16x8 = f16x8::new(1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0);
+        let e: f16x8 = f16x8::new(1.0, -0.5, -3.0, -6.5, 1.0, -0.5, -3.0, -6.5);
+        let r: f16x8 = transmute(vrsqrtsq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndn_f16() {
+        let a: f16x4 = f16x4::new(-1.5, 0.5, 1.5, 2.5);
+        let e: f16x4 = f16x4::new(-2.0, 0.0, 2.0, 2.0);
+        let r: f16x4 = transmute(vrndn_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vrndnq_f16() {
+        let a: f16x8 = f16x8::new(-1.5, 0.5, 1.5, 2.5, -2.5, 3.5, -0.5, 4.5);
+        let e: f16x8 = f16x8::new(-2.0, 0.0, 2.0, 2.0, -2.0, 4.0, -0.0, 4.0);
+        let r: f16x8 = transmute(vrndnq_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vpadd_f16() {
+        let a: f16x4 = f16x4::new(1.0, 2.0, 3.0, 4.0);
+        let b: f16x4 = f16x4::new(3.0, 4.0, 5.0, 6.0);
+        let e: f16x4 = f16x4::new(3.0, 7.0, 7.0, 11.0);
+        let r: f16x4 = transmute(vpadd_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vpmax_f16() {
+        let a: f16x4 = f16x4::new(1.0, -2.0, 3.0, 4.0);
+        let b: f16x4 = f16x4::new(0.0, 3.0, -4.0, 8.0);
+        let e: f16x4 = f16x4::new(1.0, 4.0, 3.0, 8.0);
+        let r: f16x4 = transmute(vpmax_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vpmin_f16() {
+        let a: f16x4 = f16x4::new(1.0, -2.0, 3.0, 4.0);
+        let b: f16x4 = f16x4::new(0.0, 3.0, -4.0, 8.0);
+        let e: f16x4 = f16x4::new(-2.0, 3.0, 0.0, -4.0);
+        let r: f16x4 = transmute(vpmin_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vdup_lane_f16() {
+        let a: f16x4 = f16x4::new(1.0, 1.0, 1.0, 4.0);
+        let e: f16x4 = f16x4::new(1.0, 1.0, 1.0, 1.0);
+        let r: f16x4 = transmute(vdup_lane_f16::<2>(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vdupq_laneq_f16() {
+        let a: f16x8 = f16x8::new(1.0, 1.0, 1.0, 4.0, 1.0, 1.0, 1.0, 4.0);
+        let e: f16x8 = f16x8::new(1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0);
+        let r: f16x8 = transmute(vdupq_laneq_f16::<4>(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vdup_laneq_f16() {
+        let a: f16x8 = f16x8::new(1.0, 1.0, 1.0, 4.0, 1.0, 1.0, 1.0, 4.0);
+        let e: f16x4 = f16x4::new(1.0, 1.0, 1.0, 1.0);
+        let r: f16x4 = transmute(vdup_laneq_f16::<4>(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vdupq_lane_f16() {
+        let a: f16x4 = f16x4::new(1.0, 1.0, 1.0, 4.0);
+        let e: f16x8 = f16x8::new(1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0);
+        let r: f16x8 = transmute(vdupq_lane_f16::<2>(transmute(a)));
+        assert_eq!(r, e);
+    }
 }
@@ -204,3 +204,19 @@ unsafe fn test_vld1q_f32() {
     let r: f32x4 = transmute(vld1q_f32(a[1..].as_ptr()));
     assert_eq!(r, e)
 }
+
+#[simd_test(enable = "neon")]
+unsafe fn test_vld1_f16() {
+    let a: [f16; 5] = [0., 1., 2., 3., 4.];
+    let e = f16x4::new(1., 2., 3., 4.);
+    let r: f16x4 = transmute(vld1_f16(a[1..].as_ptr()));
+    assert_eq!(r, e)
+}
+
+#[simd_test(enable = "neon")]
+unsafe fn test_vld1q_f16() {
+    let a: [f16; 9] = [0., 1., 2., 3., 4., 5., 6., 7., 8.];
+    let e = f16x8::new(1., 2., 3., 4., 5., 6., 7., 8.);
+    let r: f16x8 = transmute(vld1q_f16(a[1..].as_ptr()));
+    assert_eq!(r, e)
+}
@@ -30,8 +30,6 @@ types! {
     pub struct int16x4_t(4 x pub(crate) i16);
     /// ARM-specific 64-bit wide vector of four packed `u16`.
     pub struct uint16x4_t(4 x pub(crate) u16);
-    // FIXME: ARM-specific 64-bit wide vector of four packed `f16`.
-    // pub struct float16x4_t(f16, f16, f16, f16);
     /// ARM-specific 64-bit wide vector of four packed `p16`.
     pub struct poly16x4_t(4 x pub(crate) p16);
     /// ARM-specific 64-bit wide vector of two packed `i32`.
@@ -57,8 +55,6 @@ types! {
     pub struct int16x8_t(8 x pub(crate) i16);
     /// ARM-specific 128-bit wide vector of eight packed `u16`.
     pub struct uint16x8_t(8 x pub(crate) u16);
-    // FIXME: ARM-specific 128-bit wide vector of eight packed `f16`.
-    // pub struct float16x8_t(f16, f16, f16, f16, f16, f16, f16);
     /// ARM-specific 128-bit wide vector of eight packed `p16`.
     pub struct poly16x8_t(8 x pub(crate) p16);
     /// ARM-specific 128-bit wide vector of four packed `i32`.
@@ -75,6 +71,28 @@ types! {
     pub struct poly64x2_t(2 x pub(crate) p64);
 }
 
+types! {
+    #![unstable(feature = "stdarch_neon_f16", issue = "136306")]
+
+    /// ARM-specific 64-bit wide vector of four packed `f16`.
+    pub struct float16x4_t(4 x pub(crate) f16);
+    /// ARM-specific 128-bit wide vector of eight packed `f16`.
+    pub struct float16x8_t(8 x pub(crate) f16);
+}
+
+types! {
+    #![unstable(feature = "stdarch_neon_bf16", issue = "none")]
+
+    /// ARM-specific 64-bit wide vector of four packed `bf16`.
+    ///
+    /// Rust has no native `bf16` type, so the lanes hold the raw bit patterns.
+    pub struct bfloat16x4_t(4 x pub(crate) u16);
+    /// ARM-specific 128-bit wide vector of eight packed `bf16`.
+    ///
+    /// Rust has no native `bf16` type, so the lanes hold the raw bit patterns.
+    pub struct bfloat16x8_t(8 x pub(crate) u16);
+}
+
 /// ARM-specific type containing two `int8x8_t` vectors.
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
@@ -6823,6 +6841,30 @@ pub unsafe fn vgetq_lane_f32<const IMM5: i32>(v: float32x4_t) -> f32 {
     simd_extract!(v, IMM5 as u32)
 }
 
+/// Duplicate vector element to vector or scalar
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v7"))]
+#[rustc_legacy_const_generics(1)]
+#[cfg_attr(test, assert_instr(nop, IMM5 = 1))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vget_lane_f16<const IMM5: i32>(v: float16x4_t) -> f16 {
+    static_assert_uimm_bits!(IMM5, 2);
+    simd_extract!(v, IMM5 as u32)
+}
+
+/// Duplicate vector element to vector or scalar
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v7"))]
+#[rustc_legacy_const_generics(1)]
+#[cfg_attr(test, assert_instr(nop, IMM5 = 1))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vgetq_lane_f16<const IMM5: i32>(v: float16x8_t) -> f16 {
+    static_assert_uimm_bits!(IMM5, 3);
+    simd_extract!(v, IMM5 as u32)
+}
+
 /// Move vector element to general-purpose register
 #[inline]
 #[target_feature(enable = "neon")]
@@ -7339,6 +7381,34 @@ pub unsafe fn vget_high_f32(a: float32x4_t) -> float32x2_t {
     simd_shuffle!(a, a, [2, 3])
 }
 
+/// Duplicate vector element to vector or scalar
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v7"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vmov"))]
+#[cfg_attr(
+    all(test, any(target_arch = "aarch64", target_arch = "arm64ec")),
+    assert_instr(ext)
+)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vget_high_f16(a: float16x8_t) -> float16x4_t {
+    simd_shuffle!(a, a, [4, 5, 6, 7])
+}
+
+/// Duplicate vector element to vector or scalar
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v7"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vmov"))]
+#[cfg_attr(
+    all(test, any(target_arch = "aarch64", target_arch = "arm64ec")),
+    assert_instr(ext)
+)]
+#[unstable(feature = "stdarch_neon_bf16", issue = "none")]
+pub unsafe fn vget_high_bf16(a: bfloat16x8_t) -> bfloat16x4_t {
+    simd_shuffle!(a, a, [4, 5, 6, 7])
+}
+
 /// Duplicate vector element to vector or scalar
 #[inline]
 #[target_feature(enable = "neon")]
@@ -7526,6 +7596,26 @@ pub unsafe fn vget_low_f32(a: float32x4_t) -> float32x2_t {
     simd_shuffle!(a, a, [0, 1])
 }
 
+/// Duplicate vector element to vector or scalar
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v7"))]
+#[cfg_attr(test, assert_instr(nop))]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vget_low_f16(a: float16x8_t) -> float16x4_t {
+    simd_shuffle!(a, a, [0, 1, 2, 3])
+}
+
+/// Duplicate vector element to vector or scalar
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v7"))]
+#[cfg_attr(test, assert_instr(nop))]
+#[unstable(feature = "stdarch_neon_bf16", issue = "none")]
+pub unsafe fn vget_low_bf16(a: bfloat16x8_t) -> bfloat16x4_t {
+    simd_shuffle!(a, a, [0, 1, 2, 3])
+}
+
 /// Duplicate vector element to vector or scalar
 #[inline]
 #[target_feature(enable = "neon")]
@@ -7757,6 +7847,20 @@ pub unsafe fn vdupq_n_f32(value: f32) -> float32x4_t {
     float32x4_t::splat(value)
 }
 
+/// Duplicate vector element to vector or scalar
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v7"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vdup.16"))]
+#[cfg_attr(
+    all(test, any(target_arch = "aarch64", target_arch = "arm64ec")),
+    assert_instr(dup)
+)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vdupq_n_f16(value: f16) -> float16x8_t {
+    float16x8_t::splat(value)
+}
+
 /// Duplicate vector element to vector or scalar
 ///
 /// Private vfp4 version used by FMA intriniscs because LLVM does
@@ -8008,6 +8112,20 @@ pub unsafe fn vdup_n_f32(value: f32) -> float32x2_t {
     float32x2_t::splat(value)
 }
 
+/// Duplicate vector element to vector or scalar
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v7"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vdup.16"))]
+#[cfg_attr(
+    all(test, any(target_arch = "aarch64", target_arch = "arm64ec")),
+    assert_instr(dup)
+)]
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vdup_n_f16(value: f16) -> float16x4_t {
+    float16x4_t::splat(value)
+}
+
 /// Duplicate vector element to vector or scalar
 ///
 /// Private vfp4 version used by FMA intriniscs because LLVM does
@@ -9881,19 +9999,70 @@ pub unsafe fn vusmmlaq_s32(a: int32x4_t, b: uint8x16_t, c: int8x16_t) -> int32x4
     vusmmlaq_s32_(a, b, c)
 }
 
-/* FIXME: 16-bit float
 /// Vector combine
 #[inline]
 #[target_feature(enable = "neon")]
 #[cfg_attr(target_arch = "arm", target_feature(enable = "v7"))]
 #[cfg_attr(test, assert_instr(nop))]
-#[cfg_attr(
-    target_arch = "arm",
-    unstable(feature = "stdarch_arm_neon_intrinsics", issue = "111800")
-)] pub unsafe fn vcombine_f16 ( low: float16x4_t,  high: float16x4_t) -> float16x8_t {
+#[unstable(feature = "stdarch_neon_f16", issue = "136306")]
+pub unsafe fn vcombine_f16(low: float16x4_t, high: float16x4_t) -> float16x8_t {
     simd_shuffle!(low, high, [0, 1, 2, 3, 4, 5, 6, 7])
 }
-*/
+
+/// Vector combine
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v7"))]
+#[cfg_attr(test, assert_instr(nop))]
+#[unstable(feature = "stdarch_neon_bf16", issue = "none")]
+pub unsafe fn vcombine_bf16(low: bfloat16x4_t, high: bfloat16x4_t) -> bfloat16x8_t {
+    simd_shuffle!(low, high, [0, 1, 2, 3, 4, 5, 6, 7])
+}
+
+/// Convert from BFloat16 to single-precision floating-point
+///
+/// A `bf16` value is the upper half of the equivalent `f32`, so widening is an exact shift.
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v7"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vshll.u16"))]
+#[cfg_attr(
+    all(test, any(target_arch = "aarch64", target_arch = "arm64ec")),
+    assert_instr(shll)
+)]
+#[unstable(feature = "stdarch_neon_bf16", issue = "none")]
+pub unsafe fn vcvt_f32_bf16(a: bfloat16x4_t) -> float32x4_t {
+    let a: uint32x4_t = simd_cast::<_, uint32x4_t>(a);
+    transmute(simd_shl(a, vdupq_n_u32(16)))
+}
+
+/// Convert from BFloat16 to single-precision floating-point
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v7"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vshll.u16"))]
+#[cfg_attr(
+    all(test, any(target_arch = "aarch64", target_arch = "arm64ec")),
+    assert_instr(shll)
+)]
+#[unstable(feature = "stdarch_neon_bf16", issue = "none")]
+pub unsafe fn vcvtq_low_f32_bf16(a: bfloat16x8_t) -> float32x4_t {
+    vcvt_f32_bf16(vget_low_bf16(a))
+}
+
+/// Convert from BFloat16 to single-precision floating-point
+#[inline]
+#[target_feature(enable = "neon")]
+#[cfg_attr(target_arch = "arm", target_feature(enable = "v7"))]
+#[cfg_attr(all(test, target_arch = "arm"), assert_instr("vshll.u16"))]
+#[cfg_attr(
+    all(test, any(target_arch = "aarch64", target_arch = "arm64ec")),
+    assert_instr(shll2)
+)]
+#[unstable(feature = "stdarch_neon_bf16", issue = "none")]
+pub unsafe fn vcvtq_high_f32_bf16(a: bfloat16x8_t) -> float32x4_t {
+    vcvt_f32_bf16(vget_high_bf16(a))
+}
 
 /// Vector combine
 #[inline]
@@ -10749,6 +10918,24 @@ mod tests {
         assert_eq!(r, 1.0);
     }
 
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vget_lane_f16() {
+        let v = f16x4::new(0.0, 1.0, 2.0, 3.0);
+        let r = vget_lane_f16::<3>(transmute(v));
+        assert_eq!(r, 3.0);
+        let r = vget_lane_f16::<0>(transmute(v));
+        assert_eq!(r, 0.0);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vgetq_lane_f16() {
+        let v = f16x8::new(0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0);
+        let r = vgetq_lane_f16::<7>(transmute(v));
+        assert_eq!(r, 7.0);
+        let r = vgetq_lane_f16::<2>(transmute(v));
+        assert_eq!(r, 2.0);
+    }
+
     #[simd_test(enable = "neon")]
     unsafe fn test_vgetq_lane_s64() {
         let v = i64x2::new(0, 1);
@@ -10873,6 +11060,22 @@ mod tests {
         assert_eq!(r, e);
     }
 
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vget_high_f16() {
+        let a = f16x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let e = f16x4::new(5.0, 6.0, 7.0, 8.0);
+        let r: f16x4 = transmute(vget_high_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vget_high_bf16() {
+        let a = u16x8::new(1, 2, 3, 4, 5, 6, 7, 8);
+        let e = u16x4::new(5, 6, 7, 8);
+        let r: u16x4 = transmute(vget_high_bf16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
     #[simd_test(enable = "neon")]
     unsafe fn test_vget_low_s8() {
         let a = i8x16::new(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
@@ -10961,6 +11164,50 @@ mod tests {
         assert_eq!(r, e);
     }
 
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vget_low_f16() {
+        let a = f16x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let e = f16x4::new(1.0, 2.0, 3.0, 4.0);
+        let r: f16x4 = transmute(vget_low_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vget_low_bf16() {
+        let a = u16x8::new(1, 2, 3, 4, 5, 6, 7, 8);
+        let e = u16x4::new(1, 2, 3, 4);
+        let r: u16x4 = transmute(vget_low_bf16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vcvt_f32_bf16() {
+        let a = u16x4::new(0x3F80, 0xC000, 0x4040, 0x7F80);
+        let e = f32x4::new(1.0, -2.0, 3.0, f32::INFINITY);
+        let r: f32x4 = transmute(vcvt_f32_bf16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vcvtq_low_f32_bf16() {
+        let a = u16x8::new(
+            0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100,
+        );
+        let e = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let r: f32x4 = transmute(vcvtq_low_f32_bf16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vcvtq_high_f32_bf16() {
+        let a = u16x8::new(
+            0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100,
+        );
+        let e = f32x4::new(5.0, 6.0, 7.0, 8.0);
+        let r: f32x4 = transmute(vcvtq_high_f32_bf16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
     #[simd_test(enable = "neon")]
     unsafe fn test_vdupq_n_s8() {
         let v: i8 = 42;
@@ -11055,6 +11302,14 @@ mod tests {
         assert_eq!(r, e);
     }
 
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vdupq_n_f16() {
+        let v: f16 = 64.0;
+        let e = f16x8::new(64.0, 64.0, 64.0, 64.0, 64.0, 64.0, 64.0, 64.0);
+        let r: f16x8 = transmute(vdupq_n_f16(v));
+        assert_eq!(r, e);
+    }
+
     #[simd_test(enable = "neon")]
     unsafe fn test_vdup_n_s8() {
         let v: i8 = 64;
@@ -11143,6 +11398,14 @@ mod tests {
         assert_eq!(r, e);
     }
 
+    #[simd_test(enable = "neon")]
+    unsafe fn test_vdup_n_f16() {
+        let v: f16 = 64.0;
+        let e = f16x4::new(64.0, 64.0, 64.0, 64.0);
+        let r: f16x4 = transmute(vdup_n_f16(v));
+        assert_eq!(r, e);
+    }
+
     #[simd_test(enable = "neon")]
     unsafe fn test_vldrq_p128() {
         let v: [p128; 2] = [1, 2];
@@ -15712,9 +15975,8 @@ mod tests {
     test_vcombine!(test_vcombine_s16 => vcombine_s16([3_i16, -4, 5, -6], [13_i16, -14, 15, -16]));
     test_vcombine!(test_vcombine_u16 => vcombine_u16([3_u16, 4, 5, 6], [13_u16, 14, 15, 16]));
     test_vcombine!(test_vcombine_p16 => vcombine_p16([3_u16, 4, 5, 6], [13_u16, 14, 15, 16]));
-    // FIXME: 16-bit floats
-    // test_vcombine!(test_vcombine_f16 => vcombine_f16([3_f16, 4., 5., 6.],
-    // [13_f16, 14., 15., 16.]));
+    test_vcombine!(test_vcombine_f16 => vcombine_f16([3_f16, 4., 5., 6.], [13_f16, 14., 15., 16.]));
+    test_vcombine!(test_vcombine_bf16 => vcombine_bf16([3_u16, 4, 5, 6], [13_u16, 14, 15, 16]));
 
     test_vcombine!(test_vcombine_s32 => vcombine_s32([3_i32, -4], [13_i32, -14]));
     test_vcombine!(test_vcombine_u32 => vcombine_u32([3_u32, 4], [13_u32, 14]));
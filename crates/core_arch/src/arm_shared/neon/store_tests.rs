@@ -387,3 +387,30 @@ unsafe fn test_vst1q_f32() {
     assert_eq!(vals[3], 3.);
     assert_eq!(vals[4], 4.);
 }
+
+#[simd_test(enable = "neon")]
+unsafe fn test_vst1_f16() {
+    let mut vals = [0_f16; 5];
+    let a = f16x4::new(1., 2., 3., 4.);
+
+    vst1_f16(vals[1..].as_mut_ptr(), transmute(a));
+
+    assert_eq!(vals[0], 0.);
+    assert_eq!(vals[1], 1.);
+    assert_eq!(vals[2], 2.);
+    assert_eq!(vals[3], 3.);
+    assert_eq!(vals[4], 4.);
+}
+
+#[simd_test(enable = "neon")]
+unsafe fn test_vst1q_f16() {
+    let mut vals = [0_f16; 9];
+    let a = f16x8::new(1., 2., 3., 4., 5., 6., 7., 8.);
+
+    vst1q_f16(vals[1..].as_mut_ptr(), transmute(a));
+
+    assert_eq!(vals[0], 0.);
+    assert_eq!(vals[1], 1.);
+    assert_eq!(vals[4], 4.);
+    assert_eq!(vals[8], 8.);
+}
@@ -22,6 +22,7 @@
     sse4a_target_feature,
     riscv_target_feature,
     arm_target_feature,
+    aarch64_unstable_target_feature,
     avx512_target_feature,
     mips_target_feature,
     powerpc_target_feature,
@@ -36,9 +37,24 @@
     asm_experimental_arch,
     sha512_sm_x86,
     x86_amx_intrinsics,
+    keylocker_x86,
+    xop_target_feature,
+    fma4_target_feature,
     f16
 )]
-#![cfg_attr(test, feature(test, abi_vectorcall, stdarch_internal))]
+#![cfg_attr(
+    test,
+    feature(test, abi_vectorcall, stdarch_internal, avx10_target_feature)
+)]
+#![cfg_attr(
+    all(test, any(target_arch = "x86", target_arch = "x86_64")),
+    feature(
+        stdarch_x86_system,
+        stdarch_x86_wait,
+        stdarch_x86_memory,
+        stdarch_x86_atomics
+    )
+)]
 #![deny(clippy::missing_inline_in_public_items)]
 #![allow(
     clippy::identity_op,
@@ -72,6 +88,10 @@
         stdarch_loongarch_feature_detection
     )
 )]
+#![cfg_attr(
+    all(test, any(target_arch = "riscv32", target_arch = "riscv64")),
+    feature(stdarch_riscv_feature_detection)
+)]
 
 #[cfg(test)]
 #[macro_use]
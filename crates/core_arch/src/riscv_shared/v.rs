@@ -0,0 +1,1403 @@
+//! RISC-V Vector extension (RVV 1.0) intrinsics
+//!
+//! ## Vector register types
+//!
+//! The RISC-V vector intrinsics defined by the [RVV C intrinsic specification] operate on sizeless
+//! types such as `vint32m1_t`, whose size depends on the `VLEN` of the hart executing the code.
+//! Rust has no way to represent such scalable types yet, so the loads, stores, arithmetic,
+//! reductions and mask operations that take or return vector register values cannot be provided.
+//!
+//! What is provided here is the part of the interface that only deals with scalar values: the
+//! `vsetvl` / `vsetvlmax` family used for strip-mining loops and querying the vector register
+//! length. Code that needs the vector register operations can pair these with a single inline
+//! assembly block per loop body.
+//!
+//! [RVV C intrinsic specification]: https://github.com/riscv-non-isa/rvv-intrinsic-doc
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+#[cfg(target_arch = "riscv32")]
+extern "unadjusted" {
+    #[link_name = "llvm.riscv.vsetvli.i32"]
+    fn _vsetvli_32(avl: i32, sew: i32, lmul: i32) -> i32;
+
+    #[link_name = "llvm.riscv.vsetvlimax.i32"]
+    fn _vsetvlimax_32(sew: i32, lmul: i32) -> i32;
+}
+
+#[cfg(target_arch = "riscv64")]
+extern "unadjusted" {
+    #[link_name = "llvm.riscv.vsetvli.i64"]
+    fn _vsetvli_64(avl: i64, sew: i64, lmul: i64) -> i64;
+
+    #[link_name = "llvm.riscv.vsetvlimax.i64"]
+    fn _vsetvlimax_64(sew: i64, lmul: i64) -> i64;
+}
+
+/// Reads the `vlenb` CSR
+///
+/// Returns the vector register length in bytes, `VLEN / 8`, of the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 3.6
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(csrr))]
+#[inline]
+pub unsafe fn vlenb() -> usize {
+    let value: usize;
+    // csrr value, vlenb
+    asm!("csrr {}, 0xC22", out(reg) value, options(nomem, nostack, preserves_flags));
+    value
+}
+
+/// Sets the vector length for 8-bit elements with `LMUL = 1/8`
+///
+/// Returns the number of elements, at most `avl`, that a vector instruction operating with
+/// `SEW = 8` and `LMUL = 1/8` processes on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.1
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvl_e8mf8(avl: usize) -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvli_32(avl as i32, 0, 5) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvli_64(avl as i64, 0, 5) as usize
+    }
+}
+
+/// Returns the maximum vector length for 8-bit elements with `LMUL = 1/8`
+///
+/// Returns `VLMAX`, the number of elements held by a vector register group with `SEW = 8` and
+/// `LMUL = 1/8` on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.3
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvlmax_e8mf8() -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvlimax_32(0, 5) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvlimax_64(0, 5) as usize
+    }
+}
+
+/// Sets the vector length for 8-bit elements with `LMUL = 1/4`
+///
+/// Returns the number of elements, at most `avl`, that a vector instruction operating with
+/// `SEW = 8` and `LMUL = 1/4` processes on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.1
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvl_e8mf4(avl: usize) -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvli_32(avl as i32, 0, 6) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvli_64(avl as i64, 0, 6) as usize
+    }
+}
+
+/// Returns the maximum vector length for 8-bit elements with `LMUL = 1/4`
+///
+/// Returns `VLMAX`, the number of elements held by a vector register group with `SEW = 8` and
+/// `LMUL = 1/4` on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.3
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvlmax_e8mf4() -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvlimax_32(0, 6) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvlimax_64(0, 6) as usize
+    }
+}
+
+/// Sets the vector length for 8-bit elements with `LMUL = 1/2`
+///
+/// Returns the number of elements, at most `avl`, that a vector instruction operating with
+/// `SEW = 8` and `LMUL = 1/2` processes on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.1
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvl_e8mf2(avl: usize) -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvli_32(avl as i32, 0, 7) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvli_64(avl as i64, 0, 7) as usize
+    }
+}
+
+/// Returns the maximum vector length for 8-bit elements with `LMUL = 1/2`
+///
+/// Returns `VLMAX`, the number of elements held by a vector register group with `SEW = 8` and
+/// `LMUL = 1/2` on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.3
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvlmax_e8mf2() -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvlimax_32(0, 7) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvlimax_64(0, 7) as usize
+    }
+}
+
+/// Sets the vector length for 8-bit elements with `LMUL = 1`
+///
+/// Returns the number of elements, at most `avl`, that a vector instruction operating with
+/// `SEW = 8` and `LMUL = 1` processes on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.1
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvl_e8m1(avl: usize) -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvli_32(avl as i32, 0, 0) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvli_64(avl as i64, 0, 0) as usize
+    }
+}
+
+/// Returns the maximum vector length for 8-bit elements with `LMUL = 1`
+///
+/// Returns `VLMAX`, the number of elements held by a vector register group with `SEW = 8` and
+/// `LMUL = 1` on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.3
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvlmax_e8m1() -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvlimax_32(0, 0) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvlimax_64(0, 0) as usize
+    }
+}
+
+/// Sets the vector length for 8-bit elements with `LMUL = 2`
+///
+/// Returns the number of elements, at most `avl`, that a vector instruction operating with
+/// `SEW = 8` and `LMUL = 2` processes on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.1
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvl_e8m2(avl: usize) -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvli_32(avl as i32, 0, 1) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvli_64(avl as i64, 0, 1) as usize
+    }
+}
+
+/// Returns the maximum vector length for 8-bit elements with `LMUL = 2`
+///
+/// Returns `VLMAX`, the number of elements held by a vector register group with `SEW = 8` and
+/// `LMUL = 2` on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.3
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvlmax_e8m2() -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvlimax_32(0, 1) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvlimax_64(0, 1) as usize
+    }
+}
+
+/// Sets the vector length for 8-bit elements with `LMUL = 4`
+///
+/// Returns the number of elements, at most `avl`, that a vector instruction operating with
+/// `SEW = 8` and `LMUL = 4` processes on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.1
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvl_e8m4(avl: usize) -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvli_32(avl as i32, 0, 2) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvli_64(avl as i64, 0, 2) as usize
+    }
+}
+
+/// Returns the maximum vector length for 8-bit elements with `LMUL = 4`
+///
+/// Returns `VLMAX`, the number of elements held by a vector register group with `SEW = 8` and
+/// `LMUL = 4` on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.3
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvlmax_e8m4() -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvlimax_32(0, 2) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvlimax_64(0, 2) as usize
+    }
+}
+
+/// Sets the vector length for 8-bit elements with `LMUL = 8`
+///
+/// Returns the number of elements, at most `avl`, that a vector instruction operating with
+/// `SEW = 8` and `LMUL = 8` processes on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.1
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvl_e8m8(avl: usize) -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvli_32(avl as i32, 0, 3) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvli_64(avl as i64, 0, 3) as usize
+    }
+}
+
+/// Returns the maximum vector length for 8-bit elements with `LMUL = 8`
+///
+/// Returns `VLMAX`, the number of elements held by a vector register group with `SEW = 8` and
+/// `LMUL = 8` on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.3
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvlmax_e8m8() -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvlimax_32(0, 3) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvlimax_64(0, 3) as usize
+    }
+}
+
+/// Sets the vector length for 16-bit elements with `LMUL = 1/4`
+///
+/// Returns the number of elements, at most `avl`, that a vector instruction operating with
+/// `SEW = 16` and `LMUL = 1/4` processes on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.1
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvl_e16mf4(avl: usize) -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvli_32(avl as i32, 1, 6) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvli_64(avl as i64, 1, 6) as usize
+    }
+}
+
+/// Returns the maximum vector length for 16-bit elements with `LMUL = 1/4`
+///
+/// Returns `VLMAX`, the number of elements held by a vector register group with `SEW = 16` and
+/// `LMUL = 1/4` on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.3
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvlmax_e16mf4() -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvlimax_32(1, 6) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvlimax_64(1, 6) as usize
+    }
+}
+
+/// Sets the vector length for 16-bit elements with `LMUL = 1/2`
+///
+/// Returns the number of elements, at most `avl`, that a vector instruction operating with
+/// `SEW = 16` and `LMUL = 1/2` processes on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.1
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvl_e16mf2(avl: usize) -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvli_32(avl as i32, 1, 7) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvli_64(avl as i64, 1, 7) as usize
+    }
+}
+
+/// Returns the maximum vector length for 16-bit elements with `LMUL = 1/2`
+///
+/// Returns `VLMAX`, the number of elements held by a vector register group with `SEW = 16` and
+/// `LMUL = 1/2` on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.3
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvlmax_e16mf2() -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvlimax_32(1, 7) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvlimax_64(1, 7) as usize
+    }
+}
+
+/// Sets the vector length for 16-bit elements with `LMUL = 1`
+///
+/// Returns the number of elements, at most `avl`, that a vector instruction operating with
+/// `SEW = 16` and `LMUL = 1` processes on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.1
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvl_e16m1(avl: usize) -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvli_32(avl as i32, 1, 0) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvli_64(avl as i64, 1, 0) as usize
+    }
+}
+
+/// Returns the maximum vector length for 16-bit elements with `LMUL = 1`
+///
+/// Returns `VLMAX`, the number of elements held by a vector register group with `SEW = 16` and
+/// `LMUL = 1` on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.3
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvlmax_e16m1() -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvlimax_32(1, 0) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvlimax_64(1, 0) as usize
+    }
+}
+
+/// Sets the vector length for 16-bit elements with `LMUL = 2`
+///
+/// Returns the number of elements, at most `avl`, that a vector instruction operating with
+/// `SEW = 16` and `LMUL = 2` processes on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.1
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvl_e16m2(avl: usize) -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvli_32(avl as i32, 1, 1) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvli_64(avl as i64, 1, 1) as usize
+    }
+}
+
+/// Returns the maximum vector length for 16-bit elements with `LMUL = 2`
+///
+/// Returns `VLMAX`, the number of elements held by a vector register group with `SEW = 16` and
+/// `LMUL = 2` on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.3
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvlmax_e16m2() -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvlimax_32(1, 1) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvlimax_64(1, 1) as usize
+    }
+}
+
+/// Sets the vector length for 16-bit elements with `LMUL = 4`
+///
+/// Returns the number of elements, at most `avl`, that a vector instruction operating with
+/// `SEW = 16` and `LMUL = 4` processes on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.1
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvl_e16m4(avl: usize) -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvli_32(avl as i32, 1, 2) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvli_64(avl as i64, 1, 2) as usize
+    }
+}
+
+/// Returns the maximum vector length for 16-bit elements with `LMUL = 4`
+///
+/// Returns `VLMAX`, the number of elements held by a vector register group with `SEW = 16` and
+/// `LMUL = 4` on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.3
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvlmax_e16m4() -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvlimax_32(1, 2) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvlimax_64(1, 2) as usize
+    }
+}
+
+/// Sets the vector length for 16-bit elements with `LMUL = 8`
+///
+/// Returns the number of elements, at most `avl`, that a vector instruction operating with
+/// `SEW = 16` and `LMUL = 8` processes on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.1
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvl_e16m8(avl: usize) -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvli_32(avl as i32, 1, 3) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvli_64(avl as i64, 1, 3) as usize
+    }
+}
+
+/// Returns the maximum vector length for 16-bit elements with `LMUL = 8`
+///
+/// Returns `VLMAX`, the number of elements held by a vector register group with `SEW = 16` and
+/// `LMUL = 8` on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.3
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvlmax_e16m8() -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvlimax_32(1, 3) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvlimax_64(1, 3) as usize
+    }
+}
+
+/// Sets the vector length for 32-bit elements with `LMUL = 1/2`
+///
+/// Returns the number of elements, at most `avl`, that a vector instruction operating with
+/// `SEW = 32` and `LMUL = 1/2` processes on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.1
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvl_e32mf2(avl: usize) -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvli_32(avl as i32, 2, 7) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvli_64(avl as i64, 2, 7) as usize
+    }
+}
+
+/// Returns the maximum vector length for 32-bit elements with `LMUL = 1/2`
+///
+/// Returns `VLMAX`, the number of elements held by a vector register group with `SEW = 32` and
+/// `LMUL = 1/2` on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.3
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvlmax_e32mf2() -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvlimax_32(2, 7) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvlimax_64(2, 7) as usize
+    }
+}
+
+/// Sets the vector length for 32-bit elements with `LMUL = 1`
+///
+/// Returns the number of elements, at most `avl`, that a vector instruction operating with
+/// `SEW = 32` and `LMUL = 1` processes on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.1
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvl_e32m1(avl: usize) -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvli_32(avl as i32, 2, 0) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvli_64(avl as i64, 2, 0) as usize
+    }
+}
+
+/// Returns the maximum vector length for 32-bit elements with `LMUL = 1`
+///
+/// Returns `VLMAX`, the number of elements held by a vector register group with `SEW = 32` and
+/// `LMUL = 1` on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.3
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvlmax_e32m1() -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvlimax_32(2, 0) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvlimax_64(2, 0) as usize
+    }
+}
+
+/// Sets the vector length for 32-bit elements with `LMUL = 2`
+///
+/// Returns the number of elements, at most `avl`, that a vector instruction operating with
+/// `SEW = 32` and `LMUL = 2` processes on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.1
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvl_e32m2(avl: usize) -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvli_32(avl as i32, 2, 1) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvli_64(avl as i64, 2, 1) as usize
+    }
+}
+
+/// Returns the maximum vector length for 32-bit elements with `LMUL = 2`
+///
+/// Returns `VLMAX`, the number of elements held by a vector register group with `SEW = 32` and
+/// `LMUL = 2` on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.3
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvlmax_e32m2() -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvlimax_32(2, 1) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvlimax_64(2, 1) as usize
+    }
+}
+
+/// Sets the vector length for 32-bit elements with `LMUL = 4`
+///
+/// Returns the number of elements, at most `avl`, that a vector instruction operating with
+/// `SEW = 32` and `LMUL = 4` processes on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.1
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvl_e32m4(avl: usize) -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvli_32(avl as i32, 2, 2) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvli_64(avl as i64, 2, 2) as usize
+    }
+}
+
+/// Returns the maximum vector length for 32-bit elements with `LMUL = 4`
+///
+/// Returns `VLMAX`, the number of elements held by a vector register group with `SEW = 32` and
+/// `LMUL = 4` on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.3
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvlmax_e32m4() -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvlimax_32(2, 2) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvlimax_64(2, 2) as usize
+    }
+}
+
+/// Sets the vector length for 32-bit elements with `LMUL = 8`
+///
+/// Returns the number of elements, at most `avl`, that a vector instruction operating with
+/// `SEW = 32` and `LMUL = 8` processes on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.1
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvl_e32m8(avl: usize) -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvli_32(avl as i32, 2, 3) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvli_64(avl as i64, 2, 3) as usize
+    }
+}
+
+/// Returns the maximum vector length for 32-bit elements with `LMUL = 8`
+///
+/// Returns `VLMAX`, the number of elements held by a vector register group with `SEW = 32` and
+/// `LMUL = 8` on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.3
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvlmax_e32m8() -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvlimax_32(2, 3) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvlimax_64(2, 3) as usize
+    }
+}
+
+/// Sets the vector length for 64-bit elements with `LMUL = 1`
+///
+/// Returns the number of elements, at most `avl`, that a vector instruction operating with
+/// `SEW = 64` and `LMUL = 1` processes on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.1
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvl_e64m1(avl: usize) -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvli_32(avl as i32, 3, 0) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvli_64(avl as i64, 3, 0) as usize
+    }
+}
+
+/// Returns the maximum vector length for 64-bit elements with `LMUL = 1`
+///
+/// Returns `VLMAX`, the number of elements held by a vector register group with `SEW = 64` and
+/// `LMUL = 1` on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.3
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvlmax_e64m1() -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvlimax_32(3, 0) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvlimax_64(3, 0) as usize
+    }
+}
+
+/// Sets the vector length for 64-bit elements with `LMUL = 2`
+///
+/// Returns the number of elements, at most `avl`, that a vector instruction operating with
+/// `SEW = 64` and `LMUL = 2` processes on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.1
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvl_e64m2(avl: usize) -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvli_32(avl as i32, 3, 1) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvli_64(avl as i64, 3, 1) as usize
+    }
+}
+
+/// Returns the maximum vector length for 64-bit elements with `LMUL = 2`
+///
+/// Returns `VLMAX`, the number of elements held by a vector register group with `SEW = 64` and
+/// `LMUL = 2` on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.3
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvlmax_e64m2() -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvlimax_32(3, 1) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvlimax_64(3, 1) as usize
+    }
+}
+
+/// Sets the vector length for 64-bit elements with `LMUL = 4`
+///
+/// Returns the number of elements, at most `avl`, that a vector instruction operating with
+/// `SEW = 64` and `LMUL = 4` processes on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.1
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvl_e64m4(avl: usize) -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvli_32(avl as i32, 3, 2) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvli_64(avl as i64, 3, 2) as usize
+    }
+}
+
+/// Returns the maximum vector length for 64-bit elements with `LMUL = 4`
+///
+/// Returns `VLMAX`, the number of elements held by a vector register group with `SEW = 64` and
+/// `LMUL = 4` on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.3
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvlmax_e64m4() -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvlimax_32(3, 2) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvlimax_64(3, 2) as usize
+    }
+}
+
+/// Sets the vector length for 64-bit elements with `LMUL = 8`
+///
+/// Returns the number of elements, at most `avl`, that a vector instruction operating with
+/// `SEW = 64` and `LMUL = 8` processes on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.1
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvl_e64m8(avl: usize) -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvli_32(avl as i32, 3, 3) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvli_64(avl as i64, 3, 3) as usize
+    }
+}
+
+/// Returns the maximum vector length for 64-bit elements with `LMUL = 8`
+///
+/// Returns `VLMAX`, the number of elements held by a vector register group with `SEW = 64` and
+/// `LMUL = 8` on the current hart.
+///
+/// Source: RISC-V "V" Standard Extension for Vector Operations
+///
+/// Version: v1.0
+///
+/// Section: 6.3
+///
+/// # Safety
+///
+/// This function is safe to use if the `v` target feature is present.
+#[unstable(feature = "riscv_ext_intrinsics", issue = "114544")]
+#[target_feature(enable = "v")]
+#[cfg_attr(test, assert_instr(vsetvli))]
+#[inline]
+pub unsafe fn vsetvlmax_e64m8() -> usize {
+    #[cfg(target_arch = "riscv32")]
+    {
+        _vsetvlimax_32(3, 3) as usize
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        _vsetvlimax_64(3, 3) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use stdarch_test::simd_test;
+
+    use super::*;
+
+    #[simd_test(enable = "v")]
+    unsafe fn test_vsetvlmax_e8m1() {
+        // With `SEW = 8` and `LMUL = 1`, a vector register holds one element per byte.
+        assert_eq!(vsetvlmax_e8m1(), vlenb());
+    }
+
+    #[simd_test(enable = "v")]
+    unsafe fn test_vsetvl_e8m1() {
+        let vlmax = vsetvlmax_e8m1();
+        assert_eq!(vsetvl_e8m1(1), 1);
+        assert_eq!(vsetvl_e8m1(vlmax), vlmax);
+        assert_eq!(vsetvl_e8m1(usize::MAX), vlmax);
+    }
+}
@@ -121,6 +121,7 @@ simd_ty!(i16x4[i16;4]: x0, x1, x2, x3);
 simd_ty!(i32x2[i32;2]: x0, x1);
 simd_ty!(i64x1[i64;1]: x1);
 
+simd_ty!(f16x4[f16;4]: x0, x1, x2, x3);
 simd_ty!(f32x2[f32;2]: x0, x1);
 simd_ty!(f64x1[f64;1]: x1);
 
@@ -59611,4 +59611,14 @@ mod tests {
         let e = _mm_set_pd(0., 0.);
         assert_eq_m128d(r, e);
     }
+
+    // `avx10.1` implies the AVX-512 features it converges, so the AVX-512
+    // intrinsics can be called after checking for AVX10 alone.
+    #[simd_test(enable = "avx10.1")]
+    unsafe fn test_avx10_1_implies_avx512() {
+        let a = _mm256_set1_epi32(1);
+        let r = _mm256_mask_add_epi32(a, 0b01010101, a, a);
+        let e = _mm256_setr_epi32(2, 1, 2, 1, 2, 1, 2, 1);
+        assert_eq_m256i(r, e);
+    }
 }
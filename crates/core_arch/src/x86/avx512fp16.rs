@@ -138,6 +138,48 @@ pub unsafe fn _mm512_set1_ph(a: f16) -> __m512h {
     transmute(f16x32::splat(a))
 }
 
+/// Broadcast the complex number `re + i * im` to all complex numbers of dst. Each complex number is
+/// composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex
+/// number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// Rust has no complex type, so the real and imaginary parts are passed separately.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_set1_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[unstable(feature = "stdarch_x86_avx512_f16", issue = "127213")]
+pub unsafe fn _mm_set1_pch(re: f16, im: f16) -> __m128h {
+    transmute(u32x4::splat(transmute::<[f16; 2], u32>([re, im])))
+}
+
+/// Broadcast the complex number `re + i * im` to all complex numbers of dst. Each complex number is
+/// composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex
+/// number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// Rust has no complex type, so the real and imaginary parts are passed separately.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_set1_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[unstable(feature = "stdarch_x86_avx512_f16", issue = "127213")]
+pub unsafe fn _mm256_set1_pch(re: f16, im: f16) -> __m256h {
+    transmute(u32x8::splat(transmute::<[f16; 2], u32>([re, im])))
+}
+
+/// Broadcast the complex number `re + i * im` to all complex numbers of dst. Each complex number is
+/// composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex
+/// number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// Rust has no complex type, so the real and imaginary parts are passed separately.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_set1_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[unstable(feature = "stdarch_x86_avx512_f16", issue = "127213")]
+pub unsafe fn _mm512_set1_pch(re: f16, im: f16) -> __m512h {
+    transmute(u32x16::splat(transmute::<[f16; 2], u32>([re, im])))
+}
+
 /// Set packed half-precision (16-bit) floating-point elements in dst with the supplied values in reverse order.
 ///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_setr_ph)
@@ -16447,6 +16489,32 @@ mod tests {
         assert_eq_m512h(r, e);
     }
 
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_set1_pch() {
+        let r = _mm_set1_pch(1.0, 2.0);
+        let e = _mm_setr_ph(1.0, 2.0, 1.0, 2.0, 1.0, 2.0, 1.0, 2.0);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm256_set1_pch() {
+        let r = _mm256_set1_pch(1.0, 2.0);
+        let e = _mm256_setr_ph(
+            1.0, 2.0, 1.0, 2.0, 1.0, 2.0, 1.0, 2.0, 1.0, 2.0, 1.0, 2.0, 1.0, 2.0, 1.0, 2.0,
+        );
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_set1_pch() {
+        let r = _mm512_set1_pch(1.0, 2.0);
+        let e = _mm512_setr_ph(
+            1.0, 2.0, 1.0, 2.0, 1.0, 2.0, 1.0, 2.0, 1.0, 2.0, 1.0, 2.0, 1.0, 2.0, 1.0, 2.0, 1.0,
+            2.0, 1.0, 2.0, 1.0, 2.0, 1.0, 2.0, 1.0, 2.0, 1.0, 2.0, 1.0, 2.0, 1.0, 2.0,
+        );
+        assert_eq_m512h(r, e);
+    }
+
     #[simd_test(enable = "avx512fp16")]
     unsafe fn test_mm_setr_ph() {
         let r = _mm_setr_ph(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
@@ -0,0 +1,216 @@
+//! AVX-512 VP2INTERSECT
+//!
+//! The intrinsics here correspond to those in the `immintrin.h` C header.
+//!
+//! `VP2INTERSECTD` and `VP2INTERSECTQ` write their result to an even/odd pair
+//! of mask registers. The intrinsics return the two masks through the `k1` and
+//! `k2` pointers, like their C counterparts.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use crate::core_arch::{simd::*, x86::*};
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Compute intersection of packed 32-bit integer vectors a and b, and store indication of match
+/// in the corresponding bit of two mask registers specified by k1 and k2. A match in corresponding
+/// elements of a and b is indicated by a set bit in the corresponding bit of the mask registers.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_2intersect_epi32)
+#[inline]
+#[target_feature(enable = "avx512vp2intersect,avx512f")]
+#[unstable(feature = "stdarch_x86_avx512", issue = "111137")]
+#[cfg_attr(test, assert_instr(vp2intersectd))]
+pub unsafe fn _mm512_2intersect_epi32(
+    a: __m512i,
+    b: __m512i,
+    k1: *mut __mmask16,
+    k2: *mut __mmask16,
+) {
+    let (m1, m2) = vp2intersectd_512(a.as_i32x16(), b.as_i32x16());
+    *k1 = m1;
+    *k2 = m2;
+}
+
+/// Compute intersection of packed 64-bit integer vectors a and b, and store indication of match
+/// in the corresponding bit of two mask registers specified by k1 and k2. A match in corresponding
+/// elements of a and b is indicated by a set bit in the corresponding bit of the mask registers.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_2intersect_epi64)
+#[inline]
+#[target_feature(enable = "avx512vp2intersect,avx512f")]
+#[unstable(feature = "stdarch_x86_avx512", issue = "111137")]
+#[cfg_attr(test, assert_instr(vp2intersectq))]
+pub unsafe fn _mm512_2intersect_epi64(
+    a: __m512i,
+    b: __m512i,
+    k1: *mut __mmask8,
+    k2: *mut __mmask8,
+) {
+    let (m1, m2) = vp2intersectq_512(a.as_i64x8(), b.as_i64x8());
+    *k1 = m1;
+    *k2 = m2;
+}
+
+/// Compute intersection of packed 32-bit integer vectors a and b, and store indication of match
+/// in the corresponding bit of two mask registers specified by k1 and k2. A match in corresponding
+/// elements of a and b is indicated by a set bit in the corresponding bit of the mask registers.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_2intersect_epi32)
+#[inline]
+#[target_feature(enable = "avx512vp2intersect,avx512vl")]
+#[unstable(feature = "stdarch_x86_avx512", issue = "111137")]
+#[cfg_attr(test, assert_instr(vp2intersectd))]
+pub unsafe fn _mm256_2intersect_epi32(
+    a: __m256i,
+    b: __m256i,
+    k1: *mut __mmask8,
+    k2: *mut __mmask8,
+) {
+    let (m1, m2) = vp2intersectd_256(a.as_i32x8(), b.as_i32x8());
+    *k1 = m1;
+    *k2 = m2;
+}
+
+/// Compute intersection of packed 64-bit integer vectors a and b, and store indication of match
+/// in the corresponding bit of two mask registers specified by k1 and k2. A match in corresponding
+/// elements of a and b is indicated by a set bit in the corresponding bit of the mask registers.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_2intersect_epi64)
+#[inline]
+#[target_feature(enable = "avx512vp2intersect,avx512vl")]
+#[unstable(feature = "stdarch_x86_avx512", issue = "111137")]
+#[cfg_attr(test, assert_instr(vp2intersectq))]
+pub unsafe fn _mm256_2intersect_epi64(
+    a: __m256i,
+    b: __m256i,
+    k1: *mut __mmask8,
+    k2: *mut __mmask8,
+) {
+    let (m1, m2) = vp2intersectq_256(a.as_i64x4(), b.as_i64x4());
+    *k1 = m1;
+    *k2 = m2;
+}
+
+/// Compute intersection of packed 32-bit integer vectors a and b, and store indication of match
+/// in the corresponding bit of two mask registers specified by k1 and k2. A match in corresponding
+/// elements of a and b is indicated by a set bit in the corresponding bit of the mask registers.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_2intersect_epi32)
+#[inline]
+#[target_feature(enable = "avx512vp2intersect,avx512vl")]
+#[unstable(feature = "stdarch_x86_avx512", issue = "111137")]
+#[cfg_attr(test, assert_instr(vp2intersectd))]
+pub unsafe fn _mm_2intersect_epi32(a: __m128i, b: __m128i, k1: *mut __mmask8, k2: *mut __mmask8) {
+    let (m1, m2) = vp2intersectd_128(a.as_i32x4(), b.as_i32x4());
+    *k1 = m1;
+    *k2 = m2;
+}
+
+/// Compute intersection of packed 64-bit integer vectors a and b, and store indication of match
+/// in the corresponding bit of two mask registers specified by k1 and k2. A match in corresponding
+/// elements of a and b is indicated by a set bit in the corresponding bit of the mask registers.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_2intersect_epi64)
+#[inline]
+#[target_feature(enable = "avx512vp2intersect,avx512vl")]
+#[unstable(feature = "stdarch_x86_avx512", issue = "111137")]
+#[cfg_attr(test, assert_instr(vp2intersectq))]
+pub unsafe fn _mm_2intersect_epi64(a: __m128i, b: __m128i, k1: *mut __mmask8, k2: *mut __mmask8) {
+    let (m1, m2) = vp2intersectq_128(a.as_i64x2(), b.as_i64x2());
+    *k1 = m1;
+    *k2 = m2;
+}
+
+#[allow(improper_ctypes)]
+extern "unadjusted" {
+    #[link_name = "llvm.x86.avx512.vp2intersect.d.512"]
+    fn vp2intersectd_512(a: i32x16, b: i32x16) -> (__mmask16, __mmask16);
+    #[link_name = "llvm.x86.avx512.vp2intersect.q.512"]
+    fn vp2intersectq_512(a: i64x8, b: i64x8) -> (__mmask8, __mmask8);
+    #[link_name = "llvm.x86.avx512.vp2intersect.d.256"]
+    fn vp2intersectd_256(a: i32x8, b: i32x8) -> (__mmask8, __mmask8);
+    #[link_name = "llvm.x86.avx512.vp2intersect.q.256"]
+    fn vp2intersectq_256(a: i64x4, b: i64x4) -> (__mmask8, __mmask8);
+    #[link_name = "llvm.x86.avx512.vp2intersect.d.128"]
+    fn vp2intersectd_128(a: i32x4, b: i32x4) -> (__mmask8, __mmask8);
+    #[link_name = "llvm.x86.avx512.vp2intersect.q.128"]
+    fn vp2intersectq_128(a: i64x2, b: i64x2) -> (__mmask8, __mmask8);
+}
+
+#[cfg(test)]
+mod tests {
+    use stdarch_test::simd_test;
+
+    use crate::core_arch::x86::*;
+
+    #[simd_test(enable = "avx512vp2intersect,avx512f")]
+    unsafe fn test_mm512_2intersect_epi32() {
+        let a = _mm512_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        let b = _mm512_setr_epi32(0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30);
+        let mut k1: __mmask16 = 0;
+        let mut k2: __mmask16 = 0;
+        _mm512_2intersect_epi32(a, b, &mut k1, &mut k2);
+        assert_eq!(k1, 0b01010101_01010101);
+        assert_eq!(k2, 0b00000000_11111111);
+    }
+
+    #[simd_test(enable = "avx512vp2intersect,avx512f")]
+    unsafe fn test_mm512_2intersect_epi64() {
+        let a = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, 6, 7);
+        let b = _mm512_setr_epi64(0, 2, 4, 6, 8, 10, 12, 14);
+        let mut k1: __mmask8 = 0;
+        let mut k2: __mmask8 = 0;
+        _mm512_2intersect_epi64(a, b, &mut k1, &mut k2);
+        assert_eq!(k1, 0b01010101);
+        assert_eq!(k2, 0b00001111);
+    }
+
+    #[simd_test(enable = "avx512vp2intersect,avx512vl")]
+    unsafe fn test_mm256_2intersect_epi32() {
+        let a = _mm256_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7);
+        let b = _mm256_setr_epi32(0, 2, 4, 6, 8, 10, 12, 14);
+        let mut k1: __mmask8 = 0;
+        let mut k2: __mmask8 = 0;
+        _mm256_2intersect_epi32(a, b, &mut k1, &mut k2);
+        assert_eq!(k1, 0b01010101);
+        assert_eq!(k2, 0b00001111);
+    }
+
+    #[simd_test(enable = "avx512vp2intersect,avx512vl")]
+    unsafe fn test_mm256_2intersect_epi64() {
+        let a = _mm256_setr_epi64x(0, 1, 2, 3);
+        let b = _mm256_setr_epi64x(0, 2, 4, 6);
+        let mut k1: __mmask8 = 0;
+        let mut k2: __mmask8 = 0;
+        _mm256_2intersect_epi64(a, b, &mut k1, &mut k2);
+        assert_eq!(k1, 0b0101);
+        assert_eq!(k2, 0b0011);
+    }
+
+    #[simd_test(enable = "avx512vp2intersect,avx512vl")]
+    unsafe fn test_mm_2intersect_epi32() {
+        let a = _mm_setr_epi32(0, 1, 2, 3);
+        let b = _mm_setr_epi32(0, 2, 4, 6);
+        let mut k1: __mmask8 = 0;
+        let mut k2: __mmask8 = 0;
+        _mm_2intersect_epi32(a, b, &mut k1, &mut k2);
+        assert_eq!(k1, 0b0101);
+        assert_eq!(k2, 0b0011);
+    }
+
+    #[simd_test(enable = "avx512vp2intersect,avx512vl")]
+    unsafe fn test_mm_2intersect_epi64() {
+        let a = _mm_set_epi64x(1, 0);
+        let b = _mm_set_epi64x(2, 0);
+        let mut k1: __mmask8 = 0;
+        let mut k2: __mmask8 = 0;
+        _mm_2intersect_epi64(a, b, &mut k1, &mut k2);
+        assert_eq!(k1, 0b01);
+        assert_eq!(k2, 0b01);
+    }
+}
@@ -0,0 +1,242 @@
+//! Control-flow Enforcement Technology (CET) shadow stack intrinsics.
+//!
+//! The intrinsics here correspond to those in the `cetintrin.h` C header.
+//!
+//! `RDSSP` and `INCSSP` are encoded in the NOP space, so on processors without
+//! CET, or when shadow stacks are not enabled for the current process, they
+//! do nothing. All the other instructions raise `#UD` in that case. Use
+//! `is_x86_feature_detected!("shstk")` to check whether shadow stacks are
+//! enabled before using them.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 1: Basic Architecture][intel64_ref], Chapter 17.
+//!
+//! [intel64_ref]: https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html
+
+use crate::arch::asm;
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+// x32 wants to use a 32-bit address size, but asm! defaults to using the full
+// register name (e.g. rax). We have to explicitly override the placeholder to
+// use the 32-bit register name in that case.
+#[cfg(target_pointer_width = "32")]
+macro_rules! ss {
+    ($inst:expr) => {
+        concat!($inst, " [{p:e}]")
+    };
+    ($inst:expr, $val:expr) => {
+        concat!($inst, " [{p:e}], ", $val)
+    };
+}
+#[cfg(target_pointer_width = "64")]
+macro_rules! ss {
+    ($inst:expr) => {
+        concat!($inst, " [{p}]")
+    };
+    ($inst:expr, $val:expr) => {
+        concat!($inst, " [{p}], ", $val)
+    };
+}
+
+/// Increment the shadow stack pointer by 4 times the value specified in bits
+/// 7:0 of `a`.
+///
+/// This function is always inlined: a function that moves its own shadow
+/// stack pointer would fault with `#CP` when it returns.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_incsspd)
+#[inline(always)]
+#[cfg_attr(test, assert_instr(incsspd))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _incsspd(a: i32) {
+    asm!("incsspd {:e}", in(reg) a, options(nostack, preserves_flags));
+}
+
+/// Increment the shadow stack pointer by the size of a shadow stack entry
+/// (4 bytes on x86, 8 bytes on x86_64) times the value specified in bits 7:0
+/// of `a`.
+///
+/// Like [`_incsspd`], this function is always inlined.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_inc_ssp)
+#[inline(always)]
+#[cfg_attr(all(test, target_arch = "x86"), assert_instr(incsspd))]
+#[cfg_attr(all(test, target_arch = "x86_64"), assert_instr(incsspq))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _inc_ssp(a: u32) {
+    #[cfg(target_arch = "x86")]
+    asm!("incsspd {:e}", in(reg) a, options(nostack, preserves_flags));
+    #[cfg(target_arch = "x86_64")]
+    asm!("incsspq {}", in(reg) a as u64, options(nostack, preserves_flags));
+}
+
+/// Read the low 32 bits of the current shadow stack pointer. If shadow stacks
+/// are not enabled, `a` is returned unchanged.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_rdsspd_i32)
+#[inline]
+#[cfg_attr(test, assert_instr(rdsspd))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _rdsspd_i32(a: u32) -> u32 {
+    let mut r = a;
+    asm!("rdsspd {:e}", inout(reg) r, options(nomem, nostack, preserves_flags));
+    r
+}
+
+/// Read the current shadow stack pointer. Returns 0 if shadow stacks are not
+/// enabled.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_get_ssp)
+#[inline]
+#[cfg_attr(all(test, target_arch = "x86"), assert_instr(rdsspd))]
+#[cfg_attr(all(test, target_arch = "x86_64"), assert_instr(rdsspq))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _get_ssp() -> usize {
+    #[cfg(target_arch = "x86")]
+    {
+        _rdsspd_i32(0) as usize
+    }
+    #[cfg(target_arch = "x86_64")]
+    {
+        crate::core_arch::x86_64::_rdsspq_i64(0) as usize
+    }
+}
+
+/// Save the previous shadow stack pointer context, by pushing a restore token
+/// for it onto the previous shadow stack.
+///
+/// This function is always inlined, because it also pops the previous shadow
+/// stack token that `_rstorssp` left on the current shadow stack.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_saveprevssp)
+#[inline(always)]
+#[cfg_attr(test, assert_instr(saveprevssp))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _saveprevssp() {
+    asm!("saveprevssp", options(nostack));
+}
+
+/// Restore the shadow stack pointer from the shadow stack restore token
+/// pointed to by `p`.
+///
+/// This function is always inlined, because returning from it would check the
+/// return address against the new shadow stack.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_rstorssp)
+#[inline(always)]
+#[cfg_attr(test, assert_instr(rstorssp))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _rstorssp(p: *mut u8) {
+    asm!(ss!("rstorssp"), p = in(reg) p, options(nostack));
+}
+
+/// Write the 32-bit value `val` to the shadow stack memory pointed to by `p`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_wrssd)
+#[inline]
+#[cfg_attr(test, assert_instr(wrssd))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _wrssd(val: i32, p: *mut u8) {
+    asm!(
+        ss!("wrssd", "{val:e}"),
+        p = in(reg) p,
+        val = in(reg) val,
+        options(nostack, preserves_flags),
+    );
+}
+
+/// Write the 32-bit value `val` to the user shadow stack memory pointed to by
+/// `p`.
+///
+/// This instruction can only be executed at privilege level 0.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_wrussd)
+#[inline]
+#[cfg_attr(test, assert_instr(wrussd))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _wrussd(val: i32, p: *mut u8) {
+    asm!(
+        ss!("wrussd", "{val:e}"),
+        p = in(reg) p,
+        val = in(reg) val,
+        options(nostack, preserves_flags),
+    );
+}
+
+/// Mark the supervisor shadow stack token pointed to by `IA32_PL0_SSP` as
+/// busy, and load it into the shadow stack pointer.
+///
+/// This instruction can only be executed at privilege level 0.
+///
+/// This function is always inlined, because it replaces the shadow stack
+/// pointer of the calling function.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_setssbsy)
+#[inline(always)]
+#[cfg_attr(test, assert_instr(setssbsy))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _setssbsy() {
+    asm!("setssbsy", options(nostack));
+}
+
+/// Clear the busy flag of the supervisor shadow stack token pointed to by
+/// `p`.
+///
+/// This instruction can only be executed at privilege level 0.
+///
+/// This function is always inlined, like the other instructions that switch
+/// shadow stacks, so that it runs on the shadow stack of its caller.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_clrssbsy)
+#[inline(always)]
+#[cfg_attr(test, assert_instr(clrssbsy))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _clrssbsy(p: *mut u8) {
+    asm!(ss!("clrssbsy"), p = in(reg) p, options(nostack));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    #[test]
+    fn test_get_ssp() {
+        let ssp = unsafe { _get_ssp() };
+        if is_x86_feature_detected!("shstk") {
+            assert_ne!(ssp, 0);
+        } else {
+            assert_eq!(ssp, 0);
+        }
+    }
+
+    #[test]
+    fn test_rdsspd_i32() {
+        let ssp = unsafe { _rdsspd_i32(0) };
+        if is_x86_feature_detected!("shstk") {
+            assert_ne!(ssp, 0);
+        } else {
+            // `RDSSPD` is a NOP without shadow stacks.
+            assert_eq!(ssp, 0);
+            assert_eq!(unsafe { _rdsspd_i32(42) }, 42);
+        }
+    }
+
+    #[test]
+    fn test_incsspd() {
+        // Popping entries off an active shadow stack would break our own
+        // returns, so only check that this is a NOP without shadow stacks.
+        if !is_x86_feature_detected!("shstk") {
+            unsafe { _incsspd(1) };
+            assert_eq!(unsafe { _get_ssp() }, 0);
+        }
+    }
+
+    #[test]
+    fn test_inc_ssp() {
+        if !is_x86_feature_detected!("shstk") {
+            unsafe { _inc_ssp(1) };
+            assert_eq!(unsafe { _get_ssp() }, 0);
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//! Cache line demote (CLDEMOTE).
+//!
+//! The intrinsics here correspond to those in the `cldemoteintrin.h` C header.
+//!
+//! `CLDEMOTE` is encoded in the NOP space, so it does nothing on processors
+//! that do not support it.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Hints to the hardware that the cache line that contains `p` should be moved
+/// ("demoted") from the cache closest to the processor core to a level more
+/// distant from it, such as the shared last level cache. This can speed up
+/// subsequent accesses to the cache line by other cores.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cldemote)
+#[inline]
+#[cfg_attr(test, assert_instr(cldemote))]
+#[unstable(feature = "stdarch_x86_memory", issue = "44839")]
+pub unsafe fn _mm_cldemote(p: *const u8) {
+    // x32 wants to use a 32-bit address size, but asm! defaults to using the
+    // full register name (e.g. rax).
+    #[cfg(target_pointer_width = "32")]
+    asm!("cldemote [{:e}]", in(reg) p, options(nostack, preserves_flags));
+    #[cfg(target_pointer_width = "64")]
+    asm!("cldemote [{}]", in(reg) p, options(nostack, preserves_flags));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    #[test]
+    fn test_mm_cldemote() {
+        // `CLDEMOTE` is a NOP on processors that do not support it, so there
+        // is no need to check for the feature.
+        let x = 42u64;
+        unsafe {
+            _mm_cldemote(&x as *const u64 as *const u8);
+        }
+        assert_eq!(x, 42);
+    }
+}
@@ -0,0 +1,58 @@
+//! Optimized cache line flush (CLFLUSHOPT).
+//!
+//! The intrinsics here correspond to those in the `clflushoptintrin.h` C
+//! header.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Invalidates and flushes the cache line that contains `p` from all levels of
+/// the cache hierarchy.
+///
+/// Unlike [`_mm_clflush`](crate::core_arch::x86::_mm_clflush), this is only
+/// ordered with respect to fences, locked instructions and older writes to
+/// the same cache line, so flushes of several cache lines can proceed in
+/// parallel. Use [`_mm_sfence`](crate::core_arch::x86::_mm_sfence) to wait
+/// for them to complete.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_clflushopt)
+#[inline]
+#[cfg_attr(test, assert_instr(clflushopt))]
+#[unstable(feature = "stdarch_x86_memory", issue = "44839")]
+pub unsafe fn _mm_clflushopt(p: *const u8) {
+    // x32 wants to use a 32-bit address size, but asm! defaults to using the
+    // full register name (e.g. rax).
+    #[cfg(target_pointer_width = "32")]
+    asm!("clflushopt [{:e}]", in(reg) p, options(nostack, preserves_flags));
+    #[cfg(target_pointer_width = "64")]
+    asm!("clflushopt [{}]", in(reg) p, options(nostack, preserves_flags));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    #[test]
+    fn test_mm_clflushopt() {
+        if !is_x86_feature_detected!("clflushopt") {
+            return;
+        }
+        let mut x = [0u8; 128];
+        x[0] = 1;
+        x[64] = 2;
+        unsafe {
+            _mm_clflushopt(x.as_ptr());
+            _mm_clflushopt(x.as_ptr().add(64));
+            _mm_sfence();
+        }
+        assert_eq!(x[0], 1);
+        assert_eq!(x[64], 2);
+    }
+}
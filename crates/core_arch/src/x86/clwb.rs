@@ -0,0 +1,54 @@
+//! Cache line write back (CLWB).
+//!
+//! The intrinsics here correspond to those in the `clwbintrin.h` C header.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Writes back the cache line that contains `p` to memory, if it has been
+/// modified. The cache line may be retained in the cache hierarchy.
+///
+/// This is typically used to make writes to persistent memory durable. Like
+/// [`_mm_clflushopt`](crate::core_arch::x86::_mm_clflushopt), it is only
+/// ordered with respect to fences, locked instructions and older writes to
+/// the same cache line, so it should be followed by
+/// [`_mm_sfence`](crate::core_arch::x86::_mm_sfence) before any writes that
+/// must not become durable first.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_clwb)
+#[inline]
+#[cfg_attr(test, assert_instr(clwb))]
+#[unstable(feature = "stdarch_x86_memory", issue = "44839")]
+pub unsafe fn _mm_clwb(p: *const u8) {
+    // x32 wants to use a 32-bit address size, but asm! defaults to using the
+    // full register name (e.g. rax).
+    #[cfg(target_pointer_width = "32")]
+    asm!("clwb [{:e}]", in(reg) p, options(nostack, preserves_flags));
+    #[cfg(target_pointer_width = "64")]
+    asm!("clwb [{}]", in(reg) p, options(nostack, preserves_flags));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    #[test]
+    fn test_mm_clwb() {
+        if !is_x86_feature_detected!("clwb") {
+            return;
+        }
+        let x = 42u64;
+        unsafe {
+            _mm_clwb(&x as *const u64 as *const u8);
+            _mm_sfence();
+        }
+        assert_eq!(x, 42);
+    }
+}
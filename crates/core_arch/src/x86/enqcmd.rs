@@ -0,0 +1,83 @@
+//! Enqueue store instructions (ENQCMD and ENQCMDS).
+//!
+//! The intrinsics here correspond to those in the `enqcmdintrin.h` C header.
+//!
+//! These submit a 64-byte command descriptor to the shared work queue of a
+//! device, such as the Intel Data Streaming Accelerator, through a
+//! memory-mapped enqueue register, and report whether the device accepted it.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+// x32 wants to use a 32-bit address size, but asm! defaults to using the full
+// register name (e.g. rax). We have to explicitly override the placeholder to
+// use the 32-bit register name in that case.
+#[cfg(target_pointer_width = "32")]
+macro_rules! enqcmd {
+    ($inst:expr) => {
+        concat!($inst, " {dst:e}, [{src:e}]")
+    };
+}
+#[cfg(target_pointer_width = "64")]
+macro_rules! enqcmd {
+    ($inst:expr) => {
+        concat!($inst, " {dst}, [{src}]")
+    };
+}
+
+/// Enqueue the 64-byte command descriptor pointed to by `src` to the device
+/// enqueue register pointed to by `dst`, using the PASID of the current
+/// process. `dst` must be 64-byte aligned.
+///
+/// Returns 0 if the command was accepted by the device, and 1 if it was
+/// rejected and needs to be retried.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_enqcmd)
+#[inline]
+#[cfg_attr(test, assert_instr(enqcmd))]
+#[unstable(feature = "stdarch_x86_memory", issue = "44839")]
+pub unsafe fn _enqcmd(dst: *mut u8, src: *const u8) -> i32 {
+    let r: u8;
+    asm!(
+        enqcmd!("enqcmd"),
+        "setz {r}",
+        dst = in(reg) dst,
+        src = in(reg) src,
+        r = out(reg_byte) r,
+        options(nostack),
+    );
+    r as i32
+}
+
+/// Enqueue the 64-byte command descriptor pointed to by `src` to the device
+/// enqueue register pointed to by `dst`, using the PASID and privilege
+/// specified in the descriptor. `dst` must be 64-byte aligned.
+///
+/// Returns 0 if the command was accepted by the device, and 1 if it was
+/// rejected and needs to be retried.
+///
+/// This instruction can only be executed at privilege level 0.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_enqcmds)
+#[inline]
+#[cfg_attr(test, assert_instr(enqcmds))]
+#[unstable(feature = "stdarch_x86_memory", issue = "44839")]
+pub unsafe fn _enqcmds(dst: *mut u8, src: *const u8) -> i32 {
+    let r: u8;
+    asm!(
+        enqcmd!("enqcmds"),
+        "setz {r}",
+        dst = in(reg) dst,
+        src = in(reg) src,
+        r = out(reg_byte) r,
+        options(nostack),
+    );
+    r as i32
+}
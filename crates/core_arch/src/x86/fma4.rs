@@ -0,0 +1,590 @@
+//! AMD Four-operand Fused Multiply-Add instruction set (FMA4)
+//!
+//! FMA4 is AMD's four-operand form of fused multiply-add, available on
+//! processors of the Bulldozer family. Unlike the three-operand FMA
+//! instructions, the destination is separate from the sources.
+//!
+//! The intrinsics here correspond to those in the `fma4intrin.h` C header,
+//! which names them after the XOP multiply-accumulate intrinsics rather than
+//! the FMA ones.
+//!
+//! The reference is [AMD64 Architecture Programmer's Manual, Volume 6: 128-Bit
+//! and 256-Bit XOP and FMA4 Instructions][amd64_ref].
+//!
+//! [amd64_ref]: https://www.amd.com/content/dam/amd/en/documents/archived-tech-docs/programmer-references/43479.pdf
+
+use crate::core_arch::x86::*;
+use crate::intrinsics::simd::{simd_fma, simd_neg};
+use crate::intrinsics::{fmaf32, fmaf64};
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Multiplies packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and add the intermediate result to packed elements in `c`.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmaddps))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm_macc_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    simd_fma(a, b, c)
+}
+
+/// Multiplies packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and add the intermediate result to packed elements in `c`.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmaddpd))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm_macc_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    simd_fma(a, b, c)
+}
+
+/// Multiplies packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and add the intermediate result to packed elements in `c`.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmaddps))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm256_macc_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    simd_fma(a, b, c)
+}
+
+/// Multiplies packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and add the intermediate result to packed elements in `c`.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmaddpd))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm256_macc_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    simd_fma(a, b, c)
+}
+
+/// Multiplies the lower single-precision (32-bit) floating-point elements in
+/// `a` and `b`, and add the intermediate result to the lower element in `c`.
+/// Stores the result in the lower element of the returned value, and
+/// zeroes the upper elements.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmaddss))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm_macc_ss(a: __m128, b: __m128, c: __m128) -> __m128 {
+    simd_insert!(
+        _mm_setzero_ps(),
+        0,
+        fmaf32(_mm_cvtss_f32(a), _mm_cvtss_f32(b), _mm_cvtss_f32(c))
+    )
+}
+
+/// Multiplies the lower double-precision (64-bit) floating-point elements in
+/// `a` and `b`, and add the intermediate result to the lower element in `c`.
+/// Stores the result in the lower element of the returned value, and
+/// zeroes the upper elements.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmaddsd))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm_macc_sd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    simd_insert!(
+        _mm_setzero_pd(),
+        0,
+        fmaf64(_mm_cvtsd_f64(a), _mm_cvtsd_f64(b), _mm_cvtsd_f64(c))
+    )
+}
+
+/// Multiplies packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and subtract packed elements in `c` from the intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmsubps))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm_msub_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    simd_fma(a, b, simd_neg(c))
+}
+
+/// Multiplies packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and subtract packed elements in `c` from the intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmsubpd))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm_msub_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    simd_fma(a, b, simd_neg(c))
+}
+
+/// Multiplies packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and subtract packed elements in `c` from the intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmsubps))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm256_msub_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    simd_fma(a, b, simd_neg(c))
+}
+
+/// Multiplies packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and subtract packed elements in `c` from the intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmsubpd))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm256_msub_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    simd_fma(a, b, simd_neg(c))
+}
+
+/// Multiplies the lower single-precision (32-bit) floating-point elements in
+/// `a` and `b`, and subtract the lower element in `c` from the intermediate result.
+/// Stores the result in the lower element of the returned value, and
+/// zeroes the upper elements.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmsubss))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm_msub_ss(a: __m128, b: __m128, c: __m128) -> __m128 {
+    simd_insert!(
+        _mm_setzero_ps(),
+        0,
+        fmaf32(_mm_cvtss_f32(a), _mm_cvtss_f32(b), -_mm_cvtss_f32(c))
+    )
+}
+
+/// Multiplies the lower double-precision (64-bit) floating-point elements in
+/// `a` and `b`, and subtract the lower element in `c` from the intermediate result.
+/// Stores the result in the lower element of the returned value, and
+/// zeroes the upper elements.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmsubsd))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm_msub_sd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    simd_insert!(
+        _mm_setzero_pd(),
+        0,
+        fmaf64(_mm_cvtsd_f64(a), _mm_cvtsd_f64(b), -_mm_cvtsd_f64(c))
+    )
+}
+
+/// Multiplies packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and add the negated intermediate result to packed elements in `c`.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfnmaddps))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm_nmacc_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    simd_fma(simd_neg(a), b, c)
+}
+
+/// Multiplies packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and add the negated intermediate result to packed elements in `c`.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfnmaddpd))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm_nmacc_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    simd_fma(simd_neg(a), b, c)
+}
+
+/// Multiplies packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and add the negated intermediate result to packed elements in `c`.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfnmaddps))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm256_nmacc_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    simd_fma(simd_neg(a), b, c)
+}
+
+/// Multiplies packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and add the negated intermediate result to packed elements in `c`.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfnmaddpd))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm256_nmacc_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    simd_fma(simd_neg(a), b, c)
+}
+
+/// Multiplies the lower single-precision (32-bit) floating-point elements in
+/// `a` and `b`, and add the negated intermediate result to the lower element in `c`.
+/// Stores the result in the lower element of the returned value, and
+/// zeroes the upper elements.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfnmaddss))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm_nmacc_ss(a: __m128, b: __m128, c: __m128) -> __m128 {
+    simd_insert!(
+        _mm_setzero_ps(),
+        0,
+        fmaf32(-_mm_cvtss_f32(a), _mm_cvtss_f32(b), _mm_cvtss_f32(c))
+    )
+}
+
+/// Multiplies the lower double-precision (64-bit) floating-point elements in
+/// `a` and `b`, and add the negated intermediate result to the lower element in `c`.
+/// Stores the result in the lower element of the returned value, and
+/// zeroes the upper elements.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfnmaddsd))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm_nmacc_sd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    simd_insert!(
+        _mm_setzero_pd(),
+        0,
+        fmaf64(-_mm_cvtsd_f64(a), _mm_cvtsd_f64(b), _mm_cvtsd_f64(c))
+    )
+}
+
+/// Multiplies packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and subtract packed elements in `c` from the negated intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfnmsubps))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm_nmsub_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    simd_fma(simd_neg(a), b, simd_neg(c))
+}
+
+/// Multiplies packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and subtract packed elements in `c` from the negated intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfnmsubpd))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm_nmsub_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    simd_fma(simd_neg(a), b, simd_neg(c))
+}
+
+/// Multiplies packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and subtract packed elements in `c` from the negated intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfnmsubps))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm256_nmsub_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    simd_fma(simd_neg(a), b, simd_neg(c))
+}
+
+/// Multiplies packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and subtract packed elements in `c` from the negated intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfnmsubpd))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm256_nmsub_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    simd_fma(simd_neg(a), b, simd_neg(c))
+}
+
+/// Multiplies the lower single-precision (32-bit) floating-point elements in
+/// `a` and `b`, and subtract the lower element in `c` from the negated intermediate result.
+/// Stores the result in the lower element of the returned value, and
+/// zeroes the upper elements.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfnmsubss))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm_nmsub_ss(a: __m128, b: __m128, c: __m128) -> __m128 {
+    simd_insert!(
+        _mm_setzero_ps(),
+        0,
+        fmaf32(-_mm_cvtss_f32(a), _mm_cvtss_f32(b), -_mm_cvtss_f32(c))
+    )
+}
+
+/// Multiplies the lower double-precision (64-bit) floating-point elements in
+/// `a` and `b`, and subtract the lower element in `c` from the negated intermediate result.
+/// Stores the result in the lower element of the returned value, and
+/// zeroes the upper elements.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfnmsubsd))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm_nmsub_sd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    simd_insert!(
+        _mm_setzero_pd(),
+        0,
+        fmaf64(-_mm_cvtsd_f64(a), _mm_cvtsd_f64(b), -_mm_cvtsd_f64(c))
+    )
+}
+
+/// Multiplies packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and alternatively subtract and add packed elements in `c`
+/// from/to the intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmaddsubps))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm_maddsub_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    let add = simd_fma(a, b, c);
+    let sub = simd_fma(a, b, simd_neg(c));
+    simd_shuffle!(add, sub, [4, 1, 6, 3])
+}
+
+/// Multiplies packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and alternatively subtract and add packed elements in `c`
+/// from/to the intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmaddsubpd))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm_maddsub_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    let add = simd_fma(a, b, c);
+    let sub = simd_fma(a, b, simd_neg(c));
+    simd_shuffle!(add, sub, [2, 1])
+}
+
+/// Multiplies packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and alternatively subtract and add packed elements in `c`
+/// from/to the intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmaddsubps))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm256_maddsub_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    let add = simd_fma(a, b, c);
+    let sub = simd_fma(a, b, simd_neg(c));
+    simd_shuffle!(add, sub, [8, 1, 10, 3, 12, 5, 14, 7])
+}
+
+/// Multiplies packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and alternatively subtract and add packed elements in `c`
+/// from/to the intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmaddsubpd))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm256_maddsub_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    let add = simd_fma(a, b, c);
+    let sub = simd_fma(a, b, simd_neg(c));
+    simd_shuffle!(add, sub, [4, 1, 6, 3])
+}
+
+/// Multiplies packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and alternatively add and subtract packed elements in `c`
+/// to/from the intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmsubaddps))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm_msubadd_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    let add = simd_fma(a, b, c);
+    let sub = simd_fma(a, b, simd_neg(c));
+    simd_shuffle!(add, sub, [0, 5, 2, 7])
+}
+
+/// Multiplies packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and alternatively add and subtract packed elements in `c`
+/// to/from the intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmsubaddpd))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm_msubadd_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    let add = simd_fma(a, b, c);
+    let sub = simd_fma(a, b, simd_neg(c));
+    simd_shuffle!(add, sub, [0, 3])
+}
+
+/// Multiplies packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and alternatively add and subtract packed elements in `c`
+/// to/from the intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmsubaddps))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm256_msubadd_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    let add = simd_fma(a, b, c);
+    let sub = simd_fma(a, b, simd_neg(c));
+    simd_shuffle!(add, sub, [0, 9, 2, 11, 4, 13, 6, 15])
+}
+
+/// Multiplies packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and alternatively add and subtract packed elements in `c`
+/// to/from the intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmsubaddpd))]
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub unsafe fn _mm256_msubadd_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    let add = simd_fma(a, b, c);
+    let sub = simd_fma(a, b, simd_neg(c));
+    simd_shuffle!(add, sub, [0, 5, 2, 7])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use stdarch_test::simd_test;
+
+    #[simd_test(enable = "fma4")]
+    unsafe fn test_mm_macc_ps() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_setr_ps(9., 15., 22., 15.);
+        assert_eq_m128(_mm_macc_ps(a, b, c), r);
+    }
+
+    #[simd_test(enable = "fma4")]
+    unsafe fn test_mm_macc_pd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_setr_pd(9., 15.);
+        assert_eq_m128d(_mm_macc_pd(a, b, c), r);
+    }
+
+    #[simd_test(enable = "fma4")]
+    unsafe fn test_mm256_macc_ps() {
+        let a = _mm256_setr_ps(1., 2., 3., 4., 0., 10., -1., -2.);
+        let b = _mm256_setr_ps(5., 3., 7., 2., 4., -6., 0., 14.);
+        let c = _mm256_setr_ps(4., 9., 1., 7., -5., 11., -2., -3.);
+        let r = _mm256_setr_ps(9., 15., 22., 15., -5., -49., -2., -31.);
+        assert_eq_m256(_mm256_macc_ps(a, b, c), r);
+    }
+
+    #[simd_test(enable = "fma4")]
+    unsafe fn test_mm256_macc_pd() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let b = _mm256_setr_pd(5., 3., 7., 2.);
+        let c = _mm256_setr_pd(4., 9., 1., 7.);
+        let r = _mm256_setr_pd(9., 15., 22., 15.);
+        assert_eq_m256d(_mm256_macc_pd(a, b, c), r);
+    }
+
+    #[simd_test(enable = "fma4")]
+    unsafe fn test_mm_macc_ss() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_setr_ps(9., 0., 0., 0.);
+        assert_eq_m128(_mm_macc_ss(a, b, c), r);
+    }
+
+    #[simd_test(enable = "fma4")]
+    unsafe fn test_mm_macc_sd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_setr_pd(9., 0.);
+        assert_eq_m128d(_mm_macc_sd(a, b, c), r);
+    }
+
+    #[simd_test(enable = "fma4")]
+    unsafe fn test_mm_msub_ps() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_setr_ps(1., -3., 20., 1.);
+        assert_eq_m128(_mm_msub_ps(a, b, c), r);
+    }
+
+    #[simd_test(enable = "fma4")]
+    unsafe fn test_mm256_msub_pd() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let b = _mm256_setr_pd(5., 3., 7., 2.);
+        let c = _mm256_setr_pd(4., 9., 1., 7.);
+        let r = _mm256_setr_pd(1., -3., 20., 1.);
+        assert_eq_m256d(_mm256_msub_pd(a, b, c), r);
+    }
+
+    #[simd_test(enable = "fma4")]
+    unsafe fn test_mm_msub_sd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_setr_pd(1., 0.);
+        assert_eq_m128d(_mm_msub_sd(a, b, c), r);
+    }
+
+    #[simd_test(enable = "fma4")]
+    unsafe fn test_mm_nmacc_pd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_setr_pd(-1., 3.);
+        assert_eq_m128d(_mm_nmacc_pd(a, b, c), r);
+    }
+
+    #[simd_test(enable = "fma4")]
+    unsafe fn test_mm256_nmacc_ps() {
+        let a = _mm256_setr_ps(1., 2., 3., 4., 0., 10., -1., -2.);
+        let b = _mm256_setr_ps(5., 3., 7., 2., 4., -6., 0., 14.);
+        let c = _mm256_setr_ps(4., 9., 1., 7., -5., 11., -2., -3.);
+        let r = _mm256_setr_ps(-1., 3., -20., -1., -5., 71., -2., 25.);
+        assert_eq_m256(_mm256_nmacc_ps(a, b, c), r);
+    }
+
+    #[simd_test(enable = "fma4")]
+    unsafe fn test_mm_nmacc_ss() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_setr_ps(-1., 0., 0., 0.);
+        assert_eq_m128(_mm_nmacc_ss(a, b, c), r);
+    }
+
+    #[simd_test(enable = "fma4")]
+    unsafe fn test_mm_nmsub_ps() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_setr_ps(-9., -15., -22., -15.);
+        assert_eq_m128(_mm_nmsub_ps(a, b, c), r);
+    }
+
+    #[simd_test(enable = "fma4")]
+    unsafe fn test_mm256_nmsub_pd() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let b = _mm256_setr_pd(5., 3., 7., 2.);
+        let c = _mm256_setr_pd(4., 9., 1., 7.);
+        let r = _mm256_setr_pd(-9., -15., -22., -15.);
+        assert_eq_m256d(_mm256_nmsub_pd(a, b, c), r);
+    }
+
+    #[simd_test(enable = "fma4")]
+    unsafe fn test_mm_nmsub_sd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_setr_pd(-9., 0.);
+        assert_eq_m128d(_mm_nmsub_sd(a, b, c), r);
+    }
+
+    #[simd_test(enable = "fma4")]
+    unsafe fn test_mm_maddsub_ps() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_setr_ps(1., 15., 20., 15.);
+        assert_eq_m128(_mm_maddsub_ps(a, b, c), r);
+    }
+
+    #[simd_test(enable = "fma4")]
+    unsafe fn test_mm256_maddsub_pd() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let b = _mm256_setr_pd(5., 3., 7., 2.);
+        let c = _mm256_setr_pd(4., 9., 1., 7.);
+        let r = _mm256_setr_pd(1., 15., 20., 15.);
+        assert_eq_m256d(_mm256_maddsub_pd(a, b, c), r);
+    }
+
+    #[simd_test(enable = "fma4")]
+    unsafe fn test_mm_msubadd_pd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_setr_pd(9., -3.);
+        assert_eq_m128d(_mm_msubadd_pd(a, b, c), r);
+    }
+
+    #[simd_test(enable = "fma4")]
+    unsafe fn test_mm256_msubadd_ps() {
+        let a = _mm256_setr_ps(1., 2., 3., 4., 1., 2., 3., 4.);
+        let b = _mm256_setr_ps(5., 3., 7., 2., 5., 3., 7., 2.);
+        let c = _mm256_setr_ps(4., 9., 1., 7., 4., 9., 1., 7.);
+        let r = _mm256_setr_ps(9., -3., 22., 1., 9., -3., 22., 1.);
+        assert_eq_m256(_mm256_msubadd_ps(a, b, c), r);
+    }
+}
@@ -0,0 +1,30 @@
+//! History reset (HRESET).
+//!
+//! The intrinsics here correspond to those in the `hresetintrin.h` C header.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Resets the components of the prediction history maintained by the
+/// current logical processor that are selected by the bits set in `eax`,
+/// such as the classification history of Intel Thread Director.
+///
+/// The supported components are reported by `CPUID.20H.0H:EBX`, and each of
+/// them must also be enabled in the `IA32_HRESET_ENABLE` MSR.
+///
+/// This instruction can only be executed at privilege level 0.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_hreset)
+#[inline]
+#[cfg_attr(test, assert_instr(hreset))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _hreset(eax: i32) {
+    asm!("hreset 0", in("eax") eax, options(nomem, nostack, preserves_flags));
+}
@@ -0,0 +1,467 @@
+//! AES Key Locker Intrinsics
+//!
+//! The Intrinsics here correspond to those in the `keylockerintrin.h` C header.
+//!
+//! Key Locker converts an AES key into a *handle* that is wrapped with an
+//! internal wrapping key only known to the processor. The handle can then be
+//! used in place of the raw key by the `AES*KL` instructions. A 128-bit key
+//! produces a 384-bit handle, while a 256-bit key produces a 512-bit handle.
+//!
+//! The reference is [Intel Key Locker Specification][intel_kl].
+//!
+//! [intel_kl]: https://www.intel.com/content/www/us/en/developer/articles/technical/software-security-guidance/technical-documentation/key-locker-specification.html
+
+use crate::arch::asm;
+use crate::core_arch::x86::*;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+// x32 wants to use a 32-bit address size, but asm! defaults to using the full
+// register name (e.g. rax). We have to explicitly override the placeholder to
+// use the 32-bit register name in that case.
+#[cfg(target_pointer_width = "32")]
+macro_rules! kl {
+    ($inst:expr) => {
+        concat!($inst, " {data}, [{handle:e}]")
+    };
+}
+#[cfg(target_pointer_width = "64")]
+macro_rules! kl {
+    ($inst:expr) => {
+        concat!($inst, " {data}, [{handle}]")
+    };
+}
+
+#[cfg(target_pointer_width = "32")]
+macro_rules! klwide {
+    ($inst:expr) => {
+        concat!($inst, " [{handle:e}]")
+    };
+}
+#[cfg(target_pointer_width = "64")]
+macro_rules! klwide {
+    ($inst:expr) => {
+        concat!($inst, " [{handle}]")
+    };
+}
+
+/// Load internal wrapping key (IWKey). The 32-bit unsigned integer `control` specifies IWKey's KeySource
+/// and whether backing up the key is permitted. IWKey's 256-bit encryption key is loaded from `key_lo`
+/// and `key_hi`, and IWKey's 128-bit integrity key is loaded from `integrity_key`.
+///
+/// This instruction can only be executed at privilege level 0.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadiwkey)
+#[inline]
+#[target_feature(enable = "kl")]
+#[unstable(feature = "keylocker_x86", issue = "134813")]
+#[cfg_attr(test, assert_instr(loadiwkey))]
+pub unsafe fn _mm_loadiwkey(
+    control: u32,
+    integrity_key: __m128i,
+    key_lo: __m128i,
+    key_hi: __m128i,
+) {
+    asm!(
+        "loadiwkey {lo}, {hi}",
+        lo = in(xmm_reg) key_lo,
+        hi = in(xmm_reg) key_hi,
+        in("eax") control,
+        in("xmm0") integrity_key,
+        options(nomem, nostack),
+    );
+}
+
+/// Wrap a 128-bit AES key from `key` into a 384-bit key handle and store it in `handle`. The
+/// handle restrictions are specified by `key_params`. Returns information about the wrapped key,
+/// see the Key Locker specification for details.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_encodekey128_u32)
+#[inline]
+#[target_feature(enable = "kl")]
+#[unstable(feature = "keylocker_x86", issue = "134813")]
+#[cfg_attr(test, assert_instr(encodekey128))]
+pub unsafe fn _mm_encodekey128_u32(key_params: u32, key: __m128i, handle: *mut u8) -> u32 {
+    let result: u32;
+    let (h0, h1, h2): (__m128i, __m128i, __m128i);
+    asm!(
+        "encodekey128 {r:e}, {r:e}",
+        r = inout(reg) key_params => result,
+        inlateout("xmm0") key => h0,
+        lateout("xmm1") h1,
+        lateout("xmm2") h2,
+        lateout("xmm4") _,
+        lateout("xmm5") _,
+        lateout("xmm6") _,
+        options(nomem, nostack),
+    );
+    handle.cast::<[__m128i; 3]>().write_unaligned([h0, h1, h2]);
+    result
+}
+
+/// Wrap a 256-bit AES key from `key_lo` and `key_hi` into a 512-bit key handle and store it in
+/// `handle`. The handle restrictions are specified by `key_params`. Returns information about the
+/// wrapped key, see the Key Locker specification for details.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_encodekey256_u32)
+#[inline]
+#[target_feature(enable = "kl")]
+#[unstable(feature = "keylocker_x86", issue = "134813")]
+#[cfg_attr(test, assert_instr(encodekey256))]
+pub unsafe fn _mm_encodekey256_u32(
+    key_params: u32,
+    key_lo: __m128i,
+    key_hi: __m128i,
+    handle: *mut u8,
+) -> u32 {
+    let result: u32;
+    let (h0, h1, h2, h3): (__m128i, __m128i, __m128i, __m128i);
+    asm!(
+        "encodekey256 {r:e}, {r:e}",
+        r = inout(reg) key_params => result,
+        inlateout("xmm0") key_lo => h0,
+        inlateout("xmm1") key_hi => h1,
+        lateout("xmm2") h2,
+        lateout("xmm3") h3,
+        lateout("xmm4") _,
+        lateout("xmm5") _,
+        lateout("xmm6") _,
+        options(nomem, nostack),
+    );
+    handle
+        .cast::<[__m128i; 4]>()
+        .write_unaligned([h0, h1, h2, h3]);
+    result
+}
+
+macro_rules! aeskl {
+    ($inst:expr, $output:ident, $input:ident, $handle:ident) => {{
+        let status: u8;
+        let data: __m128i;
+        asm!(
+            kl!($inst),
+            "setz {status}",
+            data = inlateout(xmm_reg) $input => data,
+            handle = in(reg) $handle,
+            status = lateout(reg_byte) status,
+            options(readonly, nostack),
+        );
+        if status == 0 {
+            *$output = data;
+        }
+        status
+    }};
+}
+
+/// Encrypt 10 rounds of unsigned 8-bit integers in `input` using the 128-bit key handle pointed to
+/// by `handle`, and store the resulting unsigned 8-bit integers in `output`. Returns 0 on success,
+/// and 1 if the handle is invalid or does not allow encryption, in which case `output` is left
+/// unchanged.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_aesenc128kl_u8)
+#[inline]
+#[target_feature(enable = "kl")]
+#[unstable(feature = "keylocker_x86", issue = "134813")]
+#[cfg_attr(test, assert_instr(aesenc128kl))]
+pub unsafe fn _mm_aesenc128kl_u8(output: *mut __m128i, input: __m128i, handle: *const u8) -> u8 {
+    aeskl!("aesenc128kl", output, input, handle)
+}
+
+/// Decrypt 10 rounds of unsigned 8-bit integers in `input` using the 128-bit key handle pointed to
+/// by `handle`, and store the resulting unsigned 8-bit integers in `output`. Returns 0 on success,
+/// and 1 if the handle is invalid or does not allow decryption, in which case `output` is left
+/// unchanged.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_aesdec128kl_u8)
+#[inline]
+#[target_feature(enable = "kl")]
+#[unstable(feature = "keylocker_x86", issue = "134813")]
+#[cfg_attr(test, assert_instr(aesdec128kl))]
+pub unsafe fn _mm_aesdec128kl_u8(output: *mut __m128i, input: __m128i, handle: *const u8) -> u8 {
+    aeskl!("aesdec128kl", output, input, handle)
+}
+
+/// Encrypt 14 rounds of unsigned 8-bit integers in `input` using the 256-bit key handle pointed to
+/// by `handle`, and store the resulting unsigned 8-bit integers in `output`. Returns 0 on success,
+/// and 1 if the handle is invalid or does not allow encryption, in which case `output` is left
+/// unchanged.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_aesenc256kl_u8)
+#[inline]
+#[target_feature(enable = "kl")]
+#[unstable(feature = "keylocker_x86", issue = "134813")]
+#[cfg_attr(test, assert_instr(aesenc256kl))]
+pub unsafe fn _mm_aesenc256kl_u8(output: *mut __m128i, input: __m128i, handle: *const u8) -> u8 {
+    aeskl!("aesenc256kl", output, input, handle)
+}
+
+/// Decrypt 14 rounds of unsigned 8-bit integers in `input` using the 256-bit key handle pointed to
+/// by `handle`, and store the resulting unsigned 8-bit integers in `output`. Returns 0 on success,
+/// and 1 if the handle is invalid or does not allow decryption, in which case `output` is left
+/// unchanged.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_aesdec256kl_u8)
+#[inline]
+#[target_feature(enable = "kl")]
+#[unstable(feature = "keylocker_x86", issue = "134813")]
+#[cfg_attr(test, assert_instr(aesdec256kl))]
+pub unsafe fn _mm_aesdec256kl_u8(output: *mut __m128i, input: __m128i, handle: *const u8) -> u8 {
+    aeskl!("aesdec256kl", output, input, handle)
+}
+
+macro_rules! aesklwide {
+    ($inst:expr, $output:ident, $input:ident, $handle:ident) => {{
+        let status: u8;
+        let [mut x0, mut x1, mut x2, mut x3, mut x4, mut x5, mut x6, mut x7] =
+            $input.cast::<[__m128i; 8]>().read_unaligned();
+        asm!(
+            klwide!($inst),
+            "setz {status}",
+            handle = in(reg) $handle,
+            status = lateout(reg_byte) status,
+            inout("xmm0") x0,
+            inout("xmm1") x1,
+            inout("xmm2") x2,
+            inout("xmm3") x3,
+            inout("xmm4") x4,
+            inout("xmm5") x5,
+            inout("xmm6") x6,
+            inout("xmm7") x7,
+            options(readonly, nostack),
+        );
+        if status == 0 {
+            $output
+                .cast::<[__m128i; 8]>()
+                .write_unaligned([x0, x1, x2, x3, x4, x5, x6, x7]);
+        }
+        status
+    }};
+}
+
+/// Encrypt 10 rounds of 8 groups of unsigned 8-bit integers in `input` using the 128-bit key handle
+/// pointed to by `handle`, and store the resulting unsigned 8-bit integers in `output`. Returns 0
+/// on success, and 1 if the handle is invalid or does not allow encryption, in which case `output`
+/// is left unchanged.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_aesencwide128kl_u8)
+#[inline]
+#[target_feature(enable = "widekl")]
+#[unstable(feature = "keylocker_x86", issue = "134813")]
+#[cfg_attr(test, assert_instr(aesencwide128kl))]
+pub unsafe fn _mm_aesencwide128kl_u8(
+    output: *mut __m128i,
+    input: *const __m128i,
+    handle: *const u8,
+) -> u8 {
+    aesklwide!("aesencwide128kl", output, input, handle)
+}
+
+/// Decrypt 10 rounds of 8 groups of unsigned 8-bit integers in `input` using the 128-bit key handle
+/// pointed to by `handle`, and store the resulting unsigned 8-bit integers in `output`. Returns 0
+/// on success, and 1 if the handle is invalid or does not allow decryption, in which case `output`
+/// is left unchanged.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_aesdecwide128kl_u8)
+#[inline]
+#[target_feature(enable = "widekl")]
+#[unstable(feature = "keylocker_x86", issue = "134813")]
+#[cfg_attr(test, assert_instr(aesdecwide128kl))]
+pub unsafe fn _mm_aesdecwide128kl_u8(
+    output: *mut __m128i,
+    input: *const __m128i,
+    handle: *const u8,
+) -> u8 {
+    aesklwide!("aesdecwide128kl", output, input, handle)
+}
+
+/// Encrypt 14 rounds of 8 groups of unsigned 8-bit integers in `input` using the 256-bit key handle
+/// pointed to by `handle`, and store the resulting unsigned 8-bit integers in `output`. Returns 0
+/// on success, and 1 if the handle is invalid or does not allow encryption, in which case `output`
+/// is left unchanged.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_aesencwide256kl_u8)
+#[inline]
+#[target_feature(enable = "widekl")]
+#[unstable(feature = "keylocker_x86", issue = "134813")]
+#[cfg_attr(test, assert_instr(aesencwide256kl))]
+pub unsafe fn _mm_aesencwide256kl_u8(
+    output: *mut __m128i,
+    input: *const __m128i,
+    handle: *const u8,
+) -> u8 {
+    aesklwide!("aesencwide256kl", output, input, handle)
+}
+
+/// Decrypt 14 rounds of 8 groups of unsigned 8-bit integers in `input` using the 256-bit key handle
+/// pointed to by `handle`, and store the resulting unsigned 8-bit integers in `output`. Returns 0
+/// on success, and 1 if the handle is invalid or does not allow decryption, in which case `output`
+/// is left unchanged.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_aesdecwide256kl_u8)
+#[inline]
+#[target_feature(enable = "widekl")]
+#[unstable(feature = "keylocker_x86", issue = "134813")]
+#[cfg_attr(test, assert_instr(aesdecwide256kl))]
+pub unsafe fn _mm_aesdecwide256kl_u8(
+    output: *mut __m128i,
+    input: *const __m128i,
+    handle: *const u8,
+) -> u8 {
+    aesklwide!("aesdecwide256kl", output, input, handle)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use stdarch_test::simd_test;
+
+    // FIPS-197, Appendix C.1 and C.3
+    const PLAINTEXT: [u8; 16] = [
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee,
+        0xff,
+    ];
+    const CIPHERTEXT_128: [u8; 16] = [
+        0x69, 0xc4, 0xe0, 0xd8, 0x6a, 0x7b, 0x04, 0x30, 0xd8, 0xcd, 0xb7, 0x80, 0x70, 0xb4, 0xc5,
+        0x5a,
+    ];
+    const CIPHERTEXT_256: [u8; 16] = [
+        0x8e, 0xa2, 0xb7, 0xca, 0x51, 0x67, 0x45, 0xbf, 0xea, 0xfc, 0x49, 0x90, 0x4b, 0x49, 0x60,
+        0x89,
+    ];
+
+    unsafe fn key(offset: u8) -> __m128i {
+        let mut k = [0u8; 16];
+        for (i, b) in k.iter_mut().enumerate() {
+            *b = offset + i as u8;
+        }
+        _mm_loadu_si128(k.as_ptr().cast())
+    }
+
+    unsafe fn load(bytes: [u8; 16]) -> __m128i {
+        _mm_loadu_si128(bytes.as_ptr().cast())
+    }
+
+    unsafe fn encode128() -> [u8; 48] {
+        let mut handle = [0u8; 48];
+        _mm_encodekey128_u32(0, key(0), handle.as_mut_ptr());
+        handle
+    }
+
+    unsafe fn encode256() -> [u8; 64] {
+        let mut handle = [0u8; 64];
+        _mm_encodekey256_u32(0, key(0), key(16), handle.as_mut_ptr());
+        handle
+    }
+
+    #[simd_test(enable = "kl")]
+    unsafe fn test_mm_encodekey128_u32() {
+        let mut handle = [0u8; 48];
+        let result = _mm_encodekey128_u32(0, key(0), handle.as_mut_ptr());
+        // No restrictions were requested, so the handle must be a 128-bit key handle.
+        assert_eq!(result & 0xf, 0);
+        assert_ne!(handle, [0u8; 48]);
+    }
+
+    #[simd_test(enable = "kl")]
+    unsafe fn test_mm_encodekey256_u32() {
+        let mut handle = [0u8; 64];
+        let result = _mm_encodekey256_u32(0, key(0), key(16), handle.as_mut_ptr());
+        assert_eq!(result & 0xf, 0);
+        assert_ne!(handle, [0u8; 64]);
+    }
+
+    #[simd_test(enable = "kl")]
+    unsafe fn test_mm_aesenc128kl_u8() {
+        let handle = encode128();
+        let mut output = _mm_setzero_si128();
+        let status = _mm_aesenc128kl_u8(&mut output, load(PLAINTEXT), handle.as_ptr());
+        assert_eq!(status, 0);
+        assert_eq_m128i(output, load(CIPHERTEXT_128));
+    }
+
+    #[simd_test(enable = "kl")]
+    unsafe fn test_mm_aesdec128kl_u8() {
+        let handle = encode128();
+        let mut output = _mm_setzero_si128();
+        let status = _mm_aesdec128kl_u8(&mut output, load(CIPHERTEXT_128), handle.as_ptr());
+        assert_eq!(status, 0);
+        assert_eq_m128i(output, load(PLAINTEXT));
+    }
+
+    #[simd_test(enable = "kl")]
+    unsafe fn test_mm_aesenc256kl_u8() {
+        let handle = encode256();
+        let mut output = _mm_setzero_si128();
+        let status = _mm_aesenc256kl_u8(&mut output, load(PLAINTEXT), handle.as_ptr());
+        assert_eq!(status, 0);
+        assert_eq_m128i(output, load(CIPHERTEXT_256));
+    }
+
+    #[simd_test(enable = "kl")]
+    unsafe fn test_mm_aesdec256kl_u8() {
+        let handle = encode256();
+        let mut output = _mm_setzero_si128();
+        let status = _mm_aesdec256kl_u8(&mut output, load(CIPHERTEXT_256), handle.as_ptr());
+        assert_eq!(status, 0);
+        assert_eq_m128i(output, load(PLAINTEXT));
+    }
+
+    #[simd_test(enable = "kl")]
+    unsafe fn test_mm_aesenc128kl_u8_invalid_handle() {
+        // A 256-bit handle is not valid for the 128-bit instructions.
+        let handle = encode256();
+        let mut output = _mm_set1_epi8(42);
+        let status = _mm_aesenc128kl_u8(&mut output, load(PLAINTEXT), handle.as_ptr());
+        assert_eq!(status, 1);
+        assert_eq_m128i(output, _mm_set1_epi8(42));
+    }
+
+    #[simd_test(enable = "widekl")]
+    unsafe fn test_mm_aesencwide128kl_u8() {
+        let handle = encode128();
+        let input = [load(PLAINTEXT); 8];
+        let mut output = [_mm_setzero_si128(); 8];
+        let status = _mm_aesencwide128kl_u8(output.as_mut_ptr(), input.as_ptr(), handle.as_ptr());
+        assert_eq!(status, 0);
+        for o in output {
+            assert_eq_m128i(o, load(CIPHERTEXT_128));
+        }
+    }
+
+    #[simd_test(enable = "widekl")]
+    unsafe fn test_mm_aesdecwide128kl_u8() {
+        let handle = encode128();
+        let input = [load(CIPHERTEXT_128); 8];
+        let mut output = [_mm_setzero_si128(); 8];
+        let status = _mm_aesdecwide128kl_u8(output.as_mut_ptr(), input.as_ptr(), handle.as_ptr());
+        assert_eq!(status, 0);
+        for o in output {
+            assert_eq_m128i(o, load(PLAINTEXT));
+        }
+    }
+
+    #[simd_test(enable = "widekl")]
+    unsafe fn test_mm_aesencwide256kl_u8() {
+        let handle = encode256();
+        let input = [load(PLAINTEXT); 8];
+        let mut output = [_mm_setzero_si128(); 8];
+        let status = _mm_aesencwide256kl_u8(output.as_mut_ptr(), input.as_ptr(), handle.as_ptr());
+        assert_eq!(status, 0);
+        for o in output {
+            assert_eq_m128i(o, load(CIPHERTEXT_256));
+        }
+    }
+
+    #[simd_test(enable = "widekl")]
+    unsafe fn test_mm_aesdecwide256kl_u8() {
+        let handle = encode256();
+        let input = [load(CIPHERTEXT_256); 8];
+        let mut output = [_mm_setzero_si128(); 8];
+        let status = _mm_aesdecwide256kl_u8(output.as_mut_ptr(), input.as_ptr(), handle.as_ptr());
+        assert_eq!(status, 0);
+        for o in output {
+            assert_eq_m128i(o, load(PLAINTEXT));
+        }
+    }
+}
@@ -1047,6 +1047,10 @@ mod avx512vpopcntdq;
 #[unstable(feature = "stdarch_x86_avx512", issue = "111137")]
 pub use self::avx512vpopcntdq::*;
 
+mod avx512vp2intersect;
+#[unstable(feature = "stdarch_x86_avx512", issue = "111137")]
+pub use self::avx512vp2intersect::*;
+
 mod vaes;
 #[unstable(feature = "stdarch_x86_avx512", issue = "111137")]
 pub use self::vaes::*;
@@ -1078,3 +1082,67 @@ pub use self::avxneconvert::*;
 mod avx512fp16;
 #[unstable(feature = "stdarch_x86_avx512_f16", issue = "127213")]
 pub use self::avx512fp16::*;
+
+mod kl;
+#[unstable(feature = "keylocker_x86", issue = "134813")]
+pub use self::kl::*;
+
+mod xop;
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub use self::xop::*;
+
+mod fma4;
+#[unstable(feature = "fma4_target_feature", issue = "155233")]
+pub use self::fma4::*;
+
+mod cet;
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub use self::cet::*;
+
+mod waitpkg;
+#[unstable(feature = "stdarch_x86_wait", issue = "44839")]
+pub use self::waitpkg::*;
+
+mod mwaitx;
+#[unstable(feature = "stdarch_x86_wait", issue = "44839")]
+pub use self::mwaitx::*;
+
+mod movdiri;
+#[unstable(feature = "stdarch_x86_memory", issue = "44839")]
+pub use self::movdiri::*;
+
+mod enqcmd;
+#[unstable(feature = "stdarch_x86_memory", issue = "44839")]
+pub use self::enqcmd::*;
+
+mod clflushopt;
+#[unstable(feature = "stdarch_x86_memory", issue = "44839")]
+pub use self::clflushopt::*;
+
+mod clwb;
+#[unstable(feature = "stdarch_x86_memory", issue = "44839")]
+pub use self::clwb::*;
+
+mod cldemote;
+#[unstable(feature = "stdarch_x86_memory", issue = "44839")]
+pub use self::cldemote::*;
+
+mod raoint;
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub use self::raoint::*;
+
+mod rdpid;
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub use self::rdpid::*;
+
+mod serialize;
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub use self::serialize::*;
+
+mod hreset;
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub use self::hreset::*;
+
+mod tsxldtrk;
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub use self::tsxldtrk::*;
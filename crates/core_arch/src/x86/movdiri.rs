@@ -0,0 +1,106 @@
+//! Direct store instructions (MOVDIRI and MOVDIR64B).
+//!
+//! The intrinsics here correspond to those in the `movdirintrin.h` C header.
+//!
+//! Direct stores are not cached, and are weakly ordered with respect to other
+//! stores, including other direct stores to the same address. They are
+//! intended to be used to submit work to devices through memory-mapped I/O.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Store the 32-bit integer `val` to the memory pointed to by `dst` using a
+/// direct store. The store is atomic if `dst` is 4-byte aligned.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_directstoreu_u32)
+#[inline]
+#[cfg_attr(test, assert_instr(movdiri))]
+#[unstable(feature = "stdarch_x86_memory", issue = "44839")]
+pub unsafe fn _directstoreu_u32(dst: *mut u32, val: u32) {
+    // x32 wants to use a 32-bit address size, but asm! defaults to using the
+    // full register name (e.g. rax).
+    #[cfg(target_pointer_width = "32")]
+    asm!(
+        "movdiri [{dst:e}], {val:e}",
+        dst = in(reg) dst,
+        val = in(reg) val,
+        options(nostack, preserves_flags),
+    );
+    #[cfg(target_pointer_width = "64")]
+    asm!(
+        "movdiri [{dst}], {val:e}",
+        dst = in(reg) dst,
+        val = in(reg) val,
+        options(nostack, preserves_flags),
+    );
+}
+
+/// Move 64 bytes from the memory pointed to by `src` to the memory pointed to
+/// by `dst` as a single direct store. The 64 bytes are read without atomicity
+/// guarantees, but written atomically. `dst` must be 64-byte aligned.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_movdir64b)
+#[inline]
+#[cfg_attr(test, assert_instr(movdir64b))]
+#[unstable(feature = "stdarch_x86_memory", issue = "44839")]
+pub unsafe fn _movdir64b(dst: *mut u8, src: *const u8) {
+    #[cfg(target_pointer_width = "32")]
+    asm!(
+        "movdir64b {dst:e}, [{src:e}]",
+        dst = in(reg) dst,
+        src = in(reg) src,
+        options(nostack, preserves_flags),
+    );
+    #[cfg(target_pointer_width = "64")]
+    asm!(
+        "movdir64b {dst}, [{src}]",
+        dst = in(reg) dst,
+        src = in(reg) src,
+        options(nostack, preserves_flags),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    #[test]
+    fn test_directstoreu_u32() {
+        if !is_x86_feature_detected!("movdiri") {
+            return;
+        }
+        let mut x = 0u32;
+        unsafe {
+            _directstoreu_u32(&mut x, 0x1234_5678);
+            _mm_sfence();
+        }
+        assert_eq!(x, 0x1234_5678);
+    }
+
+    #[test]
+    fn test_movdir64b() {
+        if !is_x86_feature_detected!("movdir64b") {
+            return;
+        }
+        #[repr(align(64))]
+        struct Line([u8; 64]);
+
+        let mut src = Line([0; 64]);
+        for (i, b) in src.0.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+        let mut dst = Line([0xff; 64]);
+        unsafe {
+            _movdir64b(dst.0.as_mut_ptr(), src.0.as_ptr());
+            _mm_sfence();
+        }
+        assert_eq!(dst.0, src.0);
+    }
+}
@@ -0,0 +1,105 @@
+//! AMD `MONITORX` and `MWAITX` instructions.
+//!
+//! The intrinsics here correspond to those in the `mwaitxintrin.h` C header.
+//!
+//! Unlike `MONITOR` and `MWAIT`, these can be executed at any privilege
+//! level, and `MWAITX` supports an optional timeout.
+//!
+//! The reference is [AMD64 Architecture Programmer's Manual, Volume 3:
+//! General-Purpose and System Instructions][amd64_ref].
+//!
+//! [amd64_ref]: http://support.amd.com/TechDocs/24594.pdf
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Establishes a linear address range to be monitored by hardware and
+/// activates the monitor. The address range is set up by the address
+/// specified in `p`. Optional extensions are specified in `extensions`, and
+/// optional hints in `hints`.
+#[inline]
+#[cfg_attr(test, assert_instr(monitorx))]
+#[unstable(feature = "stdarch_x86_wait", issue = "44839")]
+pub unsafe fn _mm_monitorx(p: *const u8, extensions: u32, hints: u32) {
+    #[cfg(target_arch = "x86")]
+    asm!(
+        "monitorx",
+        in("eax") p,
+        in("ecx") extensions,
+        in("edx") hints,
+        options(readonly, nostack, preserves_flags),
+    );
+    // `MONITORX` takes the address from `rax` in 64-bit mode, including on
+    // x32, so zero-extend the pointer.
+    #[cfg(target_arch = "x86_64")]
+    asm!(
+        "monitorx",
+        in("rax") p as usize as u64,
+        in("ecx") extensions,
+        in("edx") hints,
+        options(readonly, nostack, preserves_flags),
+    );
+}
+
+/// Enters an implementation-dependent optimized state while waiting for a
+/// store to the address range set up by [`_mm_monitorx`]. Optional extensions
+/// are specified in `extensions`, and optional hints in `hints`.
+///
+/// If bit 1 of `extensions` is set, the wait also ends after `clock` TSC
+/// ticks.
+#[inline]
+#[cfg_attr(test, assert_instr(mwaitx))]
+#[unstable(feature = "stdarch_x86_wait", issue = "44839")]
+pub unsafe fn _mm_mwaitx(extensions: u32, hints: u32, clock: u32) {
+    // LLVM sometimes reserves `ebx` for its internal use, so we need to use
+    // a scratch register for it instead.
+    #[cfg(target_arch = "x86")]
+    asm!(
+        "xchg {0}, ebx",
+        "mwaitx",
+        "xchg {0}, ebx",
+        inout(reg) clock => _,
+        in("eax") hints,
+        in("ecx") extensions,
+        options(nomem, nostack, preserves_flags),
+    );
+    #[cfg(target_arch = "x86_64")]
+    asm!(
+        "xchg {0}, rbx",
+        "mwaitx",
+        "xchg {0}, rbx",
+        inout(reg) clock as u64 => _,
+        in("eax") hints,
+        in("ecx") extensions,
+        options(nomem, nostack, preserves_flags),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    #[test]
+    fn test_mm_monitorx() {
+        if !is_x86_feature_detected!("monitorx") {
+            return;
+        }
+        let x = 0u8;
+        unsafe { _mm_monitorx(&x, 0, 0) };
+    }
+
+    #[test]
+    fn test_mm_mwaitx() {
+        if !is_x86_feature_detected!("monitorx") {
+            return;
+        }
+        let x = 0u8;
+        unsafe {
+            _mm_monitorx(&x, 0, 0);
+            // Enable the timer so that the test does not hang.
+            _mm_mwaitx(0b10, 0, 1000);
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! Remote atomic operations (RAO-INT).
+//!
+//! The intrinsics here correspond to those in the `raointintrin.h` C header.
+//!
+//! These atomically update memory without returning the previous value, and
+//! may be performed close to the memory, for example in the shared cache,
+//! rather than in the core. This avoids moving the cache line back and forth
+//! between cores when many of them update the same location. The operations
+//! are weakly ordered with respect to other stores, so use a fence if another
+//! memory access must be ordered with respect to them.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+// x32 wants to use a 32-bit address size, but asm! defaults to using the full
+// register name (e.g. rax). We have to explicitly override the placeholder to
+// use the 32-bit register name in that case.
+#[cfg(target_pointer_width = "32")]
+macro_rules! rao {
+    ($inst:expr) => {
+        concat!($inst, " dword ptr [{p:e}], {v:e}")
+    };
+}
+#[cfg(target_pointer_width = "64")]
+macro_rules! rao {
+    ($inst:expr) => {
+        concat!($inst, " dword ptr [{p}], {v:e}")
+    };
+}
+
+/// Atomically adds `b` to the 32-bit integer pointed to by `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_aadd_i32)
+#[inline]
+#[cfg_attr(test, assert_instr(aadd))]
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub unsafe fn _aadd_i32(a: *mut i32, b: i32) {
+    asm!(rao!("aadd"), p = in(reg) a, v = in(reg) b, options(nostack, preserves_flags));
+}
+
+/// Atomically computes the bitwise AND of the 32-bit integer pointed to by `a`
+/// and `b`, and stores the result in the integer pointed to by `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_aand_i32)
+#[inline]
+#[cfg_attr(test, assert_instr(aand))]
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub unsafe fn _aand_i32(a: *mut i32, b: i32) {
+    asm!(rao!("aand"), p = in(reg) a, v = in(reg) b, options(nostack, preserves_flags));
+}
+
+/// Atomically computes the bitwise OR of the 32-bit integer pointed to by `a`
+/// and `b`, and stores the result in the integer pointed to by `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_aor_i32)
+#[inline]
+#[cfg_attr(test, assert_instr(aor))]
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub unsafe fn _aor_i32(a: *mut i32, b: i32) {
+    asm!(rao!("aor"), p = in(reg) a, v = in(reg) b, options(nostack, preserves_flags));
+}
+
+/// Atomically computes the bitwise XOR of the 32-bit integer pointed to by `a`
+/// and `b`, and stores the result in the integer pointed to by `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_axor_i32)
+#[inline]
+#[cfg_attr(test, assert_instr(axor))]
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub unsafe fn _axor_i32(a: *mut i32, b: i32) {
+    asm!(rao!("axor"), p = in(reg) a, v = in(reg) b, options(nostack, preserves_flags));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    #[test]
+    fn test_aadd_i32() {
+        if !is_x86_feature_detected!("raoint") {
+            return;
+        }
+        let mut x = 1i32;
+        unsafe { _aadd_i32(&mut x, 41) };
+        assert_eq!(x, 42);
+    }
+
+    #[test]
+    fn test_aand_i32() {
+        if !is_x86_feature_detected!("raoint") {
+            return;
+        }
+        let mut x = 0b1100i32;
+        unsafe { _aand_i32(&mut x, 0b1010) };
+        assert_eq!(x, 0b1000);
+    }
+
+    #[test]
+    fn test_aor_i32() {
+        if !is_x86_feature_detected!("raoint") {
+            return;
+        }
+        let mut x = 0b1100i32;
+        unsafe { _aor_i32(&mut x, 0b1010) };
+        assert_eq!(x, 0b1110);
+    }
+
+    #[test]
+    fn test_axor_i32() {
+        if !is_x86_feature_detected!("raoint") {
+            return;
+        }
+        let mut x = 0b1100i32;
+        unsafe { _axor_i32(&mut x, 0b1010) };
+        assert_eq!(x, 0b0110);
+    }
+}
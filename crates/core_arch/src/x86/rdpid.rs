@@ -0,0 +1,63 @@
+//! Read processor ID (RDPID).
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Reads the `IA32_TSC_AUX` MSR, which operating systems usually initialize
+/// with a value identifying the current logical processor.
+///
+/// This is the same value that [`__rdtscp`](crate::core_arch::x86::__rdtscp)
+/// returns through its `aux` argument, without reading the time-stamp
+/// counter.
+///
+/// On Linux, the low 12 bits hold the number of the CPU and the following
+/// bits the number of its NUMA node.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_rdpid_u32)
+#[inline]
+#[cfg_attr(test, assert_instr(rdpid))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _rdpid_u32() -> u32 {
+    // `RDPID` always writes a full-sized register, and the high 32 bits of it
+    // are zero in 64-bit mode.
+    #[cfg(target_arch = "x86")]
+    {
+        let r: u32;
+        asm!("rdpid {}", out(reg) r, options(nomem, nostack, preserves_flags));
+        r
+    }
+    #[cfg(target_arch = "x86_64")]
+    {
+        let r: u64;
+        asm!("rdpid {}", out(reg) r, options(nomem, nostack, preserves_flags));
+        r as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    #[test]
+    fn test_rdpid_u32() {
+        if !is_x86_feature_detected!("rdpid") {
+            return;
+        }
+        unsafe {
+            let mut before = 0;
+            let mut after = 0;
+            __rdtscp(&mut before);
+            let r = _rdpid_u32();
+            __rdtscp(&mut after);
+            // The thread could have been migrated to another CPU in between.
+            assert!(r == before || r == after);
+        }
+    }
+}
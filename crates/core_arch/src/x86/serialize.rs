@@ -0,0 +1,48 @@
+//! Serialize instruction execution (SERIALIZE).
+//!
+//! The intrinsics here correspond to those in the `serializeintrin.h` C
+//! header.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Serializes instruction execution: all modifications to flags, registers
+/// and memory by previous instructions are completed, and all buffered writes
+/// are drained to memory, before the next instruction is fetched and
+/// executed.
+///
+/// Unlike [`__cpuid`](crate::core_arch::x86::__cpuid), which is often used for
+/// the same purpose, this does not modify any registers, and it is not
+/// intercepted by hypervisors.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_serialize)
+#[inline]
+#[cfg_attr(test, assert_instr(serialize))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _serialize() {
+    asm!("serialize", options(nostack, preserves_flags));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    #[test]
+    fn test_serialize() {
+        if !is_x86_feature_detected!("serialize") {
+            return;
+        }
+        unsafe {
+            let start = _rdtsc();
+            _serialize();
+            assert!(_rdtsc() >= start);
+        }
+    }
+}
@@ -16,6 +16,26 @@ extern "C" {
     fn sha256msg2(a: i32x4, b: i32x4) -> i32x4;
     #[link_name = "llvm.x86.sha256rnds2"]
     fn sha256rnds2(a: i32x4, b: i32x4, k: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.vsha512msg1"]
+    fn vsha512msg1(a: i64x4, b: i64x2) -> i64x4;
+    #[link_name = "llvm.x86.vsha512msg2"]
+    fn vsha512msg2(a: i64x4, b: i64x4) -> i64x4;
+    #[link_name = "llvm.x86.vsha512rnds2"]
+    fn vsha512rnds2(a: i64x4, b: i64x4, k: i64x2) -> i64x4;
+    #[link_name = "llvm.x86.vsm3msg1"]
+    fn vsm3msg1(a: i32x4, b: i32x4, c: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.vsm3msg2"]
+    fn vsm3msg2(a: i32x4, b: i32x4, c: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.vsm3rnds2"]
+    fn vsm3rnds2(a: i32x4, b: i32x4, c: i32x4, d: i32) -> i32x4;
+    #[link_name = "llvm.x86.vsm4key4128"]
+    fn vsm4key4128(a: i32x4, b: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.vsm4key4256"]
+    fn vsm4key4256(a: i32x8, b: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.vsm4rnds4128"]
+    fn vsm4rnds4128(a: i32x4, b: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.vsm4rnds4256"]
+    fn vsm4rnds4256(a: i32x8, b: i32x8) -> i32x8;
 }
 
 #[cfg(test)]
@@ -118,6 +138,151 @@ pub unsafe fn _mm_sha256rnds2_epu32(a: __m128i, b: __m128i, k: __m128i) -> __m12
     transmute(sha256rnds2(a.as_i32x4(), b.as_i32x4(), k.as_i32x4()))
 }
 
+/// This is one of the two SHA512 message scheduling instructions. Performs an
+/// intermediate calculation for the next four SHA512 message qwords using the
+/// previous message qwords in `a` and the lowest qword of `b`, and returns the
+/// result.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_sha512msg1_epi64)
+#[inline]
+#[target_feature(enable = "sha512,avx")]
+#[cfg_attr(test, assert_instr(vsha512msg1))]
+#[unstable(feature = "sha512_sm_x86", issue = "126624")]
+pub unsafe fn _mm256_sha512msg1_epi64(a: __m256i, b: __m128i) -> __m256i {
+    transmute(vsha512msg1(a.as_i64x4(), b.as_i64x2()))
+}
+
+/// This is one of the two SHA512 message scheduling instructions. Performs the
+/// final calculation for the next four SHA512 message qwords using the
+/// intermediate result in `a` and the previous message qwords in `b`, and
+/// returns the result.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_sha512msg2_epi64)
+#[inline]
+#[target_feature(enable = "sha512,avx")]
+#[cfg_attr(test, assert_instr(vsha512msg2))]
+#[unstable(feature = "sha512_sm_x86", issue = "126624")]
+pub unsafe fn _mm256_sha512msg2_epi64(a: __m256i, b: __m256i) -> __m256i {
+    transmute(vsha512msg2(a.as_i64x4(), b.as_i64x4()))
+}
+
+/// Performs 2 rounds of SHA512 operation using an initial SHA512 state
+/// (C,D,G,H) from `a`, an initial SHA512 state (A,B,E,F) from `b`, and a
+/// pre-computed sum of the next 2 round message qwords and the corresponding
+/// round constants from `k`, and returns the updated SHA512 state (A,B,E,F).
+/// The result can be used as the (C,D,G,H) state of the two rounds after the
+/// next ones.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_sha512rnds2_epi64)
+#[inline]
+#[target_feature(enable = "sha512,avx")]
+#[cfg_attr(test, assert_instr(vsha512rnds2))]
+#[unstable(feature = "sha512_sm_x86", issue = "126624")]
+pub unsafe fn _mm256_sha512rnds2_epi64(a: __m256i, b: __m256i, k: __m128i) -> __m256i {
+    transmute(vsha512rnds2(a.as_i64x4(), b.as_i64x4(), k.as_i64x2()))
+}
+
+/// This is one of the two SM3 message scheduling instructions. Performs an
+/// initial calculation for the next four SM3 message words using the previous
+/// message words W\[7..10\] in `a`, W\[13..15\] in the lower three words of
+/// `b` and W\[0..3\] in `c`, and returns the result.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_sm3msg1_epi32)
+#[inline]
+#[target_feature(enable = "sm3,avx")]
+#[cfg_attr(test, assert_instr(vsm3msg1))]
+#[unstable(feature = "sha512_sm_x86", issue = "126624")]
+pub unsafe fn _mm_sm3msg1_epi32(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    transmute(vsm3msg1(a.as_i32x4(), b.as_i32x4(), c.as_i32x4()))
+}
+
+/// This is one of the two SM3 message scheduling instructions. Performs the
+/// final calculation for the next four SM3 message words using the
+/// intermediate result in `a` and the previous message words W\[3..6\] in `b`
+/// and W\[10..13\] in `c`, and returns the result.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_sm3msg2_epi32)
+#[inline]
+#[target_feature(enable = "sm3,avx")]
+#[cfg_attr(test, assert_instr(vsm3msg2))]
+#[unstable(feature = "sha512_sm_x86", issue = "126624")]
+pub unsafe fn _mm_sm3msg2_epi32(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    transmute(vsm3msg2(a.as_i32x4(), b.as_i32x4(), c.as_i32x4()))
+}
+
+/// Performs 2 rounds of SM3 operation using an initial SM3 state (C,D,G,H)
+/// from `a`, an initial SM3 state (A,B,E,F) from `b` and the pre-computed
+/// message words W\[0\], W\[1\], W\[4\] and W\[5\] from `c`, and returns
+/// the updated SM3 state (A,B,E,F).
+///
+/// The (C,D,G,H) state in `a` is expected without the left rotations that the
+/// previous rounds applied to it, so the result can be used as the (C,D,G,H)
+/// state of the two rounds after the next ones. `IMM8` is the number of the
+/// first of the two rounds, and must be even.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_sm3rnds2_epi32)
+#[inline]
+#[target_feature(enable = "sm3,avx")]
+#[cfg_attr(test, assert_instr(vsm3rnds2, IMM8 = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "sha512_sm_x86", issue = "126624")]
+pub unsafe fn _mm_sm3rnds2_epi32<const IMM8: i32>(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    static_assert!(
+        IMM8 == (IMM8 & 0x3e),
+        "IMM8 must be an even number in the range `0..=62`"
+    );
+    transmute(vsm3rnds2(a.as_i32x4(), b.as_i32x4(), c.as_i32x4(), IMM8))
+}
+
+/// Performs 4 rounds of SM4 key expansion using the previous round keys in
+/// `a` and the SM4 constants in `b`, and returns the next four round keys.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_sm4key4_epi32)
+#[inline]
+#[target_feature(enable = "sm4,avx")]
+#[cfg_attr(test, assert_instr(vsm4key4))]
+#[unstable(feature = "sha512_sm_x86", issue = "126624")]
+pub unsafe fn _mm_sm4key4_epi32(a: __m128i, b: __m128i) -> __m128i {
+    transmute(vsm4key4128(a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Performs 4 rounds of SM4 key expansion on each 128-bit lane, using the
+/// previous round keys in `a` and the SM4 constants in `b`, and returns the
+/// next four round keys of each lane.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_sm4key4_epi32)
+#[inline]
+#[target_feature(enable = "sm4,avx")]
+#[cfg_attr(test, assert_instr(vsm4key4))]
+#[unstable(feature = "sha512_sm_x86", issue = "126624")]
+pub unsafe fn _mm256_sm4key4_epi32(a: __m256i, b: __m256i) -> __m256i {
+    transmute(vsm4key4256(a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Performs 4 rounds of SM4 encryption using the state in `a` and the round
+/// keys in `b`, and returns the updated state.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_sm4rnds4_epi32)
+#[inline]
+#[target_feature(enable = "sm4,avx")]
+#[cfg_attr(test, assert_instr(vsm4rnds4))]
+#[unstable(feature = "sha512_sm_x86", issue = "126624")]
+pub unsafe fn _mm_sm4rnds4_epi32(a: __m128i, b: __m128i) -> __m128i {
+    transmute(vsm4rnds4128(a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Performs 4 rounds of SM4 encryption on each 128-bit lane, using the states
+/// in `a` and the round keys in `b`, and returns the updated states.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_sm4rnds4_epi32)
+#[inline]
+#[target_feature(enable = "sm4,avx")]
+#[cfg_attr(test, assert_instr(vsm4rnds4))]
+#[unstable(feature = "sha512_sm_x86", issue = "126624")]
+pub unsafe fn _mm256_sm4rnds4_epi32(a: __m256i, b: __m256i) -> __m256i {
+    transmute(vsm4rnds4256(a.as_i32x8(), b.as_i32x8()))
+}
+
 #[cfg(test)]
 mod tests {
     use std::{
@@ -215,4 +380,186 @@ mod tests {
         let r = _mm_sha256rnds2_epu32(a, b, k);
         assert_eq_m128i(r, expected);
     }
+
+    #[simd_test(enable = "sha512,avx")]
+    #[allow(overflowing_literals)]
+    unsafe fn test_mm256_sha512msg1_epi64() {
+        let a = _mm256_set_epi64x(
+            0xab1c5ed5923f82a4,
+            0x59f111f13956c25b,
+            0xe9b5dba5b5c0fbcf,
+            0x71374491428a2f98,
+        );
+        let b = _mm_set_epi64x(0x80deb1fe3b1696b1, 0x72be5d74f27b896f);
+        let expected = _mm256_set_epi64x(
+            0x81e54b33766acad0,
+            0x4a641d7af1004430,
+            0xe0c8777045a1963a,
+            0xac17784f76c86c83,
+        );
+        let r = _mm256_sha512msg1_epi64(a, b);
+        assert_eq_m256i(r, expected);
+    }
+
+    #[simd_test(enable = "sha512,avx")]
+    #[allow(overflowing_literals)]
+    unsafe fn test_mm256_sha512msg2_epi64() {
+        let a = _mm256_set_epi64x(
+            0xab1c5ed5923f82a4,
+            0x59f111f13956c25b,
+            0xe9b5dba5b5c0fbcf,
+            0x71374491428a2f98,
+        );
+        let b = _mm256_set_epi64x(
+            0x550c7dc3d5ffb4e2,
+            0x243185be4ee4b28c,
+            0x12835b0145706fbe,
+            0xd807aa98a3030242,
+        );
+        let expected = _mm256_set_epi64x(
+            0xd4e40a5651558d31,
+            0x95577a70893c79fe,
+            0x496170ede4d31f4d,
+            0x2884b3f40133ff0f,
+        );
+        let r = _mm256_sha512msg2_epi64(a, b);
+        assert_eq_m256i(r, expected);
+    }
+
+    #[simd_test(enable = "sha512,avx")]
+    #[allow(overflowing_literals)]
+    unsafe fn test_mm256_sha512rnds2_epi64() {
+        let a = _mm256_set_epi64x(
+            0xab1c5ed5923f82a4,
+            0x59f111f13956c25b,
+            0xe9b5dba5b5c0fbcf,
+            0x71374491428a2f98,
+        );
+        let b = _mm256_set_epi64x(
+            0x550c7dc3d5ffb4e2,
+            0x243185be4ee4b28c,
+            0x12835b0145706fbe,
+            0xd807aa98a3030242,
+        );
+        let k = _mm_set_epi64x(0x80deb1fe3b1696b1, 0x72be5d74f27b896f);
+        let expected = _mm256_set_epi64x(
+            0x084fd3009f1c3cbb,
+            0x552e5d912480ded4,
+            0x3adaf8f4fd72f5ce,
+            0x5cd3b53685a912d5,
+        );
+        let r = _mm256_sha512rnds2_epi64(a, b, k);
+        assert_eq_m256i(r, expected);
+    }
+
+    #[simd_test(enable = "sm3,avx")]
+    #[allow(overflowing_literals)]
+    unsafe fn test_mm_sm3msg1_epi32() {
+        let a = _mm_set_epi64x(0xe9b5dba5b5c0fbcf, 0x71374491428a2f98);
+        let b = _mm_set_epi64x(0xab1c5ed5923f82a4, 0x59f111f13956c25b);
+        let c = _mm_set_epi64x(0x12835b0145706fbe, 0xd807aa98a3030242);
+        let expected = _mm_set_epi64x(0xe919667fe84d34f1, 0x7820363460dc237b);
+        let r = _mm_sm3msg1_epi32(a, b, c);
+        assert_eq_m128i(r, expected);
+    }
+
+    #[simd_test(enable = "sm3,avx")]
+    #[allow(overflowing_literals)]
+    unsafe fn test_mm_sm3msg2_epi32() {
+        let a = _mm_set_epi64x(0xe9b5dba5b5c0fbcf, 0x71374491428a2f98);
+        let b = _mm_set_epi64x(0xab1c5ed5923f82a4, 0x59f111f13956c25b);
+        let c = _mm_set_epi64x(0x12835b0145706fbe, 0xd807aa98a3030242);
+        let expected = _mm_set_epi64x(0x5d80de86ef71c638, 0x51b816a54ae80046);
+        let r = _mm_sm3msg2_epi32(a, b, c);
+        assert_eq_m128i(r, expected);
+    }
+
+    #[simd_test(enable = "sm3,avx")]
+    #[allow(overflowing_literals)]
+    unsafe fn test_mm_sm3rnds2_epi32() {
+        let a = _mm_set_epi64x(0xe9b5dba5b5c0fbcf, 0x71374491428a2f98);
+        let b = _mm_set_epi64x(0xab1c5ed5923f82a4, 0x59f111f13956c25b);
+        let c = _mm_set_epi64x(0x12835b0145706fbe, 0xd807aa98a3030242);
+        let expected = _mm_set_epi64x(0x3c0d5063cbb52c86, 0x3944c84c3108e289);
+        let r = _mm_sm3rnds2_epi32::<0>(a, b, c);
+        assert_eq_m128i(r, expected);
+
+        let expected = _mm_set_epi64x(0x49bb3a6b854926ab, 0x602ba8e3506c88c9);
+        let r = _mm_sm3rnds2_epi32::<16>(a, b, c);
+        assert_eq_m128i(r, expected);
+
+        let expected = _mm_set_epi64x(0x597fa0559971b12a, 0xa6bb27504c9000e4);
+        let r = _mm_sm3rnds2_epi32::<62>(a, b, c);
+        assert_eq_m128i(r, expected);
+    }
+
+    #[simd_test(enable = "sm4,avx")]
+    #[allow(overflowing_literals)]
+    unsafe fn test_mm_sm4key4_epi32() {
+        let a = _mm_set_epi64x(0xe9b5dba5b5c0fbcf, 0x71374491428a2f98);
+        let b = _mm_set_epi64x(0xab1c5ed5923f82a4, 0x59f111f13956c25b);
+        let expected = _mm_set_epi64x(0xa70c889a43913d9d, 0xd6e7eb903f7655a5);
+        let r = _mm_sm4key4_epi32(a, b);
+        assert_eq_m128i(r, expected);
+    }
+
+    #[simd_test(enable = "sm4,avx")]
+    #[allow(overflowing_literals)]
+    unsafe fn test_mm256_sm4key4_epi32() {
+        let a = _mm256_set_epi64x(
+            0xab1c5ed5923f82a4,
+            0x59f111f13956c25b,
+            0xe9b5dba5b5c0fbcf,
+            0x71374491428a2f98,
+        );
+        let b = _mm256_set_epi64x(
+            0x550c7dc3d5ffb4e2,
+            0x243185be4ee4b28c,
+            0x12835b0145706fbe,
+            0xd807aa98a3030242,
+        );
+        let expected = _mm256_set_epi64x(
+            0xd8ede318b98605c9,
+            0x8868b58c922d3da1,
+            0xd06dd14bc58f54ad,
+            0xef6acb4c52389f2f,
+        );
+        let r = _mm256_sm4key4_epi32(a, b);
+        assert_eq_m256i(r, expected);
+    }
+
+    #[simd_test(enable = "sm4,avx")]
+    #[allow(overflowing_literals)]
+    unsafe fn test_mm_sm4rnds4_epi32() {
+        let a = _mm_set_epi64x(0xe9b5dba5b5c0fbcf, 0x71374491428a2f98);
+        let b = _mm_set_epi64x(0xab1c5ed5923f82a4, 0x59f111f13956c25b);
+        let expected = _mm_set_epi64x(0x42d7d247cb98ee17, 0xd3c378855e7d3b38);
+        let r = _mm_sm4rnds4_epi32(a, b);
+        assert_eq_m128i(r, expected);
+    }
+
+    #[simd_test(enable = "sm4,avx")]
+    #[allow(overflowing_literals)]
+    unsafe fn test_mm256_sm4rnds4_epi32() {
+        let a = _mm256_set_epi64x(
+            0xab1c5ed5923f82a4,
+            0x59f111f13956c25b,
+            0xe9b5dba5b5c0fbcf,
+            0x71374491428a2f98,
+        );
+        let b = _mm256_set_epi64x(
+            0x550c7dc3d5ffb4e2,
+            0x243185be4ee4b28c,
+            0x12835b0145706fbe,
+            0xd807aa98a3030242,
+        );
+        let expected = _mm256_set_epi64x(
+            0x1d911135a24d2892,
+            0x78ecc1f0d46add5e,
+            0x69d7179c489eaebf,
+            0x98beea74a43c1601,
+        );
+        let r = _mm256_sm4rnds4_epi32(a, b);
+        assert_eq_m256i(r, expected);
+    }
 }
@@ -147,6 +147,40 @@ pub unsafe fn _mm_moveldup_ps(a: __m128) -> __m128 {
     simd_shuffle!(a, a, [0, 0, 2, 2])
 }
 
+/// Arm address monitoring hardware using the address specified in `p`. A
+/// store to an address within the specified address range triggers the
+/// monitoring hardware. Specify optional extensions in `extensions`, and
+/// optional hints in `hints`.
+///
+/// Whether `MONITOR` can be executed outside of privilege level 0 depends on
+/// the processor and the operating system.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_monitor)
+#[inline]
+#[target_feature(enable = "sse3")]
+#[cfg_attr(test, assert_instr(monitor))]
+#[unstable(feature = "stdarch_x86_wait", issue = "44839")]
+pub unsafe fn _mm_monitor(p: *const u8, extensions: u32, hints: u32) {
+    monitor(p, extensions, hints)
+}
+
+/// Hint to the processor that it can enter an implementation-dependent
+/// optimized state while waiting for an event or store operation to the
+/// address range specified by [`_mm_monitor`]. Specify optional extensions in
+/// `extensions`, and optional hints in `hints`.
+///
+/// Whether `MWAIT` can be executed outside of privilege level 0 depends on
+/// the processor and the operating system.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mwait)
+#[inline]
+#[target_feature(enable = "sse3")]
+#[cfg_attr(test, assert_instr(mwait))]
+#[unstable(feature = "stdarch_x86_wait", issue = "44839")]
+pub unsafe fn _mm_mwait(extensions: u32, hints: u32) {
+    mwait(extensions, hints)
+}
+
 #[allow(improper_ctypes)]
 extern "C" {
     #[link_name = "llvm.x86.sse3.hadd.pd"]
@@ -159,6 +193,10 @@ extern "C" {
     fn hsubps(a: __m128, b: __m128) -> __m128;
     #[link_name = "llvm.x86.sse3.ldu.dq"]
     fn lddqu(mem_addr: *const i8) -> i8x16;
+    #[link_name = "llvm.x86.sse3.monitor"]
+    fn monitor(p: *const u8, extensions: u32, hints: u32);
+    #[link_name = "llvm.x86.sse3.mwait"]
+    fn mwait(extensions: u32, hints: u32);
 }
 
 #[cfg(test)]
@@ -0,0 +1,65 @@
+//! TSX suspend load address tracking (TSXLDTRK).
+//!
+//! The intrinsics here correspond to those in the `tsxldtrkintrin.h` C
+//! header.
+//!
+//! Within a transactional region started by
+//! [`_xbegin`](crate::core_arch::x86::_xbegin), loads executed between
+//! `_xsusldtrk` and `_xresldtrk` are not added to the read set of the
+//! transaction, so stores from other logical processors to the same addresses
+//! do not abort it.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Suspends the tracking of load addresses in the current transactional
+/// region.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_xsusldtrk)
+#[inline]
+#[cfg_attr(test, assert_instr(xsusldtrk))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _xsusldtrk() {
+    asm!("xsusldtrk", options(nostack, preserves_flags));
+}
+
+/// Resumes the tracking of load addresses in the current transactional
+/// region.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_xresldtrk)
+#[inline]
+#[cfg_attr(test, assert_instr(xresldtrk))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _xresldtrk() {
+    asm!("xresldtrk", options(nostack, preserves_flags));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    // Outside of a transactional region these do nothing.
+
+    #[test]
+    fn test_xsusldtrk() {
+        if !is_x86_feature_detected!("tsxldtrk") {
+            return;
+        }
+        unsafe { _xsusldtrk() };
+    }
+
+    #[test]
+    fn test_xresldtrk() {
+        if !is_x86_feature_detected!("tsxldtrk") {
+            return;
+        }
+        unsafe { _xresldtrk() };
+    }
+}
@@ -0,0 +1,155 @@
+//! User-mode wait instructions (WAITPKG).
+//!
+//! The intrinsics here correspond to those in the `waitpkgintrin.h` C header.
+//!
+//! `_umwait` and `_tpause` wait until the time-stamp counter reaches an
+//! absolute deadline; [`_tsc_deadline`] computes one from a relative number of
+//! TSC ticks. The operating system may further limit how long the processor
+//! stays in the optimized state through the `IA32_UMWAIT_CONTROL` MSR.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use crate::arch::asm;
+use crate::core_arch::x86::_rdtsc;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Set up a linear address range to be monitored by hardware and activate the
+/// monitor. The address range is set up by the address specified in `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_umonitor)
+#[inline]
+#[cfg_attr(test, assert_instr(umonitor))]
+#[unstable(feature = "stdarch_x86_wait", issue = "44839")]
+pub unsafe fn _umonitor(a: *mut u8) {
+    // x32 wants to use a 32-bit address size, but asm! defaults to using the
+    // full register name (e.g. rax).
+    #[cfg(target_pointer_width = "32")]
+    asm!("umonitor {:e}", in(reg) a, options(readonly, nostack, preserves_flags));
+    #[cfg(target_pointer_width = "64")]
+    asm!("umonitor {}", in(reg) a, options(readonly, nostack, preserves_flags));
+}
+
+/// Direct the processor to enter an implementation-dependent optimized state
+/// while monitoring a range of addresses set up by [`_umonitor`]. The
+/// instruction wakes up when the TSC reaches or exceeds the value specified
+/// in `counter`, when the monitored address range is written to, or when the
+/// operating system time limit expires.
+///
+/// Bit 0 of `control` selects the optimized state: 0 selects C0.2, which is
+/// slower to wake up but saves more power, and 1 selects C0.1.
+///
+/// Returns 1 if the wait was cut short by the operating system time limit,
+/// and 0 otherwise.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_umwait)
+#[inline]
+#[cfg_attr(test, assert_instr(umwait))]
+#[unstable(feature = "stdarch_x86_wait", issue = "44839")]
+pub unsafe fn _umwait(control: u32, counter: u64) -> u8 {
+    let r: u8;
+    asm!(
+        "umwait {control:e}",
+        "setc {r}",
+        control = in(reg) control,
+        r = out(reg_byte) r,
+        in("eax") counter as u32,
+        in("edx") (counter >> 32) as u32,
+        options(nomem, nostack),
+    );
+    r
+}
+
+/// Direct the processor to enter an implementation-dependent optimized state
+/// until the TSC reaches or exceeds the value specified in `counter`, or the
+/// operating system time limit expires.
+///
+/// Bit 0 of `control` selects the optimized state: 0 selects C0.2, which is
+/// slower to wake up but saves more power, and 1 selects C0.1.
+///
+/// Returns 1 if the pause was cut short by the operating system time limit,
+/// and 0 otherwise.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_tpause)
+#[inline]
+#[cfg_attr(test, assert_instr(tpause))]
+#[unstable(feature = "stdarch_x86_wait", issue = "44839")]
+pub unsafe fn _tpause(control: u32, counter: u64) -> u8 {
+    let r: u8;
+    asm!(
+        "tpause {control:e}",
+        "setc {r}",
+        control = in(reg) control,
+        r = out(reg_byte) r,
+        in("eax") counter as u32,
+        in("edx") (counter >> 32) as u32,
+        options(nomem, nostack),
+    );
+    r
+}
+
+/// Returns the value the time-stamp counter will have `ticks` ticks from now,
+/// for use as the deadline of [`_umwait`] and [`_tpause`].
+///
+/// This is not an Intel intrinsic.
+#[inline]
+#[unstable(feature = "stdarch_x86_wait", issue = "44839")]
+pub unsafe fn _tsc_deadline(ticks: u64) -> u64 {
+    _rdtsc().wrapping_add(ticks)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+
+    #[test]
+    fn test_tsc_deadline() {
+        unsafe {
+            let deadline = _tsc_deadline(1 << 32);
+            assert!(deadline > _rdtsc());
+        }
+    }
+
+    #[test]
+    fn test_tpause() {
+        if !is_x86_feature_detected!("waitpkg") {
+            return;
+        }
+        unsafe {
+            let deadline = _tsc_deadline(1000);
+            let r = _tpause(0, deadline);
+            assert!(r == 0 || r == 1);
+            // Unless the OS time limit cut it short, the pause lasts until
+            // the deadline.
+            if r == 0 {
+                assert!(_rdtsc() >= deadline);
+            }
+        }
+    }
+
+    #[test]
+    fn test_umonitor() {
+        if !is_x86_feature_detected!("waitpkg") {
+            return;
+        }
+        let mut x = 0u8;
+        unsafe { _umonitor(&mut x) };
+    }
+
+    #[test]
+    fn test_umwait() {
+        if !is_x86_feature_detected!("waitpkg") {
+            return;
+        }
+        let mut x = 0u8;
+        unsafe {
+            _umonitor(&mut x);
+            let r = _umwait(1, _tsc_deadline(1000));
+            assert!(r == 0 || r == 1);
+        }
+    }
+}
@@ -0,0 +1,1999 @@
+//! AMD eXtended Operations (XOP)
+//!
+//! XOP is an extension to the 128 and 256-bit SSE and AVX instructions
+//! available on AMD processors of the Bulldozer family (Bulldozer,
+//! Piledriver, Steamroller and Excavator). It adds integer multiply-accumulate
+//! and horizontal add instructions, variable shifts and rotates, integer
+//! comparisons with a predicate, and byte and floating-point permutes.
+//!
+//! The intrinsics here correspond to those in the `xopintrin.h` C header.
+//!
+//! The reference is [AMD64 Architecture Programmer's Manual, Volume 6: 128-Bit
+//! and 256-Bit XOP and FMA4 Instructions][amd64_ref].
+//!
+//! [amd64_ref]: https://www.amd.com/content/dam/amd/en/documents/archived-tech-docs/programmer-references/43479.pdf
+
+use crate::{
+    core_arch::{simd::*, x86::*},
+    intrinsics::simd::*,
+    mem::transmute,
+};
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Multiplies packed signed 16-bit integers in `a` and `b`, and adds the
+/// products to the packed signed 16-bit integers in `c` using signed
+/// saturation.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpmacssww))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_maccs_epi16(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    transmute(vpmacssww(a.as_i16x8(), b.as_i16x8(), c.as_i16x8()))
+}
+
+/// Multiplies packed 16-bit integers in `a` and `b`, and adds the low 16
+/// bits of the products to the packed 16-bit integers in `c`.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpmacsww))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_macc_epi16(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    transmute(vpmacsww(a.as_i16x8(), b.as_i16x8(), c.as_i16x8()))
+}
+
+/// Multiplies the odd-indexed signed 16-bit integers in `a` and `b`, and
+/// adds the 32-bit products to the packed signed 32-bit integers in `c`
+/// using signed saturation.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpmacsswd))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_maccsd_epi16(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    transmute(vpmacsswd(a.as_i16x8(), b.as_i16x8(), c.as_i32x4()))
+}
+
+/// Multiplies the odd-indexed signed 16-bit integers in `a` and `b`, and
+/// adds the 32-bit products to the packed 32-bit integers in `c`.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpmacswd))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_maccd_epi16(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    transmute(vpmacswd(a.as_i16x8(), b.as_i16x8(), c.as_i32x4()))
+}
+
+/// Multiplies packed signed 32-bit integers in `a` and `b`, and adds the
+/// products to the packed signed 32-bit integers in `c` using signed
+/// saturation.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpmacssdd))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_maccs_epi32(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    transmute(vpmacssdd(a.as_i32x4(), b.as_i32x4(), c.as_i32x4()))
+}
+
+/// Multiplies packed 32-bit integers in `a` and `b`, and adds the low 32
+/// bits of the products to the packed 32-bit integers in `c`.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpmacsdd))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_macc_epi32(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    transmute(vpmacsdd(a.as_i32x4(), b.as_i32x4(), c.as_i32x4()))
+}
+
+/// Multiplies the even-indexed signed 32-bit integers in `a` and `b`, and
+/// adds the 64-bit products to the packed signed 64-bit integers in `c`
+/// using signed saturation.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpmacssdql))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_maccslo_epi32(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    transmute(vpmacssdql(a.as_i32x4(), b.as_i32x4(), c.as_i64x2()))
+}
+
+/// Multiplies the even-indexed signed 32-bit integers in `a` and `b`, and
+/// adds the 64-bit products to the packed 64-bit integers in `c`.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpmacsdql))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_macclo_epi32(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    transmute(vpmacsdql(a.as_i32x4(), b.as_i32x4(), c.as_i64x2()))
+}
+
+/// Multiplies the odd-indexed signed 32-bit integers in `a` and `b`, and
+/// adds the 64-bit products to the packed signed 64-bit integers in `c`
+/// using signed saturation.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpmacssdqh))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_maccshi_epi32(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    transmute(vpmacssdqh(a.as_i32x4(), b.as_i32x4(), c.as_i64x2()))
+}
+
+/// Multiplies the odd-indexed signed 32-bit integers in `a` and `b`, and
+/// adds the 64-bit products to the packed 64-bit integers in `c`.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpmacsdqh))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_macchi_epi32(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    transmute(vpmacsdqh(a.as_i32x4(), b.as_i32x4(), c.as_i64x2()))
+}
+
+/// Multiplies packed signed 16-bit integers in `a` and `b`, horizontally
+/// adds adjacent pairs of the 32-bit products, and adds the sums to the
+/// packed signed 32-bit integers in `c` using signed saturation.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpmadcsswd))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_maddsd_epi16(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    transmute(vpmadcsswd(a.as_i16x8(), b.as_i16x8(), c.as_i32x4()))
+}
+
+/// Multiplies packed signed 16-bit integers in `a` and `b`, horizontally
+/// adds adjacent pairs of the 32-bit products, and adds the sums to the
+/// packed 32-bit integers in `c`.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpmadcswd))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_maddd_epi16(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    transmute(vpmadcswd(a.as_i16x8(), b.as_i16x8(), c.as_i32x4()))
+}
+
+/// Horizontally adds adjacent pairs of packed signed 8-bit integers in `a`, and
+/// returns the sums as packed 16-bit integers.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphaddbw))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_haddw_epi8(a: __m128i) -> __m128i {
+    transmute(vphaddbw(a.as_i8x16()))
+}
+
+/// Horizontally adds each group of 4 adjacent packed signed 8-bit integers in `a`, and
+/// returns the sums as packed 32-bit integers.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphaddbd))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_haddd_epi8(a: __m128i) -> __m128i {
+    transmute(vphaddbd(a.as_i8x16()))
+}
+
+/// Horizontally adds each group of 8 adjacent packed signed 8-bit integers in `a`, and
+/// returns the sums as packed 64-bit integers.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphaddbq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_haddq_epi8(a: __m128i) -> __m128i {
+    transmute(vphaddbq(a.as_i8x16()))
+}
+
+/// Horizontally adds adjacent pairs of packed signed 16-bit integers in `a`, and
+/// returns the sums as packed 32-bit integers.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphaddwd))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_haddd_epi16(a: __m128i) -> __m128i {
+    transmute(vphaddwd(a.as_i16x8()))
+}
+
+/// Horizontally adds each group of 4 adjacent packed signed 16-bit integers in `a`, and
+/// returns the sums as packed 64-bit integers.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphaddwq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_haddq_epi16(a: __m128i) -> __m128i {
+    transmute(vphaddwq(a.as_i16x8()))
+}
+
+/// Horizontally adds adjacent pairs of packed signed 32-bit integers in `a`, and
+/// returns the sums as packed 64-bit integers.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphadddq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_haddq_epi32(a: __m128i) -> __m128i {
+    transmute(vphadddq(a.as_i32x4()))
+}
+
+/// Horizontally adds adjacent pairs of packed unsigned 8-bit integers in `a`, and
+/// returns the sums as packed 16-bit integers.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphaddubw))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_haddw_epu8(a: __m128i) -> __m128i {
+    transmute(vphaddubw(a.as_u8x16()))
+}
+
+/// Horizontally adds each group of 4 adjacent packed unsigned 8-bit integers in `a`, and
+/// returns the sums as packed 32-bit integers.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphaddubd))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_haddd_epu8(a: __m128i) -> __m128i {
+    transmute(vphaddubd(a.as_u8x16()))
+}
+
+/// Horizontally adds each group of 8 adjacent packed unsigned 8-bit integers in `a`, and
+/// returns the sums as packed 64-bit integers.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphaddubq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_haddq_epu8(a: __m128i) -> __m128i {
+    transmute(vphaddubq(a.as_u8x16()))
+}
+
+/// Horizontally adds adjacent pairs of packed unsigned 16-bit integers in `a`, and
+/// returns the sums as packed 32-bit integers.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphadduwd))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_haddd_epu16(a: __m128i) -> __m128i {
+    transmute(vphadduwd(a.as_u16x8()))
+}
+
+/// Horizontally adds each group of 4 adjacent packed unsigned 16-bit integers in `a`, and
+/// returns the sums as packed 64-bit integers.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphadduwq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_haddq_epu16(a: __m128i) -> __m128i {
+    transmute(vphadduwq(a.as_u16x8()))
+}
+
+/// Horizontally adds adjacent pairs of packed unsigned 32-bit integers in `a`, and
+/// returns the sums as packed 64-bit integers.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphaddudq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_haddq_epu32(a: __m128i) -> __m128i {
+    transmute(vphaddudq(a.as_u32x4()))
+}
+
+/// Horizontally subtracts the odd-indexed signed 8-bit integers in `a` from
+/// the even-indexed ones, and returns the differences as packed 16-bit
+/// integers.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphsubbw))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_hsubw_epi8(a: __m128i) -> __m128i {
+    transmute(vphsubbw(a.as_i8x16()))
+}
+
+/// Horizontally subtracts the odd-indexed signed 16-bit integers in `a` from
+/// the even-indexed ones, and returns the differences as packed 32-bit
+/// integers.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphsubwd))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_hsubd_epi16(a: __m128i) -> __m128i {
+    transmute(vphsubwd(a.as_i16x8()))
+}
+
+/// Horizontally subtracts the odd-indexed signed 32-bit integers in `a` from
+/// the even-indexed ones, and returns the differences as packed 64-bit
+/// integers.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphsubdq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_hsubq_epi32(a: __m128i) -> __m128i {
+    transmute(vphsubdq(a.as_i32x4()))
+}
+
+/// Selects bits from `a` where the corresponding bit in `c` is set, and
+/// from `b` otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcmov))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_cmov_si128(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    let a = a.as_i64x2();
+    let b = b.as_i64x2();
+    let c = c.as_i64x2();
+    let not_c = simd_xor(c, i64x2::splat(-1));
+    transmute(simd_or(simd_and(a, c), simd_and(b, not_c)))
+}
+
+/// Selects bits from `a` where the corresponding bit in `c` is set, and
+/// from `b` otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcmov))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm256_cmov_si256(a: __m256i, b: __m256i, c: __m256i) -> __m256i {
+    let a = a.as_i64x4();
+    let b = b.as_i64x4();
+    let c = c.as_i64x4();
+    let not_c = simd_xor(c, i64x4::splat(-1));
+    transmute(simd_or(simd_and(a, c), simd_and(b, not_c)))
+}
+
+/// Permutes the bytes of `a` and `b` using the selectors in `c`.
+///
+/// Bits `[4:0]` of each byte of `c` select one of the 32 bytes of `a` and
+/// `b`, where bytes 0 to 15 come from `a` and bytes 16 to 31 from `b`. Bits
+/// `[7:5]` select an operation to apply to the selected byte:
+///
+/// * 0: none,
+/// * 1: invert all bits,
+/// * 2: reverse the order of the bits,
+/// * 3: invert all bits and reverse their order,
+/// * 4: replace it with `0x00`,
+/// * 5: replace it with `0xFF`,
+/// * 6: replicate its most significant bit to all bits,
+/// * 7: replicate the inverse of its most significant bit to all bits.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpperm))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_perm_epi8(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    transmute(vpperm(a.as_i8x16(), b.as_i8x16(), c.as_i8x16()))
+}
+
+/// Rotates each packed 8-bit integer in `a` by the number of bits specified
+/// by the corresponding element of `b`. The rotation is to the left if the
+/// count is positive, and to the right if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vprotb))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_rot_epi8(a: __m128i, b: __m128i) -> __m128i {
+    let a = a.as_i8x16();
+    transmute(fshl8(a, a, b.as_i8x16()))
+}
+
+/// Rotates each packed 16-bit integer in `a` by the number of bits specified
+/// by the corresponding element of `b`. The rotation is to the left if the
+/// count is positive, and to the right if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vprotw))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_rot_epi16(a: __m128i, b: __m128i) -> __m128i {
+    let a = a.as_i16x8();
+    transmute(fshl16(a, a, b.as_i16x8()))
+}
+
+/// Rotates each packed 32-bit integer in `a` by the number of bits specified
+/// by the corresponding element of `b`. The rotation is to the left if the
+/// count is positive, and to the right if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vprotd))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_rot_epi32(a: __m128i, b: __m128i) -> __m128i {
+    let a = a.as_i32x4();
+    transmute(fshl32(a, a, b.as_i32x4()))
+}
+
+/// Rotates each packed 64-bit integer in `a` by the number of bits specified
+/// by the corresponding element of `b`. The rotation is to the left if the
+/// count is positive, and to the right if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vprotq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_rot_epi64(a: __m128i, b: __m128i) -> __m128i {
+    let a = a.as_i64x2();
+    transmute(fshl64(a, a, b.as_i64x2()))
+}
+
+/// Rotates each packed 8-bit integer in `a` by `IMM8` bits. The rotation is
+/// to the left if `IMM8` is positive, and to the right if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vprotb, IMM8 = 1))]
+#[rustc_legacy_const_generics(1)]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_roti_epi8<const IMM8: i32>(a: __m128i) -> __m128i {
+    static_assert_simm_bits!(IMM8, 8);
+    let a = a.as_i8x16();
+    transmute(fshl8(a, a, i8x16::splat(IMM8 as i8)))
+}
+
+/// Rotates each packed 16-bit integer in `a` by `IMM8` bits. The rotation is
+/// to the left if `IMM8` is positive, and to the right if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vprotw, IMM8 = 1))]
+#[rustc_legacy_const_generics(1)]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_roti_epi16<const IMM8: i32>(a: __m128i) -> __m128i {
+    static_assert_simm_bits!(IMM8, 8);
+    let a = a.as_i16x8();
+    transmute(fshl16(a, a, i16x8::splat(IMM8 as i16)))
+}
+
+/// Rotates each packed 32-bit integer in `a` by `IMM8` bits. The rotation is
+/// to the left if `IMM8` is positive, and to the right if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vprotd, IMM8 = 1))]
+#[rustc_legacy_const_generics(1)]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_roti_epi32<const IMM8: i32>(a: __m128i) -> __m128i {
+    static_assert_simm_bits!(IMM8, 8);
+    let a = a.as_i32x4();
+    transmute(fshl32(a, a, i32x4::splat(IMM8 as i32)))
+}
+
+/// Rotates each packed 64-bit integer in `a` by `IMM8` bits. The rotation is
+/// to the left if `IMM8` is positive, and to the right if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vprotq, IMM8 = 1))]
+#[rustc_legacy_const_generics(1)]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_roti_epi64<const IMM8: i32>(a: __m128i) -> __m128i {
+    static_assert_simm_bits!(IMM8, 8);
+    let a = a.as_i64x2();
+    transmute(fshl64(a, a, i64x2::splat(IMM8 as i64)))
+}
+
+/// Shifts each packed 8-bit integer in `a` by the number of bits specified
+/// by the corresponding element of `b`, shifting in zeros. The shift is to
+/// the left if the count is positive, and to the right if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpshlb))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_shl_epi8(a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpshlb(a.as_i8x16(), b.as_i8x16()))
+}
+
+/// Shifts each packed 16-bit integer in `a` by the number of bits specified
+/// by the corresponding element of `b`, shifting in zeros. The shift is to
+/// the left if the count is positive, and to the right if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpshlw))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_shl_epi16(a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpshlw(a.as_i16x8(), b.as_i16x8()))
+}
+
+/// Shifts each packed 32-bit integer in `a` by the number of bits specified
+/// by the corresponding element of `b`, shifting in zeros. The shift is to
+/// the left if the count is positive, and to the right if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpshld))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_shl_epi32(a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpshld(a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Shifts each packed 64-bit integer in `a` by the number of bits specified
+/// by the corresponding element of `b`, shifting in zeros. The shift is to
+/// the left if the count is positive, and to the right if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpshlq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_shl_epi64(a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpshlq(a.as_i64x2(), b.as_i64x2()))
+}
+
+/// Shifts each packed signed 8-bit integer in `a` by the number of bits
+/// specified by the corresponding element of `b`. The shift is to the left,
+/// shifting in zeros, if the count is positive, and to the right, shifting
+/// in sign bits, if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpshab))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_sha_epi8(a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpshab(a.as_i8x16(), b.as_i8x16()))
+}
+
+/// Shifts each packed signed 16-bit integer in `a` by the number of bits
+/// specified by the corresponding element of `b`. The shift is to the left,
+/// shifting in zeros, if the count is positive, and to the right, shifting
+/// in sign bits, if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpshaw))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_sha_epi16(a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpshaw(a.as_i16x8(), b.as_i16x8()))
+}
+
+/// Shifts each packed signed 32-bit integer in `a` by the number of bits
+/// specified by the corresponding element of `b`. The shift is to the left,
+/// shifting in zeros, if the count is positive, and to the right, shifting
+/// in sign bits, if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpshad))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_sha_epi32(a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpshad(a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Shifts each packed signed 64-bit integer in `a` by the number of bits
+/// specified by the corresponding element of `b`. The shift is to the left,
+/// shifting in zeros, if the count is positive, and to the right, shifting
+/// in sign bits, if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpshaq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_sha_epi64(a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpshaq(a.as_i64x2(), b.as_i64x2()))
+}
+
+/// `_mm_com_*` predicate: less than
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub const _MM_PCOMCTRL_LT: i32 = 0;
+/// `_mm_com_*` predicate: less than or equal
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub const _MM_PCOMCTRL_LE: i32 = 1;
+/// `_mm_com_*` predicate: greater than
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub const _MM_PCOMCTRL_GT: i32 = 2;
+/// `_mm_com_*` predicate: greater than or equal
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub const _MM_PCOMCTRL_GE: i32 = 3;
+/// `_mm_com_*` predicate: equal
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub const _MM_PCOMCTRL_EQ: i32 = 4;
+/// `_mm_com_*` predicate: not equal
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub const _MM_PCOMCTRL_NEQ: i32 = 5;
+/// `_mm_com_*` predicate: always false
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub const _MM_PCOMCTRL_FALSE: i32 = 6;
+/// `_mm_com_*` predicate: always true
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub const _MM_PCOMCTRL_TRUE: i32 = 7;
+
+/// Compares packed signed 8-bit integers in `a` and `b` using the predicate
+/// `IMM8`, which is one of the `_MM_PCOMCTRL_*` constants.
+///
+/// Each element of the result is set to all ones if the comparison is true,
+/// and to all zeros otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomltb, IMM8 = _MM_PCOMCTRL_LT))]
+#[rustc_legacy_const_generics(2)]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_com_epi8<const IMM8: i32>(a: __m128i, b: __m128i) -> __m128i {
+    static_assert_uimm_bits!(IMM8, 3);
+    let a = a.as_i8x16();
+    let b = b.as_i8x16();
+    let r: i8x16 = match IMM8 {
+        _MM_PCOMCTRL_LT => simd_lt(a, b),
+        _MM_PCOMCTRL_LE => simd_le(a, b),
+        _MM_PCOMCTRL_GT => simd_gt(a, b),
+        _MM_PCOMCTRL_GE => simd_ge(a, b),
+        _MM_PCOMCTRL_EQ => simd_eq(a, b),
+        _MM_PCOMCTRL_NEQ => simd_ne(a, b),
+        _MM_PCOMCTRL_FALSE => i8x16::splat(0),
+        _ => i8x16::splat(-1),
+    };
+    transmute(r)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for less than.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomltb))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comlt_epi8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi8::<_MM_PCOMCTRL_LT>(a, b)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for less than or equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomleb))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comle_epi8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi8::<_MM_PCOMCTRL_LE>(a, b)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for greater than.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomgtb))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comgt_epi8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi8::<_MM_PCOMCTRL_GT>(a, b)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for greater than or equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomgeb))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comge_epi8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi8::<_MM_PCOMCTRL_GE>(a, b)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomeq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comeq_epi8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi8::<_MM_PCOMCTRL_EQ>(a, b)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for not equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomneq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comneq_epi8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi8::<_MM_PCOMCTRL_NEQ>(a, b)
+}
+
+/// Returns all zeros. This is the `_MM_PCOMCTRL_FALSE` form of
+/// [`_mm_com_epi8`].
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vxorps))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comfalse_epi8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi8::<_MM_PCOMCTRL_FALSE>(a, b)
+}
+
+/// Returns all ones. This is the `_MM_PCOMCTRL_TRUE` form of
+/// [`_mm_com_epi8`].
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcmpeqd))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comtrue_epi8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi8::<_MM_PCOMCTRL_TRUE>(a, b)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` using the predicate
+/// `IMM8`, which is one of the `_MM_PCOMCTRL_*` constants.
+///
+/// Each element of the result is set to all ones if the comparison is true,
+/// and to all zeros otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomltw, IMM8 = _MM_PCOMCTRL_LT))]
+#[rustc_legacy_const_generics(2)]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_com_epi16<const IMM8: i32>(a: __m128i, b: __m128i) -> __m128i {
+    static_assert_uimm_bits!(IMM8, 3);
+    let a = a.as_i16x8();
+    let b = b.as_i16x8();
+    let r: i16x8 = match IMM8 {
+        _MM_PCOMCTRL_LT => simd_lt(a, b),
+        _MM_PCOMCTRL_LE => simd_le(a, b),
+        _MM_PCOMCTRL_GT => simd_gt(a, b),
+        _MM_PCOMCTRL_GE => simd_ge(a, b),
+        _MM_PCOMCTRL_EQ => simd_eq(a, b),
+        _MM_PCOMCTRL_NEQ => simd_ne(a, b),
+        _MM_PCOMCTRL_FALSE => i16x8::splat(0),
+        _ => i16x8::splat(-1),
+    };
+    transmute(r)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for less than.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomltw))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comlt_epi16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi16::<_MM_PCOMCTRL_LT>(a, b)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for less than or equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomlew))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comle_epi16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi16::<_MM_PCOMCTRL_LE>(a, b)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for greater than.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomgtw))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comgt_epi16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi16::<_MM_PCOMCTRL_GT>(a, b)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for greater than or equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomgew))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comge_epi16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi16::<_MM_PCOMCTRL_GE>(a, b)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomeq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comeq_epi16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi16::<_MM_PCOMCTRL_EQ>(a, b)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for not equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomneq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comneq_epi16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi16::<_MM_PCOMCTRL_NEQ>(a, b)
+}
+
+/// Returns all zeros. This is the `_MM_PCOMCTRL_FALSE` form of
+/// [`_mm_com_epi16`].
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vxorps))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comfalse_epi16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi16::<_MM_PCOMCTRL_FALSE>(a, b)
+}
+
+/// Returns all ones. This is the `_MM_PCOMCTRL_TRUE` form of
+/// [`_mm_com_epi16`].
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcmpeqd))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comtrue_epi16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi16::<_MM_PCOMCTRL_TRUE>(a, b)
+}
+
+/// Compares packed signed 32-bit integers in `a` and `b` using the predicate
+/// `IMM8`, which is one of the `_MM_PCOMCTRL_*` constants.
+///
+/// Each element of the result is set to all ones if the comparison is true,
+/// and to all zeros otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomltd, IMM8 = _MM_PCOMCTRL_LT))]
+#[rustc_legacy_const_generics(2)]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_com_epi32<const IMM8: i32>(a: __m128i, b: __m128i) -> __m128i {
+    static_assert_uimm_bits!(IMM8, 3);
+    let a = a.as_i32x4();
+    let b = b.as_i32x4();
+    let r: i32x4 = match IMM8 {
+        _MM_PCOMCTRL_LT => simd_lt(a, b),
+        _MM_PCOMCTRL_LE => simd_le(a, b),
+        _MM_PCOMCTRL_GT => simd_gt(a, b),
+        _MM_PCOMCTRL_GE => simd_ge(a, b),
+        _MM_PCOMCTRL_EQ => simd_eq(a, b),
+        _MM_PCOMCTRL_NEQ => simd_ne(a, b),
+        _MM_PCOMCTRL_FALSE => i32x4::splat(0),
+        _ => i32x4::splat(-1),
+    };
+    transmute(r)
+}
+
+/// Compares packed signed 32-bit integers in `a` and `b` for less than.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomltd))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comlt_epi32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi32::<_MM_PCOMCTRL_LT>(a, b)
+}
+
+/// Compares packed signed 32-bit integers in `a` and `b` for less than or equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomled))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comle_epi32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi32::<_MM_PCOMCTRL_LE>(a, b)
+}
+
+/// Compares packed signed 32-bit integers in `a` and `b` for greater than.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomgtd))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comgt_epi32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi32::<_MM_PCOMCTRL_GT>(a, b)
+}
+
+/// Compares packed signed 32-bit integers in `a` and `b` for greater than or equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomged))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comge_epi32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi32::<_MM_PCOMCTRL_GE>(a, b)
+}
+
+/// Compares packed signed 32-bit integers in `a` and `b` for equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomeq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comeq_epi32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi32::<_MM_PCOMCTRL_EQ>(a, b)
+}
+
+/// Compares packed signed 32-bit integers in `a` and `b` for not equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomneq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comneq_epi32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi32::<_MM_PCOMCTRL_NEQ>(a, b)
+}
+
+/// Returns all zeros. This is the `_MM_PCOMCTRL_FALSE` form of
+/// [`_mm_com_epi32`].
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vxorps))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comfalse_epi32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi32::<_MM_PCOMCTRL_FALSE>(a, b)
+}
+
+/// Returns all ones. This is the `_MM_PCOMCTRL_TRUE` form of
+/// [`_mm_com_epi32`].
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcmpeqd))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comtrue_epi32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi32::<_MM_PCOMCTRL_TRUE>(a, b)
+}
+
+/// Compares packed signed 64-bit integers in `a` and `b` using the predicate
+/// `IMM8`, which is one of the `_MM_PCOMCTRL_*` constants.
+///
+/// Each element of the result is set to all ones if the comparison is true,
+/// and to all zeros otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomltq, IMM8 = _MM_PCOMCTRL_LT))]
+#[rustc_legacy_const_generics(2)]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_com_epi64<const IMM8: i32>(a: __m128i, b: __m128i) -> __m128i {
+    static_assert_uimm_bits!(IMM8, 3);
+    let a = a.as_i64x2();
+    let b = b.as_i64x2();
+    let r: i64x2 = match IMM8 {
+        _MM_PCOMCTRL_LT => simd_lt(a, b),
+        _MM_PCOMCTRL_LE => simd_le(a, b),
+        _MM_PCOMCTRL_GT => simd_gt(a, b),
+        _MM_PCOMCTRL_GE => simd_ge(a, b),
+        _MM_PCOMCTRL_EQ => simd_eq(a, b),
+        _MM_PCOMCTRL_NEQ => simd_ne(a, b),
+        _MM_PCOMCTRL_FALSE => i64x2::splat(0),
+        _ => i64x2::splat(-1),
+    };
+    transmute(r)
+}
+
+/// Compares packed signed 64-bit integers in `a` and `b` for less than.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomltq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comlt_epi64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi64::<_MM_PCOMCTRL_LT>(a, b)
+}
+
+/// Compares packed signed 64-bit integers in `a` and `b` for less than or equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomleq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comle_epi64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi64::<_MM_PCOMCTRL_LE>(a, b)
+}
+
+/// Compares packed signed 64-bit integers in `a` and `b` for greater than.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomgtq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comgt_epi64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi64::<_MM_PCOMCTRL_GT>(a, b)
+}
+
+/// Compares packed signed 64-bit integers in `a` and `b` for greater than or equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomgeq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comge_epi64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi64::<_MM_PCOMCTRL_GE>(a, b)
+}
+
+/// Compares packed signed 64-bit integers in `a` and `b` for equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomeq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comeq_epi64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi64::<_MM_PCOMCTRL_EQ>(a, b)
+}
+
+/// Compares packed signed 64-bit integers in `a` and `b` for not equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomneq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comneq_epi64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi64::<_MM_PCOMCTRL_NEQ>(a, b)
+}
+
+/// Returns all zeros. This is the `_MM_PCOMCTRL_FALSE` form of
+/// [`_mm_com_epi64`].
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vxorps))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comfalse_epi64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi64::<_MM_PCOMCTRL_FALSE>(a, b)
+}
+
+/// Returns all ones. This is the `_MM_PCOMCTRL_TRUE` form of
+/// [`_mm_com_epi64`].
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcmpeqd))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comtrue_epi64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi64::<_MM_PCOMCTRL_TRUE>(a, b)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` using the predicate
+/// `IMM8`, which is one of the `_MM_PCOMCTRL_*` constants.
+///
+/// Each element of the result is set to all ones if the comparison is true,
+/// and to all zeros otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomltub, IMM8 = _MM_PCOMCTRL_LT))]
+#[rustc_legacy_const_generics(2)]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_com_epu8<const IMM8: i32>(a: __m128i, b: __m128i) -> __m128i {
+    static_assert_uimm_bits!(IMM8, 3);
+    let a = a.as_u8x16();
+    let b = b.as_u8x16();
+    let r: i8x16 = match IMM8 {
+        _MM_PCOMCTRL_LT => simd_lt(a, b),
+        _MM_PCOMCTRL_LE => simd_le(a, b),
+        _MM_PCOMCTRL_GT => simd_gt(a, b),
+        _MM_PCOMCTRL_GE => simd_ge(a, b),
+        _MM_PCOMCTRL_EQ => simd_eq(a, b),
+        _MM_PCOMCTRL_NEQ => simd_ne(a, b),
+        _MM_PCOMCTRL_FALSE => i8x16::splat(0),
+        _ => i8x16::splat(-1),
+    };
+    transmute(r)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for less than.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomltub))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comlt_epu8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu8::<_MM_PCOMCTRL_LT>(a, b)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for less than or equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomleub))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comle_epu8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu8::<_MM_PCOMCTRL_LE>(a, b)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for greater than.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomgtub))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comgt_epu8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu8::<_MM_PCOMCTRL_GT>(a, b)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for greater than or equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomgeub))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comge_epu8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu8::<_MM_PCOMCTRL_GE>(a, b)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomeq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comeq_epu8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu8::<_MM_PCOMCTRL_EQ>(a, b)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for not equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomneq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comneq_epu8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu8::<_MM_PCOMCTRL_NEQ>(a, b)
+}
+
+/// Returns all zeros. This is the `_MM_PCOMCTRL_FALSE` form of
+/// [`_mm_com_epu8`].
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vxorps))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comfalse_epu8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu8::<_MM_PCOMCTRL_FALSE>(a, b)
+}
+
+/// Returns all ones. This is the `_MM_PCOMCTRL_TRUE` form of
+/// [`_mm_com_epu8`].
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcmpeqd))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comtrue_epu8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu8::<_MM_PCOMCTRL_TRUE>(a, b)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` using the predicate
+/// `IMM8`, which is one of the `_MM_PCOMCTRL_*` constants.
+///
+/// Each element of the result is set to all ones if the comparison is true,
+/// and to all zeros otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomltuw, IMM8 = _MM_PCOMCTRL_LT))]
+#[rustc_legacy_const_generics(2)]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_com_epu16<const IMM8: i32>(a: __m128i, b: __m128i) -> __m128i {
+    static_assert_uimm_bits!(IMM8, 3);
+    let a = a.as_u16x8();
+    let b = b.as_u16x8();
+    let r: i16x8 = match IMM8 {
+        _MM_PCOMCTRL_LT => simd_lt(a, b),
+        _MM_PCOMCTRL_LE => simd_le(a, b),
+        _MM_PCOMCTRL_GT => simd_gt(a, b),
+        _MM_PCOMCTRL_GE => simd_ge(a, b),
+        _MM_PCOMCTRL_EQ => simd_eq(a, b),
+        _MM_PCOMCTRL_NEQ => simd_ne(a, b),
+        _MM_PCOMCTRL_FALSE => i16x8::splat(0),
+        _ => i16x8::splat(-1),
+    };
+    transmute(r)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for less than.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomltuw))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comlt_epu16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu16::<_MM_PCOMCTRL_LT>(a, b)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for less than or equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomleuw))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comle_epu16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu16::<_MM_PCOMCTRL_LE>(a, b)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for greater than.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomgtuw))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comgt_epu16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu16::<_MM_PCOMCTRL_GT>(a, b)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for greater than or equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomgeuw))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comge_epu16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu16::<_MM_PCOMCTRL_GE>(a, b)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomeq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comeq_epu16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu16::<_MM_PCOMCTRL_EQ>(a, b)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for not equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomneq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comneq_epu16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu16::<_MM_PCOMCTRL_NEQ>(a, b)
+}
+
+/// Returns all zeros. This is the `_MM_PCOMCTRL_FALSE` form of
+/// [`_mm_com_epu16`].
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vxorps))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comfalse_epu16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu16::<_MM_PCOMCTRL_FALSE>(a, b)
+}
+
+/// Returns all ones. This is the `_MM_PCOMCTRL_TRUE` form of
+/// [`_mm_com_epu16`].
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcmpeqd))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comtrue_epu16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu16::<_MM_PCOMCTRL_TRUE>(a, b)
+}
+
+/// Compares packed unsigned 32-bit integers in `a` and `b` using the predicate
+/// `IMM8`, which is one of the `_MM_PCOMCTRL_*` constants.
+///
+/// Each element of the result is set to all ones if the comparison is true,
+/// and to all zeros otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomltud, IMM8 = _MM_PCOMCTRL_LT))]
+#[rustc_legacy_const_generics(2)]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_com_epu32<const IMM8: i32>(a: __m128i, b: __m128i) -> __m128i {
+    static_assert_uimm_bits!(IMM8, 3);
+    let a = a.as_u32x4();
+    let b = b.as_u32x4();
+    let r: i32x4 = match IMM8 {
+        _MM_PCOMCTRL_LT => simd_lt(a, b),
+        _MM_PCOMCTRL_LE => simd_le(a, b),
+        _MM_PCOMCTRL_GT => simd_gt(a, b),
+        _MM_PCOMCTRL_GE => simd_ge(a, b),
+        _MM_PCOMCTRL_EQ => simd_eq(a, b),
+        _MM_PCOMCTRL_NEQ => simd_ne(a, b),
+        _MM_PCOMCTRL_FALSE => i32x4::splat(0),
+        _ => i32x4::splat(-1),
+    };
+    transmute(r)
+}
+
+/// Compares packed unsigned 32-bit integers in `a` and `b` for less than.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomltud))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comlt_epu32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu32::<_MM_PCOMCTRL_LT>(a, b)
+}
+
+/// Compares packed unsigned 32-bit integers in `a` and `b` for less than or equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomleud))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comle_epu32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu32::<_MM_PCOMCTRL_LE>(a, b)
+}
+
+/// Compares packed unsigned 32-bit integers in `a` and `b` for greater than.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomgtud))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comgt_epu32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu32::<_MM_PCOMCTRL_GT>(a, b)
+}
+
+/// Compares packed unsigned 32-bit integers in `a` and `b` for greater than or equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomgeud))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comge_epu32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu32::<_MM_PCOMCTRL_GE>(a, b)
+}
+
+/// Compares packed unsigned 32-bit integers in `a` and `b` for equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomeq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comeq_epu32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu32::<_MM_PCOMCTRL_EQ>(a, b)
+}
+
+/// Compares packed unsigned 32-bit integers in `a` and `b` for not equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomneq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comneq_epu32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu32::<_MM_PCOMCTRL_NEQ>(a, b)
+}
+
+/// Returns all zeros. This is the `_MM_PCOMCTRL_FALSE` form of
+/// [`_mm_com_epu32`].
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vxorps))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comfalse_epu32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu32::<_MM_PCOMCTRL_FALSE>(a, b)
+}
+
+/// Returns all ones. This is the `_MM_PCOMCTRL_TRUE` form of
+/// [`_mm_com_epu32`].
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcmpeqd))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comtrue_epu32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu32::<_MM_PCOMCTRL_TRUE>(a, b)
+}
+
+/// Compares packed unsigned 64-bit integers in `a` and `b` using the predicate
+/// `IMM8`, which is one of the `_MM_PCOMCTRL_*` constants.
+///
+/// Each element of the result is set to all ones if the comparison is true,
+/// and to all zeros otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomltuq, IMM8 = _MM_PCOMCTRL_LT))]
+#[rustc_legacy_const_generics(2)]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_com_epu64<const IMM8: i32>(a: __m128i, b: __m128i) -> __m128i {
+    static_assert_uimm_bits!(IMM8, 3);
+    let a = a.as_u64x2();
+    let b = b.as_u64x2();
+    let r: i64x2 = match IMM8 {
+        _MM_PCOMCTRL_LT => simd_lt(a, b),
+        _MM_PCOMCTRL_LE => simd_le(a, b),
+        _MM_PCOMCTRL_GT => simd_gt(a, b),
+        _MM_PCOMCTRL_GE => simd_ge(a, b),
+        _MM_PCOMCTRL_EQ => simd_eq(a, b),
+        _MM_PCOMCTRL_NEQ => simd_ne(a, b),
+        _MM_PCOMCTRL_FALSE => i64x2::splat(0),
+        _ => i64x2::splat(-1),
+    };
+    transmute(r)
+}
+
+/// Compares packed unsigned 64-bit integers in `a` and `b` for less than.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomltuq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comlt_epu64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu64::<_MM_PCOMCTRL_LT>(a, b)
+}
+
+/// Compares packed unsigned 64-bit integers in `a` and `b` for less than or equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomleuq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comle_epu64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu64::<_MM_PCOMCTRL_LE>(a, b)
+}
+
+/// Compares packed unsigned 64-bit integers in `a` and `b` for greater than.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomgtuq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comgt_epu64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu64::<_MM_PCOMCTRL_GT>(a, b)
+}
+
+/// Compares packed unsigned 64-bit integers in `a` and `b` for greater than or equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomgeuq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comge_epu64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu64::<_MM_PCOMCTRL_GE>(a, b)
+}
+
+/// Compares packed unsigned 64-bit integers in `a` and `b` for equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomeq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comeq_epu64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu64::<_MM_PCOMCTRL_EQ>(a, b)
+}
+
+/// Compares packed unsigned 64-bit integers in `a` and `b` for not equal.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomneq))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comneq_epu64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu64::<_MM_PCOMCTRL_NEQ>(a, b)
+}
+
+/// Returns all zeros. This is the `_MM_PCOMCTRL_FALSE` form of
+/// [`_mm_com_epu64`].
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vxorps))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comfalse_epu64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu64::<_MM_PCOMCTRL_FALSE>(a, b)
+}
+
+/// Returns all ones. This is the `_MM_PCOMCTRL_TRUE` form of
+/// [`_mm_com_epu64`].
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcmpeqd))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_comtrue_epu64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu64::<_MM_PCOMCTRL_TRUE>(a, b)
+}
+
+/// Permutes the double-precision (64-bit) floating-point elements of `a` and
+/// `b` using the selectors in `c`.
+///
+/// Bits `[2:1]` of each element of `c` select an element: bit 1 selects the
+/// element within a source, and bit 2 selects the source, `a` or `b`.
+///
+/// Bit 3 of each element of `c` and `IMM8` control zeroing: if `IMM8` is 2
+/// and bit 3 is set, or if `IMM8` is 3 and bit 3 is clear, the element is
+/// set to zero instead.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpermil2pd, IMM8 = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_permute2_pd<const IMM8: i32>(a: __m128d, b: __m128d, c: __m128i) -> __m128d {
+    static_assert_uimm_bits!(IMM8, 2);
+    vpermil2pd(a, b, c.as_i64x2(), IMM8 as i8)
+}
+
+/// Permutes the double-precision (64-bit) floating-point elements of `a` and
+/// `b` using the selectors in `c`.
+///
+/// Bits `[2:1]` of each element of `c` select an element from the same 128-bit
+/// lane: bit 1 selects the element within a source, and bit 2 selects the
+/// source, `a` or `b`.
+///
+/// Bit 3 of each element of `c` and `IMM8` control zeroing: if `IMM8` is 2
+/// and bit 3 is set, or if `IMM8` is 3 and bit 3 is clear, the element is
+/// set to zero instead.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpermil2pd, IMM8 = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm256_permute2_pd<const IMM8: i32>(a: __m256d, b: __m256d, c: __m256i) -> __m256d {
+    static_assert_uimm_bits!(IMM8, 2);
+    vpermil2pd256(a, b, c.as_i64x4(), IMM8 as i8)
+}
+
+/// Permutes the single-precision (32-bit) floating-point elements of `a` and
+/// `b` using the selectors in `c`.
+///
+/// Bits `[2:0]` of each element of `c` select an element: bits `[1:0]` select
+/// the element within a source, and bit 2 selects the source, `a` or `b`.
+///
+/// Bit 3 of each element of `c` and `IMM8` control zeroing: if `IMM8` is 2
+/// and bit 3 is set, or if `IMM8` is 3 and bit 3 is clear, the element is
+/// set to zero instead.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpermil2ps, IMM8 = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_permute2_ps<const IMM8: i32>(a: __m128, b: __m128, c: __m128i) -> __m128 {
+    static_assert_uimm_bits!(IMM8, 2);
+    vpermil2ps(a, b, c.as_i32x4(), IMM8 as i8)
+}
+
+/// Permutes the single-precision (32-bit) floating-point elements of `a` and
+/// `b` using the selectors in `c`.
+///
+/// Bits `[2:0]` of each element of `c` select an element from the same
+/// 128-bit lane: bits `[1:0]` select the element within a source, and bit 2
+/// selects the source, `a` or `b`.
+///
+/// Bit 3 of each element of `c` and `IMM8` control zeroing: if `IMM8` is 2
+/// and bit 3 is set, or if `IMM8` is 3 and bit 3 is clear, the element is
+/// set to zero instead.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpermil2ps, IMM8 = 0))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm256_permute2_ps<const IMM8: i32>(a: __m256, b: __m256, c: __m256i) -> __m256 {
+    static_assert_uimm_bits!(IMM8, 2);
+    vpermil2ps256(a, b, c.as_i32x8(), IMM8 as i8)
+}
+
+/// Extracts the fractional part of the lower single-precision (32-bit)
+/// floating-point element in `a`, and stores it in the lower element of the
+/// result. The upper elements of the result are zeroed.
+///
+/// The fractional part has the same sign as the element, so it is
+/// computed as `x - x.trunc()`.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vfrczss))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_frcz_ss(a: __m128) -> __m128 {
+    vfrczss(a)
+}
+
+/// Extracts the fractional part of the lower double-precision (64-bit)
+/// floating-point element in `a`, and stores it in the lower element of the
+/// result. The upper element of the result is zeroed.
+///
+/// The fractional part has the same sign as the element, so it is
+/// computed as `x - x.trunc()`.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vfrczsd))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_frcz_sd(a: __m128d) -> __m128d {
+    vfrczsd(a)
+}
+
+/// Extracts the fractional part of each packed single-precision (32-bit)
+/// floating-point element in `a`.
+///
+/// The fractional part has the same sign as the element, so it is
+/// computed as `x - x.trunc()`.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vfrczps))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_frcz_ps(a: __m128) -> __m128 {
+    vfrczps(a)
+}
+
+/// Extracts the fractional part of each packed double-precision (64-bit)
+/// floating-point element in `a`.
+///
+/// The fractional part has the same sign as the element, so it is
+/// computed as `x - x.trunc()`.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vfrczpd))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm_frcz_pd(a: __m128d) -> __m128d {
+    vfrczpd(a)
+}
+
+/// Extracts the fractional part of each packed single-precision (32-bit)
+/// floating-point element in `a`.
+///
+/// The fractional part has the same sign as the element, so it is
+/// computed as `x - x.trunc()`.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vfrczps))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm256_frcz_ps(a: __m256) -> __m256 {
+    vfrczps256(a)
+}
+
+/// Extracts the fractional part of each packed double-precision (64-bit)
+/// floating-point element in `a`.
+///
+/// The fractional part has the same sign as the element, so it is
+/// computed as `x - x.trunc()`.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vfrczpd))]
+#[unstable(feature = "xop_target_feature", issue = "127208")]
+pub unsafe fn _mm256_frcz_pd(a: __m256d) -> __m256d {
+    vfrczpd256(a)
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.xop.vpmacssww"]
+    fn vpmacssww(a: i16x8, b: i16x8, c: i16x8) -> i16x8;
+    #[link_name = "llvm.x86.xop.vpmacsww"]
+    fn vpmacsww(a: i16x8, b: i16x8, c: i16x8) -> i16x8;
+    #[link_name = "llvm.x86.xop.vpmacsswd"]
+    fn vpmacsswd(a: i16x8, b: i16x8, c: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.xop.vpmacswd"]
+    fn vpmacswd(a: i16x8, b: i16x8, c: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.xop.vpmacssdd"]
+    fn vpmacssdd(a: i32x4, b: i32x4, c: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.xop.vpmacsdd"]
+    fn vpmacsdd(a: i32x4, b: i32x4, c: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.xop.vpmacssdql"]
+    fn vpmacssdql(a: i32x4, b: i32x4, c: i64x2) -> i64x2;
+    #[link_name = "llvm.x86.xop.vpmacsdql"]
+    fn vpmacsdql(a: i32x4, b: i32x4, c: i64x2) -> i64x2;
+    #[link_name = "llvm.x86.xop.vpmacssdqh"]
+    fn vpmacssdqh(a: i32x4, b: i32x4, c: i64x2) -> i64x2;
+    #[link_name = "llvm.x86.xop.vpmacsdqh"]
+    fn vpmacsdqh(a: i32x4, b: i32x4, c: i64x2) -> i64x2;
+    #[link_name = "llvm.x86.xop.vpmadcsswd"]
+    fn vpmadcsswd(a: i16x8, b: i16x8, c: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.xop.vpmadcswd"]
+    fn vpmadcswd(a: i16x8, b: i16x8, c: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.xop.vphaddbw"]
+    fn vphaddbw(a: i8x16) -> i16x8;
+    #[link_name = "llvm.x86.xop.vphaddbd"]
+    fn vphaddbd(a: i8x16) -> i32x4;
+    #[link_name = "llvm.x86.xop.vphaddbq"]
+    fn vphaddbq(a: i8x16) -> i64x2;
+    #[link_name = "llvm.x86.xop.vphaddwd"]
+    fn vphaddwd(a: i16x8) -> i32x4;
+    #[link_name = "llvm.x86.xop.vphaddwq"]
+    fn vphaddwq(a: i16x8) -> i64x2;
+    #[link_name = "llvm.x86.xop.vphadddq"]
+    fn vphadddq(a: i32x4) -> i64x2;
+    #[link_name = "llvm.x86.xop.vphaddubw"]
+    fn vphaddubw(a: u8x16) -> i16x8;
+    #[link_name = "llvm.x86.xop.vphaddubd"]
+    fn vphaddubd(a: u8x16) -> i32x4;
+    #[link_name = "llvm.x86.xop.vphaddubq"]
+    fn vphaddubq(a: u8x16) -> i64x2;
+    #[link_name = "llvm.x86.xop.vphadduwd"]
+    fn vphadduwd(a: u16x8) -> i32x4;
+    #[link_name = "llvm.x86.xop.vphadduwq"]
+    fn vphadduwq(a: u16x8) -> i64x2;
+    #[link_name = "llvm.x86.xop.vphaddudq"]
+    fn vphaddudq(a: u32x4) -> i64x2;
+    #[link_name = "llvm.x86.xop.vphsubbw"]
+    fn vphsubbw(a: i8x16) -> i16x8;
+    #[link_name = "llvm.x86.xop.vphsubwd"]
+    fn vphsubwd(a: i16x8) -> i32x4;
+    #[link_name = "llvm.x86.xop.vphsubdq"]
+    fn vphsubdq(a: i32x4) -> i64x2;
+    #[link_name = "llvm.x86.xop.vpperm"]
+    fn vpperm(a: i8x16, b: i8x16, c: i8x16) -> i8x16;
+    #[link_name = "llvm.fshl.v16i8"]
+    fn fshl8(a: i8x16, b: i8x16, c: i8x16) -> i8x16;
+    #[link_name = "llvm.fshl.v8i16"]
+    fn fshl16(a: i16x8, b: i16x8, c: i16x8) -> i16x8;
+    #[link_name = "llvm.fshl.v4i32"]
+    fn fshl32(a: i32x4, b: i32x4, c: i32x4) -> i32x4;
+    #[link_name = "llvm.fshl.v2i64"]
+    fn fshl64(a: i64x2, b: i64x2, c: i64x2) -> i64x2;
+    #[link_name = "llvm.x86.xop.vpshlb"]
+    fn vpshlb(a: i8x16, b: i8x16) -> i8x16;
+    #[link_name = "llvm.x86.xop.vpshlw"]
+    fn vpshlw(a: i16x8, b: i16x8) -> i16x8;
+    #[link_name = "llvm.x86.xop.vpshld"]
+    fn vpshld(a: i32x4, b: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.xop.vpshlq"]
+    fn vpshlq(a: i64x2, b: i64x2) -> i64x2;
+    #[link_name = "llvm.x86.xop.vpshab"]
+    fn vpshab(a: i8x16, b: i8x16) -> i8x16;
+    #[link_name = "llvm.x86.xop.vpshaw"]
+    fn vpshaw(a: i16x8, b: i16x8) -> i16x8;
+    #[link_name = "llvm.x86.xop.vpshad"]
+    fn vpshad(a: i32x4, b: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.xop.vpshaq"]
+    fn vpshaq(a: i64x2, b: i64x2) -> i64x2;
+    #[link_name = "llvm.x86.xop.vpermil2pd"]
+    fn vpermil2pd(a: __m128d, b: __m128d, c: i64x2, imm8: i8) -> __m128d;
+    #[link_name = "llvm.x86.xop.vpermil2pd.256"]
+    fn vpermil2pd256(a: __m256d, b: __m256d, c: i64x4, imm8: i8) -> __m256d;
+    #[link_name = "llvm.x86.xop.vpermil2ps"]
+    fn vpermil2ps(a: __m128, b: __m128, c: i32x4, imm8: i8) -> __m128;
+    #[link_name = "llvm.x86.xop.vpermil2ps.256"]
+    fn vpermil2ps256(a: __m256, b: __m256, c: i32x8, imm8: i8) -> __m256;
+    #[link_name = "llvm.x86.xop.vfrcz.ss"]
+    fn vfrczss(a: __m128) -> __m128;
+    #[link_name = "llvm.x86.xop.vfrcz.sd"]
+    fn vfrczsd(a: __m128d) -> __m128d;
+    #[link_name = "llvm.x86.xop.vfrcz.ps"]
+    fn vfrczps(a: __m128) -> __m128;
+    #[link_name = "llvm.x86.xop.vfrcz.pd"]
+    fn vfrczpd(a: __m128d) -> __m128d;
+    #[link_name = "llvm.x86.xop.vfrcz.ps.256"]
+    fn vfrczps256(a: __m256) -> __m256;
+    #[link_name = "llvm.x86.xop.vfrcz.pd.256"]
+    fn vfrczpd256(a: __m256d) -> __m256d;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use stdarch_test::simd_test;
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_maccs_epi16() {
+        let a = _mm_setr_epi16(1, 2, 3, 4, 5, 6, 7, 8);
+        let b = _mm_set1_epi16(2);
+        let c = _mm_setr_epi16(1, 1, 1, 1, 1, 1, 1, i16::MAX);
+        let r = _mm_setr_epi16(3, 5, 7, 9, 11, 13, 15, i16::MAX);
+        assert_eq_m128i(_mm_maccs_epi16(a, b, c), r);
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_macc_epi16() {
+        let a = _mm_setr_epi16(1, 2, 3, 4, 5, 6, 7, 8);
+        let b = _mm_set1_epi16(2);
+        let c = _mm_setr_epi16(1, 1, 1, 1, 1, 1, 1, i16::MAX);
+        let r = _mm_setr_epi16(3, 5, 7, 9, 11, 13, 15, i16::MIN + 15);
+        assert_eq_m128i(_mm_macc_epi16(a, b, c), r);
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_maccd_epi16() {
+        let a = _mm_set1_epi16(3);
+        let b = _mm_set1_epi16(-4);
+        let c = _mm_set1_epi32(100);
+        let r = _mm_set1_epi32(88);
+        assert_eq_m128i(_mm_maccd_epi16(a, b, c), r);
+        assert_eq_m128i(_mm_maccsd_epi16(a, b, c), r);
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_maccs_epi32() {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let b = _mm_set1_epi32(3);
+        let c = _mm_setr_epi32(-1, -1, -1, i32::MAX);
+        let r = _mm_setr_epi32(2, 5, 8, i32::MAX);
+        assert_eq_m128i(_mm_maccs_epi32(a, b, c), r);
+        let r = _mm_setr_epi32(2, 5, 8, i32::MIN + 11);
+        assert_eq_m128i(_mm_macc_epi32(a, b, c), r);
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_macclo_epi32() {
+        let a = _mm_set1_epi32(i32::MAX);
+        let b = _mm_set1_epi32(2);
+        let c = _mm_set1_epi64x(1);
+        let r = _mm_set1_epi64x(i32::MAX as i64 * 2 + 1);
+        assert_eq_m128i(_mm_macclo_epi32(a, b, c), r);
+        assert_eq_m128i(_mm_maccslo_epi32(a, b, c), r);
+        assert_eq_m128i(_mm_macchi_epi32(a, b, c), r);
+        assert_eq_m128i(_mm_maccshi_epi32(a, b, c), r);
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_maddd_epi16() {
+        let a = _mm_set1_epi16(3);
+        let b = _mm_set1_epi16(5);
+        let c = _mm_set1_epi32(i32::MAX - 1);
+        let r = _mm_set1_epi32(i32::MAX.wrapping_add(29));
+        assert_eq_m128i(_mm_maddd_epi16(a, b, c), r);
+        let r = _mm_set1_epi32(i32::MAX);
+        assert_eq_m128i(_mm_maddsd_epi16(a, b, c), r);
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_hadd_epi8() {
+        let a = _mm_setr_epi8(1, 2, 3, 4, 5, 6, 7, 8, -1, -2, -3, -4, -5, -6, -7, -8);
+        let r = _mm_setr_epi16(3, 7, 11, 15, -3, -7, -11, -15);
+        assert_eq_m128i(_mm_haddw_epi8(a), r);
+        let r = _mm_setr_epi32(10, 26, -10, -26);
+        assert_eq_m128i(_mm_haddd_epi8(a), r);
+        let r = _mm_setr_epi64x(36, -36);
+        assert_eq_m128i(_mm_haddq_epi8(a), r);
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_hadd_epu8() {
+        let a = _mm_set1_epi8(-1);
+        assert_eq_m128i(_mm_haddw_epu8(a), _mm_set1_epi16(510));
+        assert_eq_m128i(_mm_haddd_epu8(a), _mm_set1_epi32(1020));
+        assert_eq_m128i(_mm_haddq_epu8(a), _mm_set1_epi64x(2040));
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_hadd_epi16() {
+        let a = _mm_setr_epi16(1, 2, 3, 4, -1, -2, -3, -4);
+        assert_eq_m128i(_mm_haddd_epi16(a), _mm_setr_epi32(3, 7, -3, -7));
+        assert_eq_m128i(_mm_haddq_epi16(a), _mm_setr_epi64x(10, -10));
+        let a = _mm_set1_epi16(-1);
+        assert_eq_m128i(_mm_haddd_epu16(a), _mm_set1_epi32(0x1fffe));
+        assert_eq_m128i(_mm_haddq_epu16(a), _mm_set1_epi64x(0x3fffc));
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_hadd_epi32() {
+        let a = _mm_setr_epi32(1, 2, -1, -2);
+        assert_eq_m128i(_mm_haddq_epi32(a), _mm_setr_epi64x(3, -3));
+        let a = _mm_set1_epi32(-1);
+        assert_eq_m128i(_mm_haddq_epu32(a), _mm_set1_epi64x(0x1_ffff_fffe));
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_hsub() {
+        let a = _mm_setr_epi8(5, 2, 3, 4, 5, 6, 7, 8, -1, -2, -3, -4, -5, -6, -7, -8);
+        let r = _mm_setr_epi16(3, -1, -1, -1, 1, 1, 1, 1);
+        assert_eq_m128i(_mm_hsubw_epi8(a), r);
+        let a = _mm_setr_epi16(5, 2, 3, 4, -1, -2, -3, -4);
+        assert_eq_m128i(_mm_hsubd_epi16(a), _mm_setr_epi32(3, -1, 1, 1));
+        let a = _mm_setr_epi32(5, 2, -1, -2);
+        assert_eq_m128i(_mm_hsubq_epi32(a), _mm_setr_epi64x(3, 1));
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_cmov_si128() {
+        let a = _mm_set1_epi32(0x1234_5678);
+        let b = _mm_set1_epi32(-1);
+        let c = _mm_set1_epi32(0x0000_ffff);
+        let r = _mm_set1_epi32(0xffff_5678_u32 as i32);
+        assert_eq_m128i(_mm_cmov_si128(a, b, c), r);
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm256_cmov_si256() {
+        let a = _mm256_set1_epi32(0x1234_5678);
+        let b = _mm256_set1_epi32(0);
+        let c = _mm256_set1_epi32(0xffff_0000_u32 as i32);
+        let r = _mm256_set1_epi32(0x1234_0000);
+        assert_eq_m256i(_mm256_cmov_si256(a, b, c), r);
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_perm_epi8() {
+        let a = _mm_setr_epi8(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        let b = _mm_setr_epi8(
+            16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31,
+        );
+        let c = _mm_setr_epi8(
+            31,
+            0,
+            16,
+            1,
+            0x21,
+            0x41,
+            0x80_u8 as i8,
+            0xa0_u8 as i8,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        );
+        let r = _mm_setr_epi8(31, 0, 16, 1, -2, -128, 0, -1, 0, 0, 0, 0, 0, 0, 0, 0);
+        assert_eq_m128i(_mm_perm_epi8(a, b, c), r);
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_rot_epi32() {
+        let a = _mm_setr_epi32(1, 1, 0x8000_0000_u32 as i32, 3);
+        let b = _mm_setr_epi32(1, -1, 1, 0);
+        let r = _mm_setr_epi32(2, 0x8000_0000_u32 as i32, 1, 3);
+        assert_eq_m128i(_mm_rot_epi32(a, b), r);
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_rot_epi8() {
+        let a = _mm_set1_epi8(0x81_u8 as i8);
+        assert_eq_m128i(_mm_rot_epi8(a, _mm_set1_epi8(1)), _mm_set1_epi8(3));
+        assert_eq_m128i(
+            _mm_rot_epi8(a, _mm_set1_epi8(-1)),
+            _mm_set1_epi8(0xc0_u8 as i8),
+        );
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_roti() {
+        let a = _mm_set1_epi16(0x0180);
+        assert_eq_m128i(_mm_roti_epi16::<4>(a), _mm_set1_epi16(0x1800));
+        assert_eq_m128i(_mm_roti_epi16::<-8>(a), _mm_set1_epi16(0x8001_u16 as i16));
+        let a = _mm_set1_epi64x(1);
+        assert_eq_m128i(_mm_roti_epi64::<-1>(a), _mm_set1_epi64x(i64::MIN));
+        let a = _mm_set1_epi32(1);
+        assert_eq_m128i(_mm_roti_epi32::<31>(a), _mm_set1_epi32(i32::MIN));
+        let a = _mm_set1_epi8(1);
+        assert_eq_m128i(_mm_roti_epi8::<7>(a), _mm_set1_epi8(i8::MIN));
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_rot_epi64() {
+        let a = _mm_set1_epi64x(1);
+        let b = _mm_setr_epi64x(63, -63);
+        assert_eq_m128i(_mm_rot_epi64(a, b), _mm_setr_epi64x(i64::MIN, 2));
+        let a = _mm_set1_epi16(1);
+        let b = _mm_set1_epi16(15);
+        assert_eq_m128i(_mm_rot_epi16(a, b), _mm_set1_epi16(i16::MIN));
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_shl_epi32() {
+        let a = _mm_set1_epi32(-16);
+        let b = _mm_setr_epi32(1, -1, 0, -4);
+        let r = _mm_setr_epi32(-32, 0x7fff_fff8, -16, 0x0fff_ffff);
+        assert_eq_m128i(_mm_shl_epi32(a, b), r);
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_shl() {
+        let a = _mm_set1_epi8(-16);
+        let r = _mm_set1_epi8(0x78);
+        assert_eq_m128i(_mm_shl_epi8(a, _mm_set1_epi8(-1)), r);
+        let a = _mm_set1_epi16(-16);
+        let r = _mm_set1_epi16(0x7ff8);
+        assert_eq_m128i(_mm_shl_epi16(a, _mm_set1_epi16(-1)), r);
+        let a = _mm_set1_epi64x(3);
+        let r = _mm_set1_epi64x(12);
+        assert_eq_m128i(_mm_shl_epi64(a, _mm_set1_epi64x(2)), r);
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_sha() {
+        let a = _mm_set1_epi8(-16);
+        let r = _mm_setr_epi8(
+            -32, -8, -16, -1, -32, -8, -16, -1, -32, -8, -16, -1, -32, -8, -16, -1,
+        );
+        let b = _mm_setr_epi8(1, -1, 0, -4, 1, -1, 0, -4, 1, -1, 0, -4, 1, -1, 0, -4);
+        assert_eq_m128i(_mm_sha_epi8(a, b), r);
+        let a = _mm_set1_epi16(-16);
+        let b = _mm_setr_epi16(1, -1, 0, -4, 1, -1, 0, -4);
+        let r = _mm_setr_epi16(-32, -8, -16, -1, -32, -8, -16, -1);
+        assert_eq_m128i(_mm_sha_epi16(a, b), r);
+        let a = _mm_set1_epi32(-16);
+        let b = _mm_setr_epi32(1, -1, 0, -4);
+        let r = _mm_setr_epi32(-32, -8, -16, -1);
+        assert_eq_m128i(_mm_sha_epi32(a, b), r);
+        let a = _mm_set1_epi64x(-16);
+        let b = _mm_setr_epi64x(1, -1);
+        let r = _mm_setr_epi64x(-32, -8);
+        assert_eq_m128i(_mm_sha_epi64(a, b), r);
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_com_epi8() {
+        let a = _mm_setr_epi8(-1, 0, 1, 2, -1, 0, 1, 2, -1, 0, 1, 2, -1, 0, 1, 2);
+        let b = _mm_set1_epi8(1);
+        let r = _mm_setr_epi8(-1, -1, 0, 0, -1, -1, 0, 0, -1, -1, 0, 0, -1, -1, 0, 0);
+        assert_eq_m128i(_mm_com_epi8::<_MM_PCOMCTRL_LT>(a, b), r);
+        assert_eq_m128i(_mm_comlt_epi8(a, b), r);
+        let r = _mm_setr_epi8(0, 0, -1, 0, 0, 0, -1, 0, 0, 0, -1, 0, 0, 0, -1, 0);
+        assert_eq_m128i(_mm_comeq_epi8(a, b), r);
+        assert_eq_m128i(_mm_comfalse_epi8(a, b), _mm_setzero_si128());
+        assert_eq_m128i(_mm_comtrue_epi8(a, b), _mm_set1_epi8(-1));
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_com_epu8() {
+        let a = _mm_setr_epi8(-1, 0, 1, 2, -1, 0, 1, 2, -1, 0, 1, 2, -1, 0, 1, 2);
+        let b = _mm_set1_epi8(1);
+        let r = _mm_setr_epi8(0, -1, 0, 0, 0, -1, 0, 0, 0, -1, 0, 0, 0, -1, 0, 0);
+        assert_eq_m128i(_mm_com_epu8::<_MM_PCOMCTRL_LT>(a, b), r);
+        assert_eq_m128i(_mm_comlt_epu8(a, b), r);
+        let r = _mm_setr_epi8(-1, 0, 0, -1, -1, 0, 0, -1, -1, 0, 0, -1, -1, 0, 0, -1);
+        assert_eq_m128i(_mm_comgt_epu8(a, b), r);
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_com_epi32() {
+        let a = _mm_setr_epi32(-1, 0, 1, 2);
+        let b = _mm_set1_epi32(1);
+        assert_eq_m128i(_mm_comle_epi32(a, b), _mm_setr_epi32(-1, -1, -1, 0));
+        assert_eq_m128i(_mm_comge_epi32(a, b), _mm_setr_epi32(0, 0, -1, -1));
+        assert_eq_m128i(_mm_comneq_epi32(a, b), _mm_setr_epi32(-1, -1, 0, -1));
+        assert_eq_m128i(_mm_comle_epu32(a, b), _mm_setr_epi32(0, -1, -1, 0));
+        assert_eq_m128i(_mm_comge_epu32(a, b), _mm_setr_epi32(-1, 0, -1, -1));
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_com_epi64() {
+        let a = _mm_setr_epi64x(-1, 2);
+        let b = _mm_set1_epi64x(1);
+        assert_eq_m128i(_mm_comgt_epi64(a, b), _mm_setr_epi64x(0, -1));
+        assert_eq_m128i(_mm_comlt_epu64(a, b), _mm_setzero_si128());
+        let a = _mm_setr_epi16(-1, 0, 1, 2, -1, 0, 1, 2);
+        let b = _mm_set1_epi16(1);
+        let r = _mm_setr_epi16(0, -1, 0, 0, 0, -1, 0, 0);
+        assert_eq_m128i(_mm_comlt_epu16(a, b), r);
+        let r = _mm_setr_epi16(-1, -1, 0, 0, -1, -1, 0, 0);
+        assert_eq_m128i(_mm_comlt_epi16(a, b), r);
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_permute2_ps() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 6., 7., 8.);
+        let c = _mm_setr_epi32(3, 4, 8, 12);
+        let r = _mm_setr_ps(4., 5., 1., 5.);
+        assert_eq_m128(_mm_permute2_ps::<0>(a, b, c), r);
+        let r = _mm_setr_ps(4., 5., 0., 0.);
+        assert_eq_m128(_mm_permute2_ps::<2>(a, b, c), r);
+        let r = _mm_setr_ps(0., 0., 1., 5.);
+        assert_eq_m128(_mm_permute2_ps::<3>(a, b, c), r);
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_permute2_pd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(3., 4.);
+        let c = _mm_setr_epi64x(6, 2);
+        assert_eq_m128d(_mm_permute2_pd::<0>(a, b, c), _mm_setr_pd(4., 2.));
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm256_permute2_ps() {
+        let a = _mm256_setr_ps(1., 2., 3., 4., 5., 6., 7., 8.);
+        let b = _mm256_setr_ps(9., 10., 11., 12., 13., 14., 15., 16.);
+        let c = _mm256_setr_epi32(0, 4, 1, 5, 0, 4, 1, 5);
+        let r = _mm256_setr_ps(1., 9., 2., 10., 5., 13., 6., 14.);
+        assert_eq_m256(_mm256_permute2_ps::<0>(a, b, c), r);
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm256_permute2_pd() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let b = _mm256_setr_pd(5., 6., 7., 8.);
+        let c = _mm256_setr_epi64x(2, 4, 0, 6);
+        let r = _mm256_setr_pd(2., 5., 3., 8.);
+        assert_eq_m256d(_mm256_permute2_pd::<0>(a, b, c), r);
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_frcz_ps() {
+        let a = _mm_setr_ps(1.25, -2.5, 3., 0.75);
+        let r = _mm_setr_ps(0.25, -0.5, 0., 0.75);
+        assert_eq_m128(_mm_frcz_ps(a), r);
+        assert_eq!(_mm_cvtss_f32(_mm_frcz_ss(a)), 0.25);
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm_frcz_pd() {
+        let a = _mm_setr_pd(1.25, -2.5);
+        assert_eq_m128d(_mm_frcz_pd(a), _mm_setr_pd(0.25, -0.5));
+        assert_eq!(_mm_cvtsd_f64(_mm_frcz_sd(a)), 0.25);
+    }
+
+    #[simd_test(enable = "xop")]
+    unsafe fn test_mm256_frcz() {
+        let a = _mm256_setr_ps(1.25, -2.5, 3., 0.75, 1.25, -2.5, 3., 0.75);
+        let r = _mm256_setr_ps(0.25, -0.5, 0., 0.75, 0.25, -0.5, 0., 0.75);
+        assert_eq_m256(_mm256_frcz_ps(a), r);
+        let a = _mm256_setr_pd(1.25, -2.5, 3., 0.75);
+        let r = _mm256_setr_pd(0.25, -0.5, 0., 0.75);
+        assert_eq_m256d(_mm256_frcz_pd(a), r);
+    }
+}
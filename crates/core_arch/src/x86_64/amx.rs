@@ -1,3 +1,23 @@
+//! Advanced Matrix Extensions (AMX)
+//!
+//! The `_tile_*` intrinsics map one-to-one to the AMX instructions. They
+//! operate on the tile register named by their const arguments, using the tile
+//! configuration that was loaded with [`_tile_loadconfig`].
+//!
+//! The `__tile_*` intrinsics mirror the names of the Intel C API but not its
+//! implementation. Clang keeps `__tile1024i` values in tile registers and
+//! configures them once per function. Rust cannot do that, so each `__tile_*`
+//! call loads its own tile configuration, loads the tiles from memory, runs
+//! one instruction, stores the result and releases the tiles again. This
+//! makes them convenient for occasional use but much slower than the
+//! `_tile_*` intrinsics in a loop.
+
+#[cfg(target_os = "linux")]
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
 /// Load tile configuration from a 64-byte memory location specified by mem_addr.
 /// The tile configuration format is specified below, and includes the tile type pallette,
 /// the number of bytes per row, and the number of rows. If the specified pallette_id is zero,
@@ -224,6 +244,303 @@ pub unsafe fn _tile_cmmrlfp16ps<const DST: i32, const A: i32, const B: i32>() {
     tcmmrlfp16ps(DST as i8, A as i8, B as i8);
 }
 
+/// A tile together with its shape, as used by the `__tile_*` intrinsics.
+///
+/// Unlike the `_tile_*` intrinsics, which operate on a tile register chosen by
+/// the caller and require the tile configuration to be loaded with
+/// [`_tile_loadconfig`], the `__tile_*` intrinsics take the shape of every tile
+/// from its `__tile1024i` and configure the tile registers themselves. They
+/// load the tile configuration on entry and release it again before returning,
+/// so they must not be interleaved with the `_tile_*` intrinsics.
+///
+/// `row` is the number of rows (at most 16) and `col` the number of bytes per
+/// row (at most 64). The data is kept in memory with a stride of 64 bytes.
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[allow(non_camel_case_types)]
+#[unstable(feature = "x86_amx_intrinsics", issue = "126622")]
+pub struct __tile1024i {
+    /// The number of rows of the tile.
+    #[unstable(feature = "x86_amx_intrinsics", issue = "126622")]
+    pub row: u16,
+    /// The number of bytes in each row of the tile.
+    #[unstable(feature = "x86_amx_intrinsics", issue = "126622")]
+    pub col: u16,
+    tile: TileData,
+}
+
+#[repr(C, align(64))]
+#[derive(Copy, Clone, Debug)]
+struct TileData([u8; 1024]);
+
+impl __tile1024i {
+    /// Creates a zeroed tile with `row` rows of `col` bytes.
+    #[inline]
+    #[unstable(feature = "x86_amx_intrinsics", issue = "126622")]
+    pub const fn new(row: u16, col: u16) -> Self {
+        Self {
+            row,
+            col,
+            tile: TileData([0; 1024]),
+        }
+    }
+}
+
+/// Palette 1 tile configuration assigning the shapes of up to three tiles to
+/// `tmm0`, `tmm1` and `tmm2`.
+#[repr(C, align(64))]
+struct TileConfig([u8; 64]);
+
+impl TileConfig {
+    #[inline]
+    fn new(tiles: &[&__tile1024i]) -> Self {
+        let mut cfg = [0; 64];
+        cfg[0] = 1;
+        for (i, t) in tiles.iter().enumerate() {
+            let [lo, hi] = t.col.to_le_bytes();
+            cfg[16 + 2 * i] = lo;
+            cfg[17 + 2 * i] = hi;
+            cfg[48 + i] = t.row as u8;
+        }
+        Self(cfg)
+    }
+}
+
+/// Configures `tmm0`, `tmm1` and `tmm2` with the shapes of `dst`, `src0` and
+/// `src1` and loads their data.
+#[inline]
+#[target_feature(enable = "amx-tile")]
+unsafe fn tile_load3(dst: &__tile1024i, src0: &__tile1024i, src1: &__tile1024i) {
+    let cfg = TileConfig::new(&[dst, src0, src1]);
+    ldtilecfg(cfg.0.as_ptr());
+    tileloadd64(0, dst.tile.0.as_ptr(), 64);
+    tileloadd64(1, src0.tile.0.as_ptr(), 64);
+    tileloadd64(2, src1.tile.0.as_ptr(), 64);
+}
+
+/// Stores `tmm0` into `dst` and releases the tile configuration.
+#[inline]
+#[target_feature(enable = "amx-tile")]
+unsafe fn tile_store_release(dst: *mut __tile1024i) {
+    tilestored64(0, (*dst).tile.0.as_mut_ptr(), 64);
+    tilerelease();
+}
+
+/// Load tile rows from memory specified by base address and stride into destination tile dst.
+/// The shape of the tile is taken from dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=__tile_loadd)
+#[inline]
+#[target_feature(enable = "amx-tile")]
+#[cfg_attr(test, assert_instr(tileloadd))]
+#[unstable(feature = "x86_amx_intrinsics", issue = "126622")]
+pub unsafe fn __tile_loadd(dst: *mut __tile1024i, base: *const u8, stride: usize) {
+    let cfg = TileConfig::new(&[&*dst]);
+    ldtilecfg(cfg.0.as_ptr());
+    tileloadd64(0, base, stride);
+    tile_store_release(dst);
+}
+
+/// Load tile rows from memory specified by base address and stride into destination tile dst.
+/// The shape of the tile is taken from dst. This intrinsic provides a hint to the implementation
+/// that the data will likely not be reused in the near future and the data caching can be optimized accordingly.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=__tile_stream_loadd)
+#[inline]
+#[target_feature(enable = "amx-tile")]
+#[cfg_attr(test, assert_instr(tileloaddt1))]
+#[unstable(feature = "x86_amx_intrinsics", issue = "126622")]
+pub unsafe fn __tile_stream_loadd(dst: *mut __tile1024i, base: *const u8, stride: usize) {
+    let cfg = TileConfig::new(&[&*dst]);
+    ldtilecfg(cfg.0.as_ptr());
+    tileloaddt164(0, base, stride);
+    tile_store_release(dst);
+}
+
+/// Store the tile specified by src to memory specified by base address and stride.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=__tile_stored)
+#[inline]
+#[target_feature(enable = "amx-tile")]
+#[cfg_attr(test, assert_instr(tilestored))]
+#[unstable(feature = "x86_amx_intrinsics", issue = "126622")]
+pub unsafe fn __tile_stored(base: *mut u8, stride: usize, src: __tile1024i) {
+    let cfg = TileConfig::new(&[&src]);
+    ldtilecfg(cfg.0.as_ptr());
+    tileloadd64(0, src.tile.0.as_ptr(), 64);
+    tilestored64(0, base, stride);
+    tilerelease();
+}
+
+/// Zero the tile specified by dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=__tile_zero)
+#[inline]
+#[target_feature(enable = "amx-tile")]
+#[cfg_attr(test, assert_instr(tilezero))]
+#[unstable(feature = "x86_amx_intrinsics", issue = "126622")]
+pub unsafe fn __tile_zero(dst: *mut __tile1024i) {
+    let cfg = TileConfig::new(&[&*dst]);
+    ldtilecfg(cfg.0.as_ptr());
+    tilezero(0);
+    tile_store_release(dst);
+}
+
+/// Compute dot-product of BF16 (16-bit) floating-point pairs in tiles src0 and src1,
+/// accumulating the intermediate single-precision (32-bit) floating-point elements
+/// with elements in dst, and store the 32-bit result back to tile dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=__tile_dpbf16ps)
+#[inline]
+#[target_feature(enable = "amx-tile,amx-bf16")]
+#[cfg_attr(test, assert_instr(tdpbf16ps))]
+#[unstable(feature = "x86_amx_intrinsics", issue = "126622")]
+pub unsafe fn __tile_dpbf16ps(dst: *mut __tile1024i, src0: __tile1024i, src1: __tile1024i) {
+    tile_load3(&*dst, &src0, &src1);
+    tdpbf16ps(0, 1, 2);
+    tile_store_release(dst);
+}
+
+/// Compute dot-product of bytes in tiles with a source/destination accumulator.
+/// Multiply groups of 4 adjacent pairs of signed 8-bit integers in src0 with corresponding
+/// signed 8-bit integers in src1, producing 4 intermediate 32-bit results.
+/// Sum these 4 results with the corresponding 32-bit integer in dst, and store the 32-bit result back to tile dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=__tile_dpbssd)
+#[inline]
+#[target_feature(enable = "amx-tile,amx-int8")]
+#[cfg_attr(test, assert_instr(tdpbssd))]
+#[unstable(feature = "x86_amx_intrinsics", issue = "126622")]
+pub unsafe fn __tile_dpbssd(dst: *mut __tile1024i, src0: __tile1024i, src1: __tile1024i) {
+    tile_load3(&*dst, &src0, &src1);
+    tdpbssd(0, 1, 2);
+    tile_store_release(dst);
+}
+
+/// Compute dot-product of bytes in tiles with a source/destination accumulator.
+/// Multiply groups of 4 adjacent pairs of signed 8-bit integers in src0 with corresponding
+/// unsigned 8-bit integers in src1, producing 4 intermediate 32-bit results.
+/// Sum these 4 results with the corresponding 32-bit integer in dst, and store the 32-bit result back to tile dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=__tile_dpbsud)
+#[inline]
+#[target_feature(enable = "amx-tile,amx-int8")]
+#[cfg_attr(test, assert_instr(tdpbsud))]
+#[unstable(feature = "x86_amx_intrinsics", issue = "126622")]
+pub unsafe fn __tile_dpbsud(dst: *mut __tile1024i, src0: __tile1024i, src1: __tile1024i) {
+    tile_load3(&*dst, &src0, &src1);
+    tdpbsud(0, 1, 2);
+    tile_store_release(dst);
+}
+
+/// Compute dot-product of bytes in tiles with a source/destination accumulator.
+/// Multiply groups of 4 adjacent pairs of unsigned 8-bit integers in src0 with corresponding
+/// signed 8-bit integers in src1, producing 4 intermediate 32-bit results.
+/// Sum these 4 results with the corresponding 32-bit integer in dst, and store the 32-bit result back to tile dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=__tile_dpbusd)
+#[inline]
+#[target_feature(enable = "amx-tile,amx-int8")]
+#[cfg_attr(test, assert_instr(tdpbusd))]
+#[unstable(feature = "x86_amx_intrinsics", issue = "126622")]
+pub unsafe fn __tile_dpbusd(dst: *mut __tile1024i, src0: __tile1024i, src1: __tile1024i) {
+    tile_load3(&*dst, &src0, &src1);
+    tdpbusd(0, 1, 2);
+    tile_store_release(dst);
+}
+
+/// Compute dot-product of bytes in tiles with a source/destination accumulator.
+/// Multiply groups of 4 adjacent pairs of unsigned 8-bit integers in src0 with corresponding
+/// unsigned 8-bit integers in src1, producing 4 intermediate 32-bit results.
+/// Sum these 4 results with the corresponding 32-bit integer in dst, and store the 32-bit result back to tile dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=__tile_dpbuud)
+#[inline]
+#[target_feature(enable = "amx-tile,amx-int8")]
+#[cfg_attr(test, assert_instr(tdpbuud))]
+#[unstable(feature = "x86_amx_intrinsics", issue = "126622")]
+pub unsafe fn __tile_dpbuud(dst: *mut __tile1024i, src0: __tile1024i, src1: __tile1024i) {
+    tile_load3(&*dst, &src0, &src1);
+    tdpbuud(0, 1, 2);
+    tile_store_release(dst);
+}
+
+/// Compute dot-product of FP16 (16-bit) floating-point pairs in tiles src0 and src1,
+/// accumulating the intermediate single-precision (32-bit) floating-point elements
+/// with elements in dst, and store the 32-bit result back to tile dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=__tile_dpfp16ps)
+#[inline]
+#[target_feature(enable = "amx-tile,amx-fp16")]
+#[cfg_attr(test, assert_instr(tdpfp16ps))]
+#[unstable(feature = "x86_amx_intrinsics", issue = "126622")]
+pub unsafe fn __tile_dpfp16ps(dst: *mut __tile1024i, src0: __tile1024i, src1: __tile1024i) {
+    tile_load3(&*dst, &src0, &src1);
+    tdpfp16ps(0, 1, 2);
+    tile_store_release(dst);
+}
+
+/// Perform matrix multiplication of two tiles containing complex elements and accumulate the results into a packed single precision tile.
+/// Each dword element in input tiles src0 and src1 is interpreted as a complex number with FP16 real part and FP16 imaginary part.
+/// Calculates the imaginary part of the result, see [`_tile_cmmimfp16ps`] for the details.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=__tile_cmmimfp16ps)
+#[inline]
+#[target_feature(enable = "amx-tile,amx-complex")]
+#[cfg_attr(test, assert_instr(tcmmimfp16ps))]
+#[unstable(feature = "x86_amx_intrinsics", issue = "126622")]
+pub unsafe fn __tile_cmmimfp16ps(dst: *mut __tile1024i, src0: __tile1024i, src1: __tile1024i) {
+    tile_load3(&*dst, &src0, &src1);
+    tcmmimfp16ps(0, 1, 2);
+    tile_store_release(dst);
+}
+
+/// Perform matrix multiplication of two tiles containing complex elements and accumulate the results into a packed single precision tile.
+/// Each dword element in input tiles src0 and src1 is interpreted as a complex number with FP16 real part and FP16 imaginary part.
+/// Calculates the real part of the result, see [`_tile_cmmrlfp16ps`] for the details.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=__tile_cmmrlfp16ps)
+#[inline]
+#[target_feature(enable = "amx-tile,amx-complex")]
+#[cfg_attr(test, assert_instr(tcmmrlfp16ps))]
+#[unstable(feature = "x86_amx_intrinsics", issue = "126622")]
+pub unsafe fn __tile_cmmrlfp16ps(dst: *mut __tile1024i, src0: __tile1024i, src1: __tile1024i) {
+    tile_load3(&*dst, &src0, &src1);
+    tcmmrlfp16ps(0, 1, 2);
+    tile_store_release(dst);
+}
+
+/// Requests permission from the Linux kernel to use the AMX tile data.
+///
+/// Since Linux 5.16, a process has to request the `XTILEDATA` state component
+/// with `arch_prctl(ARCH_REQ_XCOMP_PERM, XFEATURE_XTILEDATA)` before it can use
+/// the tile registers; otherwise the AMX instructions raise `SIGILL`. The
+/// permission applies to the whole process and only needs to be requested once.
+///
+/// Returns `true` if the permission was granted, or had already been granted,
+/// and `false` if the processor or the kernel does not support AMX.
+#[cfg(target_os = "linux")]
+#[inline]
+#[unstable(feature = "x86_amx_intrinsics", issue = "126622")]
+pub fn _tile_request_permission() -> bool {
+    const SYS_ARCH_PRCTL: usize = 158;
+    const ARCH_REQ_XCOMP_PERM: usize = 0x1023;
+    const XFEATURE_XTILEDATA: usize = 18;
+    let ret: isize;
+    unsafe {
+        asm!(
+            "syscall",
+            inlateout("rax") SYS_ARCH_PRCTL => ret,
+            in("rdi") ARCH_REQ_XCOMP_PERM,
+            in("rsi") XFEATURE_XTILEDATA,
+            lateout("rcx") _,
+            lateout("r11") _,
+            options(nostack, preserves_flags)
+        );
+    }
+    ret == 0
+}
+
 #[allow(improper_ctypes)]
 extern "C" {
     #[link_name = "llvm.x86.ldtilecfg"]
@@ -264,8 +581,6 @@ mod tests {
     use crate::core_arch::x86_64::*;
     use core::mem::transmute;
     use stdarch_test::simd_test;
-    #[cfg(target_os = "linux")]
-    use syscalls::{syscall, Sysno};
 
     #[allow(non_camel_case_types)]
     #[repr(packed)]
@@ -309,33 +624,11 @@ mod tests {
     }
 
     #[cfg(not(target_os = "linux"))]
-    #[target_feature(enable = "amx-tile")]
     fn _init_amx() {}
 
     #[cfg(target_os = "linux")]
-    #[target_feature(enable = "amx-tile")]
-    #[inline]
-    unsafe fn _init_amx() {
-        let mut ret: usize;
-        let mut xfeatures: usize = 0;
-        ret = syscall!(Sysno::arch_prctl, 0x1022, &mut xfeatures as *mut usize)
-            .expect("arch_prctl ARCH_GET_XCOMP_PERM syscall failed");
-        if ret != 0 {
-            panic!("Failed to get XFEATURES");
-        } else {
-            match 0b11 & (xfeatures >> 17) {
-                0 => panic!("AMX is not available"),
-                1 => {
-                    ret = syscall!(Sysno::arch_prctl, 0x1023, 18)
-                        .expect("arch_prctl ARCH_REQ_XCOMP_PERM syscall failed");
-                    if ret != 0 {
-                        panic!("Failed to enable AMX");
-                    }
-                }
-                3 => {}
-                _ => unreachable!(),
-            }
-        }
+    fn _init_amx() {
+        assert!(_tile_request_permission(), "Failed to enable AMX");
     }
 
     #[simd_test(enable = "amx-tile")]
@@ -601,4 +894,200 @@ mod tests {
         _tile_release();
         assert_eq!(res, [[0f32; 16]; 16]);
     }
+
+    #[simd_test(enable = "amx-tile")]
+    unsafe fn test_tile1024i_zero() {
+        _init_amx();
+        let mut dst = __tile1024i::new(16, 64);
+        __tile_loadd(&mut dst, [1_i8; 1024].as_ptr() as *const u8, 64);
+        __tile_zero(&mut dst);
+        let mut out = [[1_i8; 64]; 16];
+        __tile_stored(&mut out as *mut [i8; 64] as *mut u8, 64, dst);
+        assert_eq!(out, [[0; 64]; 16]);
+    }
+
+    #[simd_test(enable = "amx-tile")]
+    unsafe fn test_tile1024i_loadd() {
+        _init_amx();
+        let mat: [i8; 1024] = core::array::from_fn(|i| i as i8);
+        let mut dst = __tile1024i::new(16, 64);
+        __tile_loadd(&mut dst, &mat as *const i8 as *const u8, 64);
+        let mut out = [0_i8; 1024];
+        __tile_stored(&mut out as *mut i8 as *mut u8, 64, dst);
+        assert_eq!(out, mat);
+    }
+
+    #[simd_test(enable = "amx-tile")]
+    unsafe fn test_tile1024i_stream_loadd() {
+        _init_amx();
+        let mat: [i8; 1024] = core::array::from_fn(|i| i as i8);
+        let mut dst = __tile1024i::new(16, 64);
+        __tile_stream_loadd(&mut dst, &mat as *const i8 as *const u8, 64);
+        let mut out = [0_i8; 1024];
+        __tile_stored(&mut out as *mut i8 as *mut u8, 64, dst);
+        assert_eq!(out, mat);
+    }
+
+    #[simd_test(enable = "amx-tile")]
+    unsafe fn test_tile1024i_stored() {
+        _init_amx();
+        // An 8x32 tile stored with a stride of 64 leaves every other half row untouched.
+        let mat = [1_i8; 256];
+        let mut src = __tile1024i::new(8, 32);
+        __tile_loadd(&mut src, &mat as *const i8 as *const u8, 32);
+        let mut out = [[-1_i8; 64]; 8];
+        __tile_stored(&mut out as *mut [i8; 64] as *mut u8, 64, src);
+        for row in out {
+            assert_eq!(row[..32], [1; 32]);
+            assert_eq!(row[32..], [-1; 32]);
+        }
+    }
+
+    #[simd_test(enable = "amx-bf16,avx512f")]
+    unsafe fn test_tile1024i_dpbf16ps() {
+        _init_amx();
+        let bf16_1: u16 = _mm_cvtness_sbh(1.0).to_bits();
+        let bf16_2: u16 = _mm_cvtness_sbh(2.0).to_bits();
+        let ones: [u8; 1024] = transmute([bf16_1; 512]);
+        let twos: [u8; 1024] = transmute([bf16_2; 512]);
+        let a = ones;
+        let b = twos;
+        let mut dst = __tile1024i::new(16, 64);
+        let mut src0 = __tile1024i::new(16, 64);
+        let mut src1 = __tile1024i::new(16, 64);
+        __tile_zero(&mut dst);
+        __tile_loadd(&mut src0, &a as *const u8 as *const u8, 64);
+        __tile_loadd(&mut src1, &b as *const u8 as *const u8, 64);
+        __tile_dpbf16ps(&mut dst, src0, src1);
+        let mut res = [[0 as f32; 16]; 16];
+        __tile_stored(&mut res as *mut [f32; 16] as *mut u8, 64, dst);
+        assert_eq!(res, [[64f32; 16]; 16]);
+    }
+
+    #[simd_test(enable = "amx-int8")]
+    unsafe fn test_tile1024i_dpbssd() {
+        _init_amx();
+        let a = [-1_i8; 1024];
+        let b = [-2_i8; 1024];
+        let mut dst = __tile1024i::new(16, 64);
+        let mut src0 = __tile1024i::new(16, 64);
+        let mut src1 = __tile1024i::new(16, 64);
+        __tile_zero(&mut dst);
+        __tile_loadd(&mut src0, &a as *const i8 as *const u8, 64);
+        __tile_loadd(&mut src1, &b as *const i8 as *const u8, 64);
+        __tile_dpbssd(&mut dst, src0, src1);
+        let mut res = [[0 as i32; 16]; 16];
+        __tile_stored(&mut res as *mut [i32; 16] as *mut u8, 64, dst);
+        assert_eq!(res, [[128_i32; 16]; 16]);
+    }
+
+    #[simd_test(enable = "amx-int8")]
+    unsafe fn test_tile1024i_dpbsud() {
+        _init_amx();
+        let a = [-1_i8; 1024];
+        let b = [2_u8; 1024];
+        let mut dst = __tile1024i::new(16, 64);
+        let mut src0 = __tile1024i::new(16, 64);
+        let mut src1 = __tile1024i::new(16, 64);
+        __tile_zero(&mut dst);
+        __tile_loadd(&mut src0, &a as *const i8 as *const u8, 64);
+        __tile_loadd(&mut src1, &b as *const u8 as *const u8, 64);
+        __tile_dpbsud(&mut dst, src0, src1);
+        let mut res = [[0 as i32; 16]; 16];
+        __tile_stored(&mut res as *mut [i32; 16] as *mut u8, 64, dst);
+        assert_eq!(res, [[-128_i32; 16]; 16]);
+    }
+
+    #[simd_test(enable = "amx-int8")]
+    unsafe fn test_tile1024i_dpbusd() {
+        _init_amx();
+        let a = [1_u8; 1024];
+        let b = [-2_i8; 1024];
+        let mut dst = __tile1024i::new(16, 64);
+        let mut src0 = __tile1024i::new(16, 64);
+        let mut src1 = __tile1024i::new(16, 64);
+        __tile_zero(&mut dst);
+        __tile_loadd(&mut src0, &a as *const u8 as *const u8, 64);
+        __tile_loadd(&mut src1, &b as *const i8 as *const u8, 64);
+        __tile_dpbusd(&mut dst, src0, src1);
+        let mut res = [[0 as i32; 16]; 16];
+        __tile_stored(&mut res as *mut [i32; 16] as *mut u8, 64, dst);
+        assert_eq!(res, [[-128_i32; 16]; 16]);
+    }
+
+    #[simd_test(enable = "amx-int8")]
+    unsafe fn test_tile1024i_dpbuud() {
+        _init_amx();
+        let a = [1_u8; 1024];
+        let b = [2_u8; 1024];
+        let mut dst = __tile1024i::new(16, 64);
+        let mut src0 = __tile1024i::new(16, 64);
+        let mut src1 = __tile1024i::new(16, 64);
+        __tile_zero(&mut dst);
+        __tile_loadd(&mut src0, &a as *const u8 as *const u8, 64);
+        __tile_loadd(&mut src1, &b as *const u8 as *const u8, 64);
+        __tile_dpbuud(&mut dst, src0, src1);
+        let mut res = [[0 as i32; 16]; 16];
+        __tile_stored(&mut res as *mut [i32; 16] as *mut u8, 64, dst);
+        assert_eq!(res, [[128_i32; 16]; 16]);
+    }
+
+    #[simd_test(enable = "amx-fp16")]
+    unsafe fn test_tile1024i_dpfp16ps() {
+        _init_amx();
+        let a = [1f16; 512];
+        let b = [2f16; 512];
+        let mut dst = __tile1024i::new(16, 64);
+        let mut src0 = __tile1024i::new(16, 64);
+        let mut src1 = __tile1024i::new(16, 64);
+        __tile_zero(&mut dst);
+        __tile_loadd(&mut src0, &a as *const f16 as *const u8, 64);
+        __tile_loadd(&mut src1, &b as *const f16 as *const u8, 64);
+        __tile_dpfp16ps(&mut dst, src0, src1);
+        let mut res = [[0 as f32; 16]; 16];
+        __tile_stored(&mut res as *mut [f32; 16] as *mut u8, 64, dst);
+        assert_eq!(res, [[64f32; 16]; 16]);
+    }
+
+    #[simd_test(enable = "amx-complex")]
+    unsafe fn test_tile1024i_cmmimfp16ps() {
+        _init_amx();
+        let a = [1f16; 512];
+        let b = [2f16; 512];
+        let mut dst = __tile1024i::new(16, 64);
+        let mut src0 = __tile1024i::new(16, 64);
+        let mut src1 = __tile1024i::new(16, 64);
+        __tile_zero(&mut dst);
+        __tile_loadd(&mut src0, &a as *const f16 as *const u8, 64);
+        __tile_loadd(&mut src1, &b as *const f16 as *const u8, 64);
+        __tile_cmmimfp16ps(&mut dst, src0, src1);
+        let mut res = [[0 as f32; 16]; 16];
+        __tile_stored(&mut res as *mut [f32; 16] as *mut u8, 64, dst);
+        assert_eq!(res, [[64f32; 16]; 16]);
+    }
+
+    #[simd_test(enable = "amx-complex")]
+    unsafe fn test_tile1024i_cmmrlfp16ps() {
+        _init_amx();
+        let a = [1f16; 512];
+        let b = [2f16; 512];
+        let mut dst = __tile1024i::new(16, 64);
+        let mut src0 = __tile1024i::new(16, 64);
+        let mut src1 = __tile1024i::new(16, 64);
+        __tile_zero(&mut dst);
+        __tile_loadd(&mut src0, &a as *const f16 as *const u8, 64);
+        __tile_loadd(&mut src1, &b as *const f16 as *const u8, 64);
+        __tile_cmmrlfp16ps(&mut dst, src0, src1);
+        let mut res = [[0 as f32; 16]; 16];
+        __tile_stored(&mut res as *mut [f32; 16] as *mut u8, 64, dst);
+        assert_eq!(res, [[0f32; 16]; 16]);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_tile_request_permission() {
+        if is_x86_feature_detected!("amx-tile") {
+            assert!(_tile_request_permission());
+        }
+    }
 }
@@ -0,0 +1,112 @@
+//! Control-flow Enforcement Technology (CET) shadow stack intrinsics.
+//!
+//! The intrinsics here correspond to those in the `cetintrin.h` C header.
+
+use crate::arch::asm;
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+// x32 wants to use a 32-bit address size, but asm! defaults to using the full
+// register name (e.g. rax). We have to explicitly override the placeholder to
+// use the 32-bit register name in that case.
+#[cfg(target_pointer_width = "32")]
+macro_rules! ss {
+    ($inst:expr) => {
+        concat!($inst, " [{p:e}], {val}")
+    };
+}
+#[cfg(target_pointer_width = "64")]
+macro_rules! ss {
+    ($inst:expr) => {
+        concat!($inst, " [{p}], {val}")
+    };
+}
+
+/// Increment the shadow stack pointer by 8 times the value specified in bits
+/// 7:0 of `a`.
+///
+/// This function is always inlined, so that it moves the shadow stack pointer
+/// of its caller rather than that of a frame which then has to return.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_incsspq)
+#[inline(always)]
+#[cfg_attr(test, assert_instr(incsspq))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _incsspq(a: u64) {
+    asm!("incsspq {}", in(reg) a, options(nostack, preserves_flags));
+}
+
+/// Read the current shadow stack pointer. If shadow stacks are not enabled,
+/// `a` is returned unchanged.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_rdsspq_i64)
+#[inline]
+#[cfg_attr(test, assert_instr(rdsspq))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _rdsspq_i64(a: u64) -> u64 {
+    let mut r = a;
+    asm!("rdsspq {}", inout(reg) r, options(nomem, nostack, preserves_flags));
+    r
+}
+
+/// Write the 64-bit value `val` to the shadow stack memory pointed to by `p`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_wrssq)
+#[inline]
+#[cfg_attr(test, assert_instr(wrssq))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _wrssq(val: i64, p: *mut u8) {
+    asm!(
+        ss!("wrssq"),
+        p = in(reg) p,
+        val = in(reg) val,
+        options(nostack, preserves_flags),
+    );
+}
+
+/// Write the 64-bit value `val` to the user shadow stack memory pointed to by
+/// `p`.
+///
+/// This instruction can only be executed at privilege level 0.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_wrussq)
+#[inline]
+#[cfg_attr(test, assert_instr(wrussq))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _wrussq(val: i64, p: *mut u8) {
+    asm!(
+        ss!("wrussq"),
+        p = in(reg) p,
+        val = in(reg) val,
+        options(nostack, preserves_flags),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use crate::core_arch::x86_64::*;
+
+    #[test]
+    fn test_rdsspq_i64() {
+        let ssp = unsafe { _rdsspq_i64(0) };
+        if is_x86_feature_detected!("shstk") {
+            assert_ne!(ssp, 0);
+            assert_eq!(ssp % 8, 0);
+        } else {
+            // `RDSSPQ` is a NOP without shadow stacks.
+            assert_eq!(ssp, 0);
+            assert_eq!(unsafe { _rdsspq_i64(42) }, 42);
+        }
+    }
+
+    #[test]
+    fn test_incsspq() {
+        // Popping entries off an active shadow stack would break our own
+        // returns, so only check that this is a NOP without shadow stacks.
+        if !is_x86_feature_detected!("shstk") {
+            unsafe { _incsspq(1) };
+            assert_eq!(unsafe { _get_ssp() }, 0);
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//! Compare and add if condition is met (CMPCCXADD).
+//!
+//! The intrinsics here correspond to those in the `cmpccxaddintrin.h` C
+//! header.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// The `_CMPCCX_ENUM` type used to specify the condition of the
+/// `_cmpccxadd_epi32` and `_cmpccxadd_epi64` intrinsics.
+#[allow(non_camel_case_types)]
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub type _CMPCCX_ENUM = i32;
+
+/// Overflow.
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub const _CMPCCX_O: _CMPCCX_ENUM = 0x00;
+/// No overflow.
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub const _CMPCCX_NO: _CMPCCX_ENUM = 0x01;
+/// Below (unsigned less than).
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub const _CMPCCX_B: _CMPCCX_ENUM = 0x02;
+/// Not below (unsigned greater than or equal).
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub const _CMPCCX_NB: _CMPCCX_ENUM = 0x03;
+/// Zero (equal).
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub const _CMPCCX_Z: _CMPCCX_ENUM = 0x04;
+/// Not zero (not equal).
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub const _CMPCCX_NZ: _CMPCCX_ENUM = 0x05;
+/// Below or equal (unsigned less than or equal).
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub const _CMPCCX_BE: _CMPCCX_ENUM = 0x06;
+/// Neither below nor equal (unsigned greater than).
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub const _CMPCCX_NBE: _CMPCCX_ENUM = 0x07;
+/// Sign (negative).
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub const _CMPCCX_S: _CMPCCX_ENUM = 0x08;
+/// No sign (non-negative).
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub const _CMPCCX_NS: _CMPCCX_ENUM = 0x09;
+/// Parity (even number of bits set in the low byte of the difference).
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub const _CMPCCX_P: _CMPCCX_ENUM = 0x0a;
+/// No parity (odd number of bits set in the low byte of the difference).
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub const _CMPCCX_NP: _CMPCCX_ENUM = 0x0b;
+/// Less (signed less than).
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub const _CMPCCX_L: _CMPCCX_ENUM = 0x0c;
+/// Not less (signed greater than or equal).
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub const _CMPCCX_NL: _CMPCCX_ENUM = 0x0d;
+/// Less or equal (signed less than or equal).
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub const _CMPCCX_LE: _CMPCCX_ENUM = 0x0e;
+/// Neither less nor equal (signed greater than).
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub const _CMPCCX_NLE: _CMPCCX_ENUM = 0x0f;
+
+// x32 wants to use a 32-bit address size, but asm! defaults to using the full
+// register name (e.g. rax). We have to explicitly override the placeholder to
+// use the 32-bit register name in that case.
+#[cfg(target_pointer_width = "32")]
+macro_rules! cmpccxadd_ptr {
+    ($size:literal) => {
+        concat!($size, " ptr [{p:e}]")
+    };
+}
+#[cfg(target_pointer_width = "64")]
+macro_rules! cmpccxadd_ptr {
+    ($size:literal) => {
+        concat!($size, " ptr [{p}]")
+    };
+}
+
+// The condition is part of the mnemonic, so expand to one `asm!` per
+// condition and select the right one based on `$cond`.
+macro_rules! cmpccxadd {
+    ($cond:ident, $size:literal, $reg:literal, $p:ident, $b:ident, $c:ident) => {
+        cmpccxadd!(
+            @match $cond, $size, $reg, $p, $b, $c;
+            _CMPCCX_O => "cmpoxadd",
+            _CMPCCX_NO => "cmpnoxadd",
+            _CMPCCX_B => "cmpbxadd",
+            _CMPCCX_NB => "cmpnbxadd",
+            _CMPCCX_Z => "cmpzxadd",
+            _CMPCCX_NZ => "cmpnzxadd",
+            _CMPCCX_BE => "cmpbexadd",
+            _CMPCCX_NBE => "cmpnbexadd",
+            _CMPCCX_S => "cmpsxadd",
+            _CMPCCX_NS => "cmpnsxadd",
+            _CMPCCX_P => "cmppxadd",
+            _CMPCCX_NP => "cmpnpxadd",
+            _CMPCCX_L => "cmplxadd",
+            _CMPCCX_NL => "cmpnlxadd",
+            _CMPCCX_LE => "cmplexadd",
+            _CMPCCX_NLE => "cmpnlexadd",
+        )
+    };
+    (@match $cond:ident, $size:literal, $reg:literal, $p:ident, $b:ident, $c:ident;
+     $($cc:ident => $inst:literal,)*) => {
+        match $cond {
+            $(
+                $cc => asm!(
+                    concat!($inst, " ", cmpccxadd_ptr!($size), ", {b", $reg, "}, {c", $reg, "}"),
+                    p = in(reg) $p,
+                    b = inout(reg) $b,
+                    c = in(reg) $c,
+                    options(nostack),
+                ),
+            )*
+            _ => unreachable!(),
+        }
+    };
+}
+
+/// Compares the 32-bit integer pointed to by `a` with `b`. If the condition
+/// `COND` is met, adds `c` to the integer pointed to by `a`. The whole
+/// operation is atomic.
+///
+/// Returns the original value of the integer pointed to by `a`.
+///
+/// The comparison sets the flags the same way as `CMP` with the integer pointed
+/// to by `a` as the first operand, and `COND` is one of the `_CMPCCX_*`
+/// conditions on those flags.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_cmpccxadd_epi32)
+#[inline]
+#[cfg_attr(test, assert_instr(cmpbexadd, COND = _CMPCCX_BE))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub unsafe fn _cmpccxadd_epi32<const COND: _CMPCCX_ENUM>(a: *mut i32, b: i32, c: i32) -> i32 {
+    static_assert_uimm_bits!(COND, 4);
+    let mut r = b;
+    cmpccxadd!(COND, "dword", ":e", a, r, c);
+    r
+}
+
+/// Compares the 64-bit integer pointed to by `a` with `b`. If the condition
+/// `COND` is met, adds `c` to the integer pointed to by `a`. The whole
+/// operation is atomic.
+///
+/// Returns the original value of the integer pointed to by `a`.
+///
+/// The comparison sets the flags the same way as `CMP` with the integer pointed
+/// to by `a` as the first operand, and `COND` is one of the `_CMPCCX_*`
+/// conditions on those flags.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_cmpccxadd_epi64)
+#[inline]
+#[cfg_attr(test, assert_instr(cmpbexadd, COND = _CMPCCX_BE))]
+#[rustc_legacy_const_generics(3)]
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub unsafe fn _cmpccxadd_epi64<const COND: _CMPCCX_ENUM>(a: *mut i64, b: i64, c: i64) -> i64 {
+    static_assert_uimm_bits!(COND, 4);
+    let mut r = b;
+    cmpccxadd!(COND, "qword", "", a, r, c);
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86_64::*;
+
+    #[test]
+    fn test_cmpccxadd_epi32() {
+        if !is_x86_feature_detected!("cmpccxadd") {
+            return;
+        }
+        let mut x = 5i32;
+        unsafe {
+            // 5 is not less than 3, so nothing is added.
+            assert_eq!(_cmpccxadd_epi32::<_CMPCCX_L>(&mut x, 3, 10), 5);
+            assert_eq!(x, 5);
+            // 5 is less than 7, so 10 is added.
+            assert_eq!(_cmpccxadd_epi32::<_CMPCCX_L>(&mut x, 7, 10), 5);
+            assert_eq!(x, 15);
+            // 15 is equal to 15, so -1 is added.
+            assert_eq!(_cmpccxadd_epi32::<_CMPCCX_Z>(&mut x, 15, -1), 15);
+            assert_eq!(x, 14);
+            // 14 is below -1 as an unsigned integer, so nothing is added.
+            assert_eq!(_cmpccxadd_epi32::<_CMPCCX_NBE>(&mut x, -1, 1), 14);
+            assert_eq!(x, 14);
+        }
+    }
+
+    #[test]
+    fn test_cmpccxadd_epi64() {
+        if !is_x86_feature_detected!("cmpccxadd") {
+            return;
+        }
+        let mut x = 1i64 << 40;
+        unsafe {
+            assert_eq!(_cmpccxadd_epi64::<_CMPCCX_NZ>(&mut x, 1 << 40, 1), 1 << 40);
+            assert_eq!(x, 1 << 40);
+            assert_eq!(_cmpccxadd_epi64::<_CMPCCX_NZ>(&mut x, 0, 1), 1 << 40);
+            assert_eq!(x, (1 << 40) + 1);
+            assert_eq!(
+                _cmpccxadd_epi64::<_CMPCCX_S>(&mut x, 1 << 41, -1),
+                (1 << 40) + 1
+            );
+            assert_eq!(x, 1 << 40);
+        }
+    }
+}
@@ -0,0 +1,231 @@
+//! FS and GS segment base instructions (FSGSBASE).
+//!
+//! The intrinsics here correspond to those in the `fsgsbaseintrin.h` C
+//! header.
+//!
+//! These instructions are only available in 64-bit mode, and only if the
+//! operating system has enabled them by setting `CR4.FSGSBASE`.
+//! `is_x86_feature_detected!("fsgsbase")` checks both, but it can only tell
+//! whether the operating system has enabled them on Linux, so it never
+//! detects them elsewhere. Also note that operating systems and threading
+//! libraries commonly use the FS and GS base addresses for thread-local
+//! storage, so writing them is rarely safe.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Reads the low 32 bits of the FS segment base address.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_readfsbase_u32)
+#[inline]
+#[cfg_attr(test, assert_instr(rdfsbase))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _readfsbase_u32() -> u32 {
+    let r: u32;
+    asm!("rdfsbase {:e}", out(reg) r, options(nomem, nostack, preserves_flags));
+    r
+}
+
+/// Reads the low 32 bits of the GS segment base address.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_readgsbase_u32)
+#[inline]
+#[cfg_attr(test, assert_instr(rdgsbase))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _readgsbase_u32() -> u32 {
+    let r: u32;
+    asm!("rdgsbase {:e}", out(reg) r, options(nomem, nostack, preserves_flags));
+    r
+}
+
+/// Reads the FS segment base address.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_readfsbase_u64)
+#[inline]
+#[cfg_attr(test, assert_instr(rdfsbase))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _readfsbase_u64() -> u64 {
+    let r: u64;
+    asm!("rdfsbase {}", out(reg) r, options(nomem, nostack, preserves_flags));
+    r
+}
+
+/// Reads the GS segment base address.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_readgsbase_u64)
+#[inline]
+#[cfg_attr(test, assert_instr(rdgsbase))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _readgsbase_u64() -> u64 {
+    let r: u64;
+    asm!("rdgsbase {}", out(reg) r, options(nomem, nostack, preserves_flags));
+    r
+}
+
+/// Sets the FS segment base address to the zero-extended value of `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_writefsbase_u32)
+#[inline]
+#[cfg_attr(test, assert_instr(wrfsbase))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _writefsbase_u32(a: u32) {
+    asm!("wrfsbase {:e}", in(reg) a, options(nostack, preserves_flags));
+}
+
+/// Sets the GS segment base address to the zero-extended value of `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_writegsbase_u32)
+#[inline]
+#[cfg_attr(test, assert_instr(wrgsbase))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _writegsbase_u32(a: u32) {
+    asm!("wrgsbase {:e}", in(reg) a, options(nostack, preserves_flags));
+}
+
+/// Sets the FS segment base address to `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_writefsbase_u64)
+#[inline]
+#[cfg_attr(test, assert_instr(wrfsbase))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _writefsbase_u64(a: u64) {
+    asm!("wrfsbase {}", in(reg) a, options(nostack, preserves_flags));
+}
+
+/// Sets the GS segment base address to `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_writegsbase_u64)
+#[inline]
+#[cfg_attr(test, assert_instr(wrgsbase))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _writegsbase_u64(a: u64) {
+    asm!("wrgsbase {}", in(reg) a, options(nostack, preserves_flags));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86_64::*;
+
+    // Linux uses the FS base address for thread-local storage, so the tests
+    // that write it restore it right away, before anything can access a
+    // thread-local variable. Linux leaves the GS base address alone in user
+    // space.
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_readfsbase_u32() {
+        if !is_x86_feature_detected!("fsgsbase") {
+            return;
+        }
+        unsafe {
+            assert_eq!(_readfsbase_u32(), _readfsbase_u64() as u32);
+        }
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_readgsbase_u32() {
+        if !is_x86_feature_detected!("fsgsbase") {
+            return;
+        }
+        unsafe {
+            assert_eq!(_readgsbase_u32(), _readgsbase_u64() as u32);
+        }
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_writefsbase_u32() {
+        if !is_x86_feature_detected!("fsgsbase") {
+            return;
+        }
+        unsafe {
+            let old = _readfsbase_u64();
+            _writefsbase_u32(0xdead_beef);
+            let new = _readfsbase_u64();
+            _writefsbase_u64(old);
+            assert_eq!(new, 0xdead_beef);
+        }
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_writegsbase_u32() {
+        if !is_x86_feature_detected!("fsgsbase") {
+            return;
+        }
+        unsafe {
+            let old = _readgsbase_u64();
+            _writegsbase_u32(0xdead_beef);
+            let new = _readgsbase_u64();
+            _writegsbase_u64(old);
+            assert_eq!(new, 0xdead_beef);
+        }
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_readfsbase_u64() {
+        if !is_x86_feature_detected!("fsgsbase") {
+            return;
+        }
+        // The x86-64 TLS ABI stores a pointer to the thread control block at
+        // its start, which is where the FS base points.
+        unsafe {
+            let base = _readfsbase_u64();
+            assert_eq!(*(base as *const u64), base);
+        }
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_readgsbase_u64() {
+        if !is_x86_feature_detected!("fsgsbase") {
+            return;
+        }
+        unsafe {
+            let old = _readgsbase_u64();
+            _writegsbase_u64(0x1234_5678_9abc);
+            let new = _readgsbase_u64();
+            _writegsbase_u64(old);
+            assert_eq!(new, 0x1234_5678_9abc);
+        }
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_writefsbase_u64() {
+        if !is_x86_feature_detected!("fsgsbase") {
+            return;
+        }
+        unsafe {
+            let old = _readfsbase_u64();
+            _writefsbase_u64(0x1234_5678_9abc);
+            let new = _readfsbase_u64();
+            _writefsbase_u64(old);
+            assert_eq!(new, 0x1234_5678_9abc);
+        }
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_writegsbase_u64() {
+        if !is_x86_feature_detected!("fsgsbase") {
+            return;
+        }
+        unsafe {
+            let old = _readgsbase_u64();
+            _writegsbase_u64(0x1234_5678_9abc);
+            let new = _readgsbase_u64();
+            _writegsbase_u64(old);
+            assert_eq!(new, 0x1234_5678_9abc);
+        }
+    }
+}
@@ -81,3 +81,35 @@ pub use self::avx512fp16::*;
 mod amx;
 #[unstable(feature = "x86_amx_intrinsics", issue = "126622")]
 pub use self::amx::*;
+
+mod cet;
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub use self::cet::*;
+
+mod movdiri;
+#[unstable(feature = "stdarch_x86_memory", issue = "44839")]
+pub use self::movdiri::*;
+
+mod prefetchi;
+#[unstable(feature = "stdarch_x86_memory", issue = "44839")]
+pub use self::prefetchi::*;
+
+mod cmpccxadd;
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub use self::cmpccxadd::*;
+
+mod raoint;
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub use self::raoint::*;
+
+mod fsgsbase;
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub use self::fsgsbase::*;
+
+mod uintr;
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub use self::uintr::*;
+
+mod usermsr;
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub use self::usermsr::*;
@@ -0,0 +1,53 @@
+//! Direct store instructions (MOVDIRI).
+//!
+//! The intrinsics here correspond to those in the `movdirintrin.h` C header.
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Store the 64-bit integer `val` to the memory pointed to by `dst` using a
+/// direct store. The store is atomic if `dst` is 8-byte aligned.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_directstoreu_u64)
+#[inline]
+#[cfg_attr(test, assert_instr(movdiri))]
+#[unstable(feature = "stdarch_x86_memory", issue = "44839")]
+pub unsafe fn _directstoreu_u64(dst: *mut u64, val: u64) {
+    // x32 wants to use a 32-bit address size, but asm! defaults to using the
+    // full register name (e.g. rax).
+    #[cfg(target_pointer_width = "32")]
+    asm!(
+        "movdiri [{dst:e}], {val}",
+        dst = in(reg) dst,
+        val = in(reg) val,
+        options(nostack, preserves_flags),
+    );
+    #[cfg(target_pointer_width = "64")]
+    asm!(
+        "movdiri [{dst}], {val}",
+        dst = in(reg) dst,
+        val = in(reg) val,
+        options(nostack, preserves_flags),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use crate::core_arch::x86_64::*;
+
+    #[test]
+    fn test_directstoreu_u64() {
+        if !is_x86_feature_detected!("movdiri") {
+            return;
+        }
+        let mut x = 0u64;
+        unsafe {
+            _directstoreu_u64(&mut x, 0x0123_4567_89ab_cdef);
+            _mm_sfence();
+        }
+        assert_eq!(x, 0x0123_4567_89ab_cdef);
+    }
+}
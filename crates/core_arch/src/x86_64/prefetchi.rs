@@ -0,0 +1,71 @@
+//! Code prefetch (PREFETCHI).
+//!
+//! The intrinsics here correspond to those in the `prfchiintrin.h` C header.
+//!
+//! `PREFETCHIT0` and `PREFETCHIT1` are encoded in the NOP space. The processor
+//! only prefetches code when the memory operand uses RIP-relative addressing,
+//! and treats any other addressing form as a NOP. These intrinsics take the
+//! address at run-time, so they always emit the register-indirect form, as
+//! Clang does for a pointer it cannot fold into a RIP-relative operand. They
+//! are only hints and never fault.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Hints to the processor that the code at `p` is likely to be executed soon,
+/// and should be fetched into all levels of the cache hierarchy.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_m_prefetchit0)
+#[inline]
+// objdump only decodes the RIP-relative form as `prefetchit0`, and decodes
+// the register form as the NOP it is architecturally.
+#[cfg_attr(test, assert_instr(nopl))]
+#[unstable(feature = "stdarch_x86_memory", issue = "44839")]
+pub unsafe fn _m_prefetchit0(p: *const u8) {
+    // LLVM's assembler warns about `prefetchit0` with a register operand, and
+    // rustc has no `prefetchi` target feature to lower `llvm.prefetch` with, so
+    // encode `prefetchit0 [rax]` (`0F 18 /7`) by hand. x32 needs the 32-bit
+    // address size prefix.
+    #[cfg(target_pointer_width = "32")]
+    asm!(".byte 0x67, 0x0f, 0x18, 0x38", in("eax") p, options(nostack, preserves_flags));
+    #[cfg(target_pointer_width = "64")]
+    asm!(".byte 0x0f, 0x18, 0x38", in("rax") p, options(nostack, preserves_flags));
+}
+
+/// Hints to the processor that the code at `p` is likely to be executed soon,
+/// and should be fetched into the second level cache and above.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_m_prefetchit1)
+#[inline]
+#[cfg_attr(test, assert_instr(nopl))]
+#[unstable(feature = "stdarch_x86_memory", issue = "44839")]
+pub unsafe fn _m_prefetchit1(p: *const u8) {
+    // `prefetchit1 [rax]` (`0F 18 /6`), see `_m_prefetchit0`.
+    #[cfg(target_pointer_width = "32")]
+    asm!(".byte 0x67, 0x0f, 0x18, 0x30", in("eax") p, options(nostack, preserves_flags));
+    #[cfg(target_pointer_width = "64")]
+    asm!(".byte 0x0f, 0x18, 0x30", in("rax") p, options(nostack, preserves_flags));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86_64::*;
+
+    #[test]
+    fn test_m_prefetchit0() {
+        // These are only hints, so there is no need to check for the feature.
+        unsafe { _m_prefetchit0(test_m_prefetchit0 as *const u8) };
+    }
+
+    #[test]
+    fn test_m_prefetchit1() {
+        unsafe { _m_prefetchit1(test_m_prefetchit1 as *const u8) };
+    }
+}
@@ -0,0 +1,117 @@
+//! Remote atomic operations (RAO-INT).
+//!
+//! The intrinsics here correspond to those in the `raointintrin.h` C header.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+// x32 wants to use a 32-bit address size, but asm! defaults to using the full
+// register name (e.g. rax). We have to explicitly override the placeholder to
+// use the 32-bit register name in that case.
+#[cfg(target_pointer_width = "32")]
+macro_rules! rao {
+    ($inst:expr) => {
+        concat!($inst, " qword ptr [{p:e}], {v}")
+    };
+}
+#[cfg(target_pointer_width = "64")]
+macro_rules! rao {
+    ($inst:expr) => {
+        concat!($inst, " qword ptr [{p}], {v}")
+    };
+}
+
+/// Atomically adds `b` to the 64-bit integer pointed to by `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_aadd_i64)
+#[inline]
+#[cfg_attr(test, assert_instr(aadd))]
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub unsafe fn _aadd_i64(a: *mut i64, b: i64) {
+    asm!(rao!("aadd"), p = in(reg) a, v = in(reg) b, options(nostack, preserves_flags));
+}
+
+/// Atomically computes the bitwise AND of the 64-bit integer pointed to by `a`
+/// and `b`, and stores the result in the integer pointed to by `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_aand_i64)
+#[inline]
+#[cfg_attr(test, assert_instr(aand))]
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub unsafe fn _aand_i64(a: *mut i64, b: i64) {
+    asm!(rao!("aand"), p = in(reg) a, v = in(reg) b, options(nostack, preserves_flags));
+}
+
+/// Atomically computes the bitwise OR of the 64-bit integer pointed to by `a`
+/// and `b`, and stores the result in the integer pointed to by `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_aor_i64)
+#[inline]
+#[cfg_attr(test, assert_instr(aor))]
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub unsafe fn _aor_i64(a: *mut i64, b: i64) {
+    asm!(rao!("aor"), p = in(reg) a, v = in(reg) b, options(nostack, preserves_flags));
+}
+
+/// Atomically computes the bitwise XOR of the 64-bit integer pointed to by `a`
+/// and `b`, and stores the result in the integer pointed to by `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_axor_i64)
+#[inline]
+#[cfg_attr(test, assert_instr(axor))]
+#[unstable(feature = "stdarch_x86_atomics", issue = "44839")]
+pub unsafe fn _axor_i64(a: *mut i64, b: i64) {
+    asm!(rao!("axor"), p = in(reg) a, v = in(reg) b, options(nostack, preserves_flags));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86_64::*;
+
+    #[test]
+    fn test_aadd_i64() {
+        if !is_x86_feature_detected!("raoint") {
+            return;
+        }
+        let mut x = 1i64 << 40;
+        unsafe { _aadd_i64(&mut x, 41) };
+        assert_eq!(x, (1 << 40) + 41);
+    }
+
+    #[test]
+    fn test_aand_i64() {
+        if !is_x86_feature_detected!("raoint") {
+            return;
+        }
+        let mut x = 0b1100i64;
+        unsafe { _aand_i64(&mut x, 0b1010) };
+        assert_eq!(x, 0b1000);
+    }
+
+    #[test]
+    fn test_aor_i64() {
+        if !is_x86_feature_detected!("raoint") {
+            return;
+        }
+        let mut x = 0b1100i64;
+        unsafe { _aor_i64(&mut x, 0b1010) };
+        assert_eq!(x, 0b1110);
+    }
+
+    #[test]
+    fn test_axor_i64() {
+        if !is_x86_feature_detected!("raoint") {
+            return;
+        }
+        let mut x = 0b1100i64;
+        unsafe { _axor_i64(&mut x, 0b1010) };
+        assert_eq!(x, 0b0110);
+    }
+}
@@ -0,0 +1,126 @@
+//! User interrupts (UINTR).
+//!
+//! The intrinsics here correspond to those in the `uintrintrin.h` C header.
+//!
+//! User interrupts are delivered directly to a thread running in user mode,
+//! without going through the kernel. The receiving thread controls whether it
+//! can be interrupted with the user interrupt flag (UIF), and other threads
+//! send interrupts to it through the user-interrupt target table (UITT) set
+//! up by the operating system.
+//!
+//! These instructions raise `#UD` unless the operating system has enabled
+//! user interrupts by setting `CR4.UINTR`. `is_x86_feature_detected!("uintr")`
+//! checks this on Linux, and is always false elsewhere.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 3: System Programming Guide][intel64_ref], Chapter 8.
+//!
+//! [intel64_ref]: https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Clears the user interrupt flag (UIF), so that user interrupts are not
+/// delivered to the current thread.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_clui)
+#[inline]
+#[cfg_attr(test, assert_instr(clui))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _clui() {
+    asm!("clui", options(nostack, preserves_flags));
+}
+
+/// Sets the user interrupt flag (UIF), so that user interrupts can be
+/// delivered to the current thread.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_stui)
+#[inline]
+#[cfg_attr(test, assert_instr(stui))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _stui() {
+    asm!("stui", options(nostack, preserves_flags));
+}
+
+/// Returns the current value of the user interrupt flag (UIF).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_testui)
+#[inline]
+#[cfg_attr(test, assert_instr(testui))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _testui() -> u8 {
+    let r: u8;
+    asm!(
+        "testui",
+        "setc {}",
+        out(reg_byte) r,
+        options(nomem, nostack),
+    );
+    r
+}
+
+/// Sends a user interrupt to the thread described by entry `a` of the
+/// user-interrupt target table (UITT) of the current thread.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_senduipi)
+#[inline]
+#[cfg_attr(test, assert_instr(senduipi))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _senduipi(a: u64) {
+    asm!("senduipi {}", in(reg) a, options(nostack, preserves_flags));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86_64::*;
+
+    #[test]
+    fn test_clui() {
+        if !is_x86_feature_detected!("uintr") {
+            return;
+        }
+        unsafe {
+            let uif = _testui();
+            _clui();
+            assert_eq!(_testui(), 0);
+            if uif != 0 {
+                _stui();
+            }
+        }
+    }
+
+    #[test]
+    fn test_stui() {
+        if !is_x86_feature_detected!("uintr") {
+            return;
+        }
+        unsafe {
+            let uif = _testui();
+            _stui();
+            assert_eq!(_testui(), 1);
+            if uif == 0 {
+                _clui();
+            }
+        }
+    }
+
+    #[test]
+    fn test_testui() {
+        if !is_x86_feature_detected!("uintr") {
+            return;
+        }
+        unsafe {
+            let uif = _testui();
+            assert!(uif == 0 || uif == 1);
+            _clui();
+            assert_eq!(_testui(), 0);
+            _stui();
+            assert_eq!(_testui(), 1);
+            if uif == 0 {
+                _clui();
+            }
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! User mode access to model-specific registers (USER_MSR).
+//!
+//! The intrinsics here correspond to those in the `usermsrintrin.h` C header.
+//!
+//! These instructions can only access the MSRs that the operating system
+//! allows in the user MSR bitmap, and raise `#GP` for any other MSR.
+//!
+//! The reference is [Intel Architecture Instruction Set Extensions and Future
+//! Features Programming Reference][intel_ref].
+//!
+//! [intel_ref]: https://www.intel.com/content/www/us/en/content-details/790021/intel-architecture-instruction-set-extensions-programming-reference.html
+
+use crate::arch::asm;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Reads the model-specific register (MSR) specified by `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_urdmsr)
+#[inline]
+#[cfg_attr(test, assert_instr(urdmsr))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _urdmsr(a: u64) -> u64 {
+    let r: u64;
+    asm!(
+        "urdmsr {r}, {a}",
+        a = in(reg) a,
+        r = lateout(reg) r,
+        options(nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Writes `b` to the model-specific register (MSR) specified by `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_uwrmsr)
+#[inline]
+#[cfg_attr(test, assert_instr(uwrmsr))]
+#[unstable(feature = "stdarch_x86_system", issue = "44839")]
+pub unsafe fn _uwrmsr(a: u64, b: u64) {
+    asm!(
+        "uwrmsr {a}, {b}",
+        a = in(reg) a,
+        b = in(reg) b,
+        options(nostack, preserves_flags),
+    );
+}
@@ -25,4 +25,6 @@ features! {
     /// FEAT_I8MM (integer matrix multiplication, plus ASIMD support)
     @FEATURE: #[unstable(feature = "stdarch_arm_feature_detection", issue = "111190")] dotprod: "dotprod";
     /// FEAT_DotProd (Vector Dot-Product - ASIMDDP)
+    @FEATURE: #[unstable(feature = "stdarch_arm_feature_detection", issue = "111190")] fp16: "fp16";
+    /// FEAT_FP16 (Half-precision floating-point data processing - ASIMDHP)
 }
@@ -107,10 +107,44 @@ features! {
     /// * `"rtm"`
     /// * `"movbe"`
     /// * `"ermsb"`
+    /// * `"xop"`
+    /// * `"fma4"`
+    /// * `"kl"`
+    /// * `"widekl"`
+    /// * `"avx10.1"`
+    /// * `"avx10.2"`
+    /// * `"avx10-256"` (detection only, see below)
+    /// * `"avx10-512"` (detection only, see below)
+    /// * `"apxf"`
+    /// * `"shstk"`
+    /// * `"waitpkg"`
+    /// * `"monitorx"`
+    /// * `"movdiri"`
+    /// * `"movdir64b"`
+    /// * `"enqcmd"`
+    /// * `"clflushopt"`
+    /// * `"clwb"`
+    /// * `"cldemote"`
+    /// * `"prefetchi"`
+    /// * `"cmpccxadd"`
+    /// * `"raoint"`
+    /// * `"fsgsbase"` (never detected outside of Linux)
+    /// * `"rdpid"`
+    /// * `"serialize"`
+    /// * `"hreset"`
+    /// * `"tsxldtrk"`
+    /// * `"uintr"` (never detected outside of Linux)
+    /// * `"usermsr"` (`"user_msr"` is accepted as a synonym)
+    ///
+    /// `"avx10-256"` and `"avx10-512"` are not `#[target_feature]`s. They
+    /// report the maximum AVX10 vector length from CPUID leaf 0x24, for code
+    /// that has to choose between 256-bit and 512-bit AVX10 paths. The
+    /// `avx512*` intrinsics still require the `avx512*` target features.
     ///
     /// [docs]: https://software.intel.com/sites/landingpage/IntrinsicsGuide
     #[stable(feature = "simd_x86", since = "1.27.0")]
     @BIND_FEATURE_NAME: "abm"; "lzcnt"; // abm is a synonym for lzcnt
+    @BIND_FEATURE_NAME: "user_msr"; "usermsr"; // Intel's name for usermsr
     @BIND_FEATURE_NAME: "avx512gfni"; "gfni"; #[deprecated(since = "1.67.0", note = "the `avx512gfni` feature has been renamed to `gfni`")];
     @BIND_FEATURE_NAME: "avx512vaes"; "vaes"; #[deprecated(since = "1.67.0", note = "the `avx512vaes` feature has been renamed to `vaes`")];
     @BIND_FEATURE_NAME: "avx512vpclmulqdq"; "vpclmulqdq"; #[deprecated(since = "1.67.0", note = "the `avx512vpclmulqdq` feature has been renamed to `vpclmulqdq`")];
@@ -247,4 +281,83 @@ features! {
     /// ERMSB, Enhanced REP MOVSB and STOSB
     @FEATURE: #[unstable(feature = "xop_target_feature", issue = "127208")] xop: "xop";
     /// XOP: eXtended Operations (AMD)
+    @FEATURE: #[unstable(feature = "fma4_target_feature", issue = "155233")] fma4: "fma4";
+    /// FMA4: Four-operand fused multiply-add (AMD)
+    @FEATURE: #[unstable(feature = "keylocker_x86", issue = "134813")] kl: "kl";
+    /// KL: Key Locker
+    @FEATURE: #[unstable(feature = "keylocker_x86", issue = "134813")] widekl: "widekl";
+    /// WIDEKL: Key Locker wide instructions
+    @FEATURE: #[unstable(feature = "avx10_target_feature", issue = "138843")] avx10_1: "avx10.1";
+    /// AVX10.1: AVX10 converged vector ISA, version 1
+    @FEATURE: #[unstable(feature = "avx10_target_feature", issue = "138843")] avx10_2: "avx10.2";
+    /// AVX10.2: AVX10 converged vector ISA, version 2
+    @FEATURE: #[unstable(feature = "apx_target_feature", issue = "139284")] apxf: "apxf";
+    /// APX_F: Advanced Performance Extensions (extended general-purpose registers)
+    // The AVX10 vector lengths are detection-only names, not `#[target_feature]`s, but `avx10.1`
+    // requires 512-bit vectors.
+    @FEATURE: #[unstable(feature = "avx10_target_feature", issue = "138843")] avx10_256: "avx10-256";
+    implied by target_features: ["avx10.1"];
+    /// AVX10 with vectors of up to (at least) 256 bits, at any AVX10 version
+    @FEATURE: #[unstable(feature = "avx10_target_feature", issue = "138843")] avx10_512: "avx10-512";
+    implied by target_features: ["avx10.1"];
+    /// AVX10 with vectors of up to 512 bits, at any AVX10 version
+    // The following are not `#[target_feature]`s, so no `cfg!(target_feature)` implies them.
+    @FEATURE: #[unstable(feature = "stdarch_x86_system", issue = "44839")] shstk: "shstk";
+    implied by target_features: [];
+    /// SHSTK: CET shadow stacks, supported by the CPU and enabled for the current process
+    @FEATURE: #[unstable(feature = "stdarch_x86_wait", issue = "44839")] waitpkg: "waitpkg";
+    implied by target_features: [];
+    /// WAITPKG: User-mode wait instructions (`UMONITOR`, `UMWAIT` and `TPAUSE`)
+    @FEATURE: #[unstable(feature = "stdarch_x86_wait", issue = "44839")] monitorx: "monitorx";
+    implied by target_features: [];
+    /// MONITORX: `MONITORX` and `MWAITX` (AMD)
+    @FEATURE: #[unstable(feature = "stdarch_x86_memory", issue = "44839")] movdiri: "movdiri";
+    implied by target_features: [];
+    /// MOVDIRI: Direct store of doublewords and quadwords
+    @FEATURE: #[unstable(feature = "stdarch_x86_memory", issue = "44839")] movdir64b: "movdir64b";
+    implied by target_features: [];
+    /// MOVDIR64B: Direct store of 64 bytes
+    @FEATURE: #[unstable(feature = "stdarch_x86_memory", issue = "44839")] enqcmd: "enqcmd";
+    implied by target_features: [];
+    /// ENQCMD: Enqueue stores (`ENQCMD` and `ENQCMDS`)
+    @FEATURE: #[unstable(feature = "stdarch_x86_memory", issue = "44839")] clflushopt: "clflushopt";
+    implied by target_features: [];
+    /// CLFLUSHOPT: Optimized cache line flush
+    @FEATURE: #[unstable(feature = "stdarch_x86_memory", issue = "44839")] clwb: "clwb";
+    implied by target_features: [];
+    /// CLWB: Cache line write back
+    @FEATURE: #[unstable(feature = "stdarch_x86_memory", issue = "44839")] cldemote: "cldemote";
+    implied by target_features: [];
+    /// CLDEMOTE: Cache line demote
+    @FEATURE: #[unstable(feature = "stdarch_x86_memory", issue = "44839")] prefetchi: "prefetchi";
+    implied by target_features: [];
+    /// PREFETCHI: Code prefetch
+    @FEATURE: #[unstable(feature = "stdarch_x86_atomics", issue = "44839")] cmpccxadd: "cmpccxadd";
+    implied by target_features: [];
+    /// CMPCCXADD: Compare and add if condition is met
+    @FEATURE: #[unstable(feature = "stdarch_x86_atomics", issue = "44839")] raoint: "raoint";
+    implied by target_features: [];
+    /// RAO-INT: Remote atomic operations
+    @FEATURE: #[unstable(feature = "stdarch_x86_system", issue = "44839")] fsgsbase: "fsgsbase";
+    implied by target_features: [];
+    /// FSGSBASE: Read and write the FS and GS segment base addresses, enabled by the OS (Linux only)
+    @FEATURE: #[unstable(feature = "stdarch_x86_system", issue = "44839")] rdpid: "rdpid";
+    implied by target_features: [];
+    /// RDPID: Read processor ID
+    @FEATURE: #[unstable(feature = "stdarch_x86_system", issue = "44839")] serialize: "serialize";
+    implied by target_features: [];
+    /// SERIALIZE: Serialize instruction execution
+    @FEATURE: #[unstable(feature = "stdarch_x86_system", issue = "44839")] hreset: "hreset";
+    implied by target_features: [];
+    /// HRESET: History reset
+    @FEATURE: #[unstable(feature = "stdarch_x86_system", issue = "44839")] tsxldtrk: "tsxldtrk";
+    implied by target_features: [];
+    /// TSXLDTRK: TSX suspend load address tracking
+    @FEATURE: #[unstable(feature = "stdarch_x86_system", issue = "44839")] uintr: "uintr";
+    implied by target_features: [];
+    /// UINTR: User interrupts, enabled by the OS (Linux only)
+    @FEATURE: #[unstable(feature = "stdarch_x86_system", issue = "44839")] usermsr: "usermsr";
+    implied by target_features: [];
+    /// USER_MSR: User mode access to model-specific registers. Named
+    /// `usermsr` like the LLVM and GCC target feature.
 }
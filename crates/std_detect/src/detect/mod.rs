@@ -44,8 +44,12 @@ cfg_if! {
         // this run-time detection logic is never called.
         #[path = "os/other.rs"]
         mod os;
-    } else if #[cfg(any(target_arch = "x86", target_arch = "x86_64"))] {
-        // On x86/x86_64 no OS specific functionality is required.
+    } else if #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(all(any(target_os = "linux", target_os = "android"), feature = "libc")),
+    ))] {
+        // On x86/x86_64 no OS specific functionality is required, except for
+        // the features that Linux reports in the auxiliary vector.
         #[path = "os/x86.rs"]
         mod os;
     } else if #[cfg(all(any(target_os = "linux", target_os = "android"), feature = "libc"))] {
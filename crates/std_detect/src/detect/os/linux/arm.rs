@@ -19,6 +19,7 @@ pub(crate) fn detect_features() -> cache::Initializer {
     if let Ok(auxv) = auxvec::auxv() {
         enable_feature(&mut value, Feature::i8mm, bit::test(auxv.hwcap, 27));
         enable_feature(&mut value, Feature::dotprod, bit::test(auxv.hwcap, 24));
+        enable_feature(&mut value, Feature::fp16, bit::test(auxv.hwcap, 23));
         enable_feature(&mut value, Feature::neon, bit::test(auxv.hwcap, 12));
         enable_feature(&mut value, Feature::pmull, bit::test(auxv.hwcap2, 1));
         enable_feature(&mut value, Feature::crc, bit::test(auxv.hwcap2, 4));
@@ -45,6 +46,11 @@ pub(crate) fn detect_features() -> cache::Initializer {
             Feature::dotprod,
             c.field("Features").has("asimddp"),
         );
+        enable_feature(
+            &mut value,
+            Feature::fp16,
+            c.field("Features").has("asimdhp"),
+        );
         enable_feature(&mut value, Feature::pmull, c.field("Features").has("pmull"));
         enable_feature(&mut value, Feature::crc, c.field("Features").has("crc32"));
         enable_feature(&mut value, Feature::aes, c.field("Features").has("aes"));
@@ -10,7 +10,9 @@ pub(crate) const AT_HWCAP: usize = 16;
     target_arch = "aarch64",
     target_arch = "arm",
     target_arch = "powerpc",
-    target_arch = "powerpc64"
+    target_arch = "powerpc64",
+    target_arch = "x86",
+    target_arch = "x86_64"
 ))]
 pub(crate) const AT_HWCAP2: usize = 26;
 
@@ -26,7 +28,9 @@ pub(crate) struct AuxVec {
         target_arch = "aarch64",
         target_arch = "arm",
         target_arch = "powerpc",
-        target_arch = "powerpc64"
+        target_arch = "powerpc64",
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
     pub hwcap2: usize,
 }
@@ -98,7 +102,9 @@ pub(crate) fn auxv() -> Result<AuxVec, ()> {
                 target_arch = "aarch64",
                 target_arch = "arm",
                 target_arch = "powerpc",
-                target_arch = "powerpc64"
+                target_arch = "powerpc64",
+                target_arch = "x86",
+                target_arch = "x86_64"
             ))]
             {
                 if let Ok(hwcap2) = getauxval(AT_HWCAP2) {
@@ -146,7 +152,9 @@ pub(crate) fn auxv() -> Result<AuxVec, ()> {
             target_arch = "aarch64",
             target_arch = "arm",
             target_arch = "powerpc",
-            target_arch = "powerpc64"
+            target_arch = "powerpc64",
+            target_arch = "x86",
+            target_arch = "x86_64"
         ))]
         {
             let hwcap = unsafe { libc::getauxval(AT_HWCAP as libc::c_ulong) as usize };
@@ -242,7 +250,9 @@ fn auxv_from_buf(buf: &[usize]) -> Result<AuxVec, ()> {
         target_arch = "aarch64",
         target_arch = "arm",
         target_arch = "powerpc",
-        target_arch = "powerpc64"
+        target_arch = "powerpc64",
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
     {
         let mut hwcap = None;
@@ -290,7 +300,9 @@ mod tests {
             target_arch = "aarch64",
             target_arch = "arm",
             target_arch = "powerpc",
-            target_arch = "powerpc64"
+            target_arch = "powerpc64",
+            target_arch = "x86",
+            target_arch = "x86_64"
         ))]
         {
             if let Ok(hwcap2) = getauxval(AT_HWCAP2) {
@@ -361,6 +373,7 @@ mod tests {
         }
     }
 
+    // On x86, glibc's `getauxval(AT_HWCAP)` does not return the kernel's value.
     #[cfg(any(
         target_arch = "aarch64",
         target_arch = "arm",
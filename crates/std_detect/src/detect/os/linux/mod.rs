@@ -57,6 +57,10 @@ cfg_if::cfg_if! {
     } else if #[cfg(target_arch = "loongarch64")] {
         mod loongarch;
         pub(crate) use self::loongarch::detect_features;
+    } else if #[cfg(any(target_arch = "x86", target_arch = "x86_64"))] {
+        #[path = "../x86.rs"]
+        mod x86;
+        pub(crate) use self::x86::detect_features;
     } else {
         use crate::detect::cache;
         /// Performs run-time feature detection.
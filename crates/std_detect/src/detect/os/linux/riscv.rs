@@ -1,10 +1,157 @@
 //! Run-time feature detection for RISC-V on Linux.
+//!
+//! Linux 6.4 and later report the supported extensions through the
+//! `riscv_hwprobe` system call. On older kernels only the single-letter
+//! extensions can be read from the auxiliary vector.
 
 use super::auxvec;
 use crate::detect::{bit, cache, Feature};
 
-/// Read list of supported features from the auxiliary vector.
+/// System call number of `riscv_hwprobe`, see [asm/unistd.h][unistd].
+///
+/// [unistd]: https://github.com/torvalds/linux/blob/master/arch/riscv/include/uapi/asm/unistd.h
+const NR_RISCV_HWPROBE: libc::c_long = 258;
+
+// The keys and values are part of the platform-specific [asm/hwprobe.h][hwprobe]
+//
+// [hwprobe]: https://github.com/torvalds/linux/blob/master/arch/riscv/include/uapi/asm/hwprobe.h
+const RISCV_HWPROBE_KEY_MVENDORID: i64 = 0;
+const RISCV_HWPROBE_KEY_MARCHID: i64 = 1;
+const RISCV_HWPROBE_KEY_MIMPID: i64 = 2;
+const RISCV_HWPROBE_KEY_BASE_BEHAVIOR: i64 = 3;
+const RISCV_HWPROBE_KEY_IMA_EXT_0: i64 = 4;
+const RISCV_HWPROBE_KEY_CPUPERF_0: i64 = 5;
+
+const RISCV_HWPROBE_BASE_BEHAVIOR_IMA: u64 = 1 << 0;
+
+const RISCV_HWPROBE_IMA_FD: u32 = 0;
+const RISCV_HWPROBE_IMA_C: u32 = 1;
+const RISCV_HWPROBE_IMA_V: u32 = 2;
+const RISCV_HWPROBE_EXT_ZBA: u32 = 3;
+const RISCV_HWPROBE_EXT_ZBB: u32 = 4;
+const RISCV_HWPROBE_EXT_ZBS: u32 = 5;
+const RISCV_HWPROBE_EXT_ZICBOZ: u32 = 6;
+const RISCV_HWPROBE_EXT_ZBC: u32 = 7;
+const RISCV_HWPROBE_EXT_ZBKB: u32 = 8;
+const RISCV_HWPROBE_EXT_ZBKC: u32 = 9;
+const RISCV_HWPROBE_EXT_ZBKX: u32 = 10;
+const RISCV_HWPROBE_EXT_ZKND: u32 = 11;
+const RISCV_HWPROBE_EXT_ZKNE: u32 = 12;
+const RISCV_HWPROBE_EXT_ZKNH: u32 = 13;
+const RISCV_HWPROBE_EXT_ZKSED: u32 = 14;
+const RISCV_HWPROBE_EXT_ZKSH: u32 = 15;
+const RISCV_HWPROBE_EXT_ZKT: u32 = 16;
+const RISCV_HWPROBE_EXT_ZVBB: u32 = 17;
+const RISCV_HWPROBE_EXT_ZVBC: u32 = 18;
+const RISCV_HWPROBE_EXT_ZVKB: u32 = 19;
+const RISCV_HWPROBE_EXT_ZVKG: u32 = 20;
+const RISCV_HWPROBE_EXT_ZVKNED: u32 = 21;
+const RISCV_HWPROBE_EXT_ZVKNHA: u32 = 22;
+const RISCV_HWPROBE_EXT_ZVKNHB: u32 = 23;
+const RISCV_HWPROBE_EXT_ZVKSED: u32 = 24;
+const RISCV_HWPROBE_EXT_ZVKSH: u32 = 25;
+const RISCV_HWPROBE_EXT_ZVKT: u32 = 26;
+const RISCV_HWPROBE_EXT_ZFH: u32 = 27;
+const RISCV_HWPROBE_EXT_ZFHMIN: u32 = 28;
+const RISCV_HWPROBE_EXT_ZIHINTNTL: u32 = 29;
+const RISCV_HWPROBE_EXT_ZVFH: u32 = 30;
+const RISCV_HWPROBE_EXT_ZVFHMIN: u32 = 31;
+const RISCV_HWPROBE_EXT_ZFA: u32 = 32;
+const RISCV_HWPROBE_EXT_ZTSO: u32 = 33;
+const RISCV_HWPROBE_EXT_ZACAS: u32 = 34;
+const RISCV_HWPROBE_EXT_ZICOND: u32 = 35;
+const RISCV_HWPROBE_EXT_ZIHINTPAUSE: u32 = 36;
+
+/// A key-value pair as read and written by the `riscv_hwprobe` system call.
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct RiscvHwprobe {
+    key: i64,
+    value: u64,
+}
+
+/// Values reported by the `riscv_hwprobe` system call.
+///
+/// Keys that the running kernel does not know about are `None`.
+///
+/// Only the base behavior and `IMA_EXT_0` are used to detect features. The
+/// vendor, architecture and implementation IDs and the `CPUPERF_0` performance
+/// hints are queried alongside them so that the whole probe result can be
+/// dumped when recording test data.
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(test, derive(PartialEq))]
+struct HwProbe {
+    mvendorid: Option<u64>,
+    marchid: Option<u64>,
+    mimpid: Option<u64>,
+    base_behavior: Option<u64>,
+    ima_ext_0: Option<u64>,
+    cpuperf_0: Option<u64>,
+}
+
+impl HwProbe {
+    /// Collects the values of the keys recognized by the kernel. The kernel
+    /// sets the key of an unrecognized pair to -1.
+    fn from_pairs(pairs: &[RiscvHwprobe]) -> Self {
+        let mut probe = HwProbe::default();
+        for pair in pairs {
+            let slot = match pair.key {
+                RISCV_HWPROBE_KEY_MVENDORID => &mut probe.mvendorid,
+                RISCV_HWPROBE_KEY_MARCHID => &mut probe.marchid,
+                RISCV_HWPROBE_KEY_MIMPID => &mut probe.mimpid,
+                RISCV_HWPROBE_KEY_BASE_BEHAVIOR => &mut probe.base_behavior,
+                RISCV_HWPROBE_KEY_IMA_EXT_0 => &mut probe.ima_ext_0,
+                RISCV_HWPROBE_KEY_CPUPERF_0 => &mut probe.cpuperf_0,
+                _ => continue,
+            };
+            *slot = Some(pair.value);
+        }
+        probe
+    }
+}
+
+/// Queries the `riscv_hwprobe` system call for the features common to all
+/// harts of the process.
+///
+/// Fails with `Err` on kernels older than 6.4, which do not implement it.
+fn hwprobe() -> Result<HwProbe, ()> {
+    let mut pairs = [
+        RISCV_HWPROBE_KEY_MVENDORID,
+        RISCV_HWPROBE_KEY_MARCHID,
+        RISCV_HWPROBE_KEY_MIMPID,
+        RISCV_HWPROBE_KEY_BASE_BEHAVIOR,
+        RISCV_HWPROBE_KEY_IMA_EXT_0,
+        RISCV_HWPROBE_KEY_CPUPERF_0,
+    ]
+    .map(|key| RiscvHwprobe { key, value: 0 });
+
+    // An empty CPU set asks for the behavior of all online CPUs.
+    let ret = unsafe {
+        libc::syscall(
+            NR_RISCV_HWPROBE,
+            pairs.as_mut_ptr(),
+            pairs.len() as libc::size_t,
+            0 as libc::size_t,
+            core::ptr::null_mut::<libc::c_ulong>(),
+            0 as libc::c_uint,
+        )
+    };
+    if ret != 0 {
+        return Err(());
+    }
+    Ok(HwProbe::from_pairs(&pairs))
+}
+
+/// Read list of supported features from `riscv_hwprobe`, falling back to the
+/// auxiliary vector.
 pub(crate) fn detect_features() -> cache::Initializer {
+    let hwcap = auxvec::auxv().map(|auxv| auxv.hwcap).unwrap_or(0);
+    cache(hwcap, hwprobe().unwrap_or_default())
+}
+
+/// Combines the single-letter extensions from `AT_HWCAP` with the features
+/// reported by `riscv_hwprobe`.
+fn cache(hwcap: usize, probe: HwProbe) -> cache::Initializer {
     let mut value = cache::Initializer::default();
     let enable_feature = |value: &mut cache::Initializer, feature, enable| {
         if enable {
@@ -22,28 +169,27 @@ pub(crate) fn detect_features() -> cache::Initializer {
     // The values are part of the platform-specific [asm/hwcap.h][hwcap]
     //
     // [hwcap]: https://github.com/torvalds/linux/blob/master/arch/riscv/include/asm/hwcap.h
-    let auxv = auxvec::auxv().expect("read auxvec"); // should not fail on RISC-V platform
     enable_feature(
         &mut value,
         Feature::a,
-        bit::test(auxv.hwcap, (b'a' - b'a').into()),
+        bit::test(hwcap, (b'a' - b'a').into()),
     );
     enable_feature(
         &mut value,
         Feature::c,
-        bit::test(auxv.hwcap, (b'c' - b'a').into()),
+        bit::test(hwcap, (b'c' - b'a').into()),
     );
     enable_features(
         &mut value,
         &[Feature::d, Feature::f, Feature::zicsr],
-        bit::test(auxv.hwcap, (b'd' - b'a').into()),
+        bit::test(hwcap, (b'd' - b'a').into()),
     );
     enable_features(
         &mut value,
         &[Feature::f, Feature::zicsr],
-        bit::test(auxv.hwcap, (b'f' - b'a').into()),
+        bit::test(hwcap, (b'f' - b'a').into()),
     );
-    let has_i = bit::test(auxv.hwcap, (b'i' - b'a').into());
+    let has_i = bit::test(hwcap, (b'i' - b'a').into());
     // If future RV128I is supported, implement with `enable_feature` here
     #[cfg(target_pointer_width = "64")]
     enable_feature(&mut value, Feature::rv64i, has_i);
@@ -53,21 +199,232 @@ pub(crate) fn detect_features() -> cache::Initializer {
     enable_feature(
         &mut value,
         Feature::rv32e,
-        bit::test(auxv.hwcap, (b'e' - b'a').into()),
+        bit::test(hwcap, (b'e' - b'a').into()),
     );
     enable_feature(
         &mut value,
         Feature::h,
-        bit::test(auxv.hwcap, (b'h' - b'a').into()),
+        bit::test(hwcap, (b'h' - b'a').into()),
     );
     enable_feature(
         &mut value,
         Feature::m,
-        bit::test(auxv.hwcap, (b'm' - b'a').into()),
+        bit::test(hwcap, (b'm' - b'a').into()),
+    );
+    enable_feature(
+        &mut value,
+        Feature::v,
+        bit::test(hwcap, (b'v' - b'a').into()),
     );
     // FIXME: Auxvec does not show supervisor feature support, but this mode may be useful
     // to detect when Rust is used to write Linux kernel modules.
     // These should be more than Auxvec way to detect supervisor features.
 
+    if let Some(base_behavior) = probe.base_behavior {
+        let has_ima = base_behavior & RISCV_HWPROBE_BASE_BEHAVIOR_IMA != 0;
+        #[cfg(target_pointer_width = "64")]
+        enable_feature(&mut value, Feature::rv64i, has_ima);
+        #[cfg(target_pointer_width = "32")]
+        enable_feature(&mut value, Feature::rv32i, has_ima);
+        enable_features(&mut value, &[Feature::m, Feature::a], has_ima);
+    }
+
+    if let Some(ima_ext_0) = probe.ima_ext_0 {
+        // `IMA_EXT_0` is 64 bits wide on RV32 as well, so `bit::test` can't be used.
+        let test = |bit: u32| ima_ext_0 & (1 << bit) != 0;
+
+        enable_features(
+            &mut value,
+            &[Feature::d, Feature::f, Feature::zicsr],
+            test(RISCV_HWPROBE_IMA_FD),
+        );
+        enable_feature(&mut value, Feature::c, test(RISCV_HWPROBE_IMA_C));
+        enable_feature(&mut value, Feature::v, test(RISCV_HWPROBE_IMA_V));
+
+        enable_feature(&mut value, Feature::zba, test(RISCV_HWPROBE_EXT_ZBA));
+        enable_feature(&mut value, Feature::zbb, test(RISCV_HWPROBE_EXT_ZBB));
+        enable_feature(&mut value, Feature::zbs, test(RISCV_HWPROBE_EXT_ZBS));
+        enable_feature(&mut value, Feature::zbc, test(RISCV_HWPROBE_EXT_ZBC));
+
+        let zbkb = test(RISCV_HWPROBE_EXT_ZBKB);
+        let zbkc = test(RISCV_HWPROBE_EXT_ZBKC);
+        let zbkx = test(RISCV_HWPROBE_EXT_ZBKX);
+        let zknd = test(RISCV_HWPROBE_EXT_ZKND);
+        let zkne = test(RISCV_HWPROBE_EXT_ZKNE);
+        let zknh = test(RISCV_HWPROBE_EXT_ZKNH);
+        let zksed = test(RISCV_HWPROBE_EXT_ZKSED);
+        let zksh = test(RISCV_HWPROBE_EXT_ZKSH);
+        enable_feature(&mut value, Feature::zbkb, zbkb);
+        enable_feature(&mut value, Feature::zbkc, zbkc);
+        enable_feature(&mut value, Feature::zbkx, zbkx);
+        enable_feature(&mut value, Feature::zknd, zknd);
+        enable_feature(&mut value, Feature::zkne, zkne);
+        enable_feature(&mut value, Feature::zknh, zknh);
+        enable_feature(&mut value, Feature::zksed, zksed);
+        enable_feature(&mut value, Feature::zksh, zksh);
+        enable_feature(&mut value, Feature::zkt, test(RISCV_HWPROBE_EXT_ZKT));
+        enable_feature(
+            &mut value,
+            Feature::zkn,
+            zbkb && zbkc && zbkx && zkne && zknd && zknh,
+        );
+        enable_feature(
+            &mut value,
+            Feature::zks,
+            zbkb && zbkc && zbkx && zksed && zksh,
+        );
+
+        enable_feature(&mut value, Feature::zvbb, test(RISCV_HWPROBE_EXT_ZVBB));
+        enable_feature(&mut value, Feature::zvbc, test(RISCV_HWPROBE_EXT_ZVBC));
+        enable_feature(&mut value, Feature::zvkb, test(RISCV_HWPROBE_EXT_ZVKB));
+        enable_feature(&mut value, Feature::zvkg, test(RISCV_HWPROBE_EXT_ZVKG));
+        enable_feature(&mut value, Feature::zvkned, test(RISCV_HWPROBE_EXT_ZVKNED));
+        enable_feature(&mut value, Feature::zvknha, test(RISCV_HWPROBE_EXT_ZVKNHA));
+        enable_feature(&mut value, Feature::zvknhb, test(RISCV_HWPROBE_EXT_ZVKNHB));
+        enable_feature(&mut value, Feature::zvksed, test(RISCV_HWPROBE_EXT_ZVKSED));
+        enable_feature(&mut value, Feature::zvksh, test(RISCV_HWPROBE_EXT_ZVKSH));
+        enable_feature(&mut value, Feature::zvkt, test(RISCV_HWPROBE_EXT_ZVKT));
+        enable_feature(&mut value, Feature::zvfh, test(RISCV_HWPROBE_EXT_ZVFH));
+        enable_feature(
+            &mut value,
+            Feature::zvfhmin,
+            test(RISCV_HWPROBE_EXT_ZVFHMIN),
+        );
+
+        enable_feature(&mut value, Feature::zfh, test(RISCV_HWPROBE_EXT_ZFH));
+        enable_feature(&mut value, Feature::zfhmin, test(RISCV_HWPROBE_EXT_ZFHMIN));
+        enable_feature(&mut value, Feature::zfa, test(RISCV_HWPROBE_EXT_ZFA));
+        enable_feature(&mut value, Feature::ztso, test(RISCV_HWPROBE_EXT_ZTSO));
+        enable_feature(&mut value, Feature::zacas, test(RISCV_HWPROBE_EXT_ZACAS));
+        enable_feature(&mut value, Feature::zicond, test(RISCV_HWPROBE_EXT_ZICOND));
+        enable_feature(&mut value, Feature::zicboz, test(RISCV_HWPROBE_EXT_ZICBOZ));
+        enable_feature(
+            &mut value,
+            Feature::zihintntl,
+            test(RISCV_HWPROBE_EXT_ZIHINTNTL),
+        );
+        enable_feature(
+            &mut value,
+            Feature::zihintpause,
+            test(RISCV_HWPROBE_EXT_ZIHINTPAUSE),
+        );
+    }
+
     value
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reads a probe result recorded as the raw array of key-value pairs the
+    /// kernel writes back.
+    #[cfg(feature = "std_detect_file_io")]
+    fn hwprobe_from_file(file: &str) -> HwProbe {
+        let file = super::super::read_file(file).unwrap();
+        let pairs: alloc::vec::Vec<RiscvHwprobe> = file
+            .chunks_exact(16)
+            .map(|chunk| RiscvHwprobe {
+                key: i64::from_le_bytes(chunk[..8].try_into().unwrap()),
+                value: u64::from_le_bytes(chunk[8..].try_into().unwrap()),
+            })
+            .collect();
+        HwProbe::from_pairs(&pairs)
+    }
+
+    #[test]
+    fn hwprobe_dump() {
+        if let Ok(probe) = hwprobe() {
+            println!("{:?}", probe);
+        } else {
+            println!("riscv_hwprobe is not supported by this kernel!");
+        }
+    }
+
+    #[test]
+    fn hwprobe_unknown_keys() {
+        let pairs = [
+            RiscvHwprobe {
+                key: RISCV_HWPROBE_KEY_BASE_BEHAVIOR,
+                value: RISCV_HWPROBE_BASE_BEHAVIOR_IMA,
+            },
+            RiscvHwprobe { key: -1, value: 0 },
+        ];
+        let probe = HwProbe::from_pairs(&pairs);
+        assert_eq!(probe.base_behavior, Some(RISCV_HWPROBE_BASE_BEHAVIOR_IMA));
+        assert_eq!(probe.ima_ext_0, None);
+    }
+
+    #[cfg(feature = "std_detect_file_io")]
+    #[test]
+    fn linux_artificial_rva22_hwprobe() {
+        let file = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/detect/test_data/linux-artificial-rva22-riscv64.hwprobe"
+        );
+        println!("file: {file}");
+        let probe = hwprobe_from_file(file);
+        assert_eq!(probe.mvendorid, Some(0x489));
+        assert_eq!(probe.base_behavior, Some(RISCV_HWPROBE_BASE_BEHAVIOR_IMA));
+        assert_eq!(probe.ima_ext_0, Some(0x1c_1000_007f));
+        assert_eq!(probe.cpuperf_0, Some(3));
+
+        let value = cache(0, probe);
+        for feature in [
+            Feature::m,
+            Feature::a,
+            Feature::f,
+            Feature::d,
+            Feature::c,
+            Feature::v,
+            Feature::zba,
+            Feature::zbb,
+            Feature::zbs,
+            Feature::zicboz,
+            Feature::zfhmin,
+            Feature::zicond,
+            Feature::zacas,
+            Feature::zihintpause,
+        ] {
+            assert!(value.test(feature as u32), "{}", feature.to_str());
+        }
+        for feature in [Feature::zbc, Feature::zkn, Feature::zvbb, Feature::zfh] {
+            assert!(!value.test(feature as u32), "{}", feature.to_str());
+        }
+    }
+
+    #[cfg(feature = "std_detect_file_io")]
+    #[test]
+    fn linux_artificial_rv64gc_hwprobe() {
+        let file = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/detect/test_data/linux-artificial-rv64gc-riscv64.hwprobe"
+        );
+        println!("file: {file}");
+        let probe = hwprobe_from_file(file);
+        assert_eq!(probe.ima_ext_0, Some(0x1b));
+        assert_eq!(probe.cpuperf_0, Some(1));
+
+        let value = cache(0, probe);
+        for feature in [Feature::zba, Feature::zbb, Feature::f, Feature::d] {
+            assert!(value.test(feature as u32), "{}", feature.to_str());
+        }
+        for feature in [Feature::zbs, Feature::v] {
+            assert!(!value.test(feature as u32), "{}", feature.to_str());
+        }
+    }
+
+    #[test]
+    fn auxv_only() {
+        // Without `riscv_hwprobe` only the single-letter extensions are known.
+        let hwcap = [b'i', b'm', b'a', b'f', b'd', b'c']
+            .iter()
+            .fold(0, |hwcap, letter| hwcap | 1 << (letter - b'a'));
+        let value = cache(hwcap, HwProbe::default());
+        for feature in [Feature::m, Feature::a, Feature::f, Feature::d, Feature::c] {
+            assert!(value.test(feature as u32), "{}", feature.to_str());
+        }
+        assert!(!value.test(Feature::zbb as u32));
+        assert!(!value.test(Feature::v as u32));
+    }
+}
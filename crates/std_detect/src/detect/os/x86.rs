@@ -1,11 +1,15 @@
-//! x86 run-time feature detection is OS independent.
+//! x86 run-time feature detection is OS independent, except for the few
+//! features that the OS has to enable for user space.
+//!
+//! On Linux, this module is part of `os::linux`, so that it can read the
+//! auxiliary vector.
 
 #[cfg(target_arch = "x86")]
 use core::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use core::arch::x86_64::*;
 
-use core::mem;
+use core::{arch::asm, mem};
 
 use crate::detect::{bit, cache, Feature};
 
@@ -141,6 +145,71 @@ pub(crate) fn detect_features() -> cache::Initializer {
 
         enable(extended_features_ebx, 9, Feature::ermsb);
 
+        enable(extended_features_ecx, 5, Feature::waitpkg);
+        enable(extended_features_ecx, 27, Feature::movdiri);
+        enable(extended_features_ecx, 28, Feature::movdir64b);
+        enable(extended_features_ecx, 29, Feature::enqcmd);
+
+        enable(extended_features_ebx, 23, Feature::clflushopt);
+        enable(extended_features_ebx, 24, Feature::clwb);
+        enable(extended_features_ecx, 25, Feature::cldemote);
+        enable(extended_features_edx_leaf_1, 14, Feature::prefetchi);
+
+        enable(extended_features_eax_leaf_1, 3, Feature::raoint);
+        enable(extended_features_eax_leaf_1, 7, Feature::cmpccxadd);
+
+        enable(extended_features_ecx, 22, Feature::rdpid);
+        enable(extended_features_edx, 14, Feature::serialize);
+        enable(extended_features_edx, 16, Feature::tsxldtrk);
+        enable(extended_features_eax_leaf_1, 22, Feature::hreset);
+
+        // FSGSBASE: the instructions raise `#UD` unless the OS has set
+        // `CR4.FSGSBASE`. Linux reports that it has with `HWCAP2_FSGSBASE`
+        // (bit 1) in `AT_HWCAP2`. Other operating systems do not report it,
+        // so the feature is never detected there.
+        #[cfg(all(any(target_os = "linux", target_os = "android"), feature = "libc"))]
+        {
+            if let Ok(auxv) = super::auxvec::auxv() {
+                if bit::test(auxv.hwcap2, 1) {
+                    enable(extended_features_ebx, 0, Feature::fsgsbase);
+                }
+            }
+        }
+
+        // UINTR: the instructions raise `#UD` unless the OS has set
+        // `CR4.UINTR`. A Linux kernel that has enabled user interrupts lists
+        // `uintr` in the flags of `/proc/cpuinfo` (kernels without support
+        // for them never do). Other operating systems do not report it, so
+        // the feature is never detected there.
+        #[cfg(all(
+            any(target_os = "linux", target_os = "android"),
+            feature = "libc",
+            feature = "std_detect_file_io"
+        ))]
+        {
+            if bit::test(extended_features_edx as usize, 5) {
+                if let Ok(cpuinfo) = super::cpuinfo::CpuInfo::new() {
+                    if cpuinfo.field("flags").has("uintr") {
+                        enable(extended_features_edx, 5, Feature::uintr);
+                    }
+                }
+            }
+        }
+
+        enable(extended_features_edx_leaf_1, 15, Feature::usermsr);
+
+        // Key Locker: the CPU must support it (`CPUID.(EAX=07H, ECX=0H):ECX.KL[bit 23]`)
+        // and the OS must have loaded an internal wrapping key, which is
+        // reported by `CPUID.19H:EBX.AESKLE[bit 0]`. `CPUID.19H:EBX[bit 2]`
+        // reports support for the wide Key Locker instructions.
+        if bit::test(extended_features_ecx as usize, 23) && max_basic_leaf >= 0x19 {
+            let CpuidResult { ebx, .. } = unsafe { __cpuid(0x19) };
+            if bit::test(ebx as usize, 0) {
+                enable(ebx, 0, Feature::kl);
+                enable(ebx, 2, Feature::widekl);
+            }
+        }
+
         // `XSAVE` and `AVX` support:
         let cpu_xsave = bit::test(proc_info_ecx as usize, 26);
         if cpu_xsave {
@@ -165,6 +234,7 @@ pub(crate) fn detect_features() -> cache::Initializer {
                 // * AVX -> `XCR0.AVX[2]`
                 // * AVX-512 -> `XCR0.AVX-512[7:5]`.
                 // * AMX -> `XCR0.AMX[18:17]`
+                // * APX -> `XCR0.APX[19]`
                 //
                 // by setting the corresponding bits of `XCR0` to `1`.
                 //
@@ -177,6 +247,9 @@ pub(crate) fn detect_features() -> cache::Initializer {
                 let os_avx512_support = xcr0 & 0xe0 == 0xe0;
                 // Test `XCR0.AMX[18:17]` with the mask `0b110_0000_0000_0000_0000 == 0x60000`
                 let os_amx_support = xcr0 & 0x60000 == 0x60000;
+                // Test `XCR0.APX[19]` with the mask `0b1000_0000_0000_0000_0000 == 0x80000`
+                #[cfg(target_arch = "x86_64")]
+                let os_apx_support = xcr0 & 0x80000 == 0x80000;
 
                 // Only if the OS and the CPU support saving/restoring the AVX
                 // registers we enable `xsave` support:
@@ -208,6 +281,9 @@ pub(crate) fn detect_features() -> cache::Initializer {
 
                     // FMA (uses 256-bit wide registers):
                     enable(proc_info_ecx, 12, Feature::fma);
+                    // FMA4 is AMD-only, but its CPUID bit is reserved (zero)
+                    // on other vendors.
+                    enable(extended_proc_info_ecx, 16, Feature::fma4);
 
                     // And AVX/AVX2:
                     enable(proc_info_ecx, 28, Feature::avx);
@@ -244,6 +320,49 @@ pub(crate) fn detect_features() -> cache::Initializer {
                         enable(extended_features_edx, 23, Feature::avx512fp16);
                         enable(extended_features_eax_leaf_1, 5, Feature::avx512bf16);
 
+                        // AVX10 (`CPUID.(EAX=07H, ECX=01H):EDX.AVX10[bit 19]`)
+                        // reports a version number and the supported vector
+                        // lengths in leaf 0x24 instead of individual feature
+                        // bits:
+                        if bit::test(extended_features_edx_leaf_1 as usize, 19)
+                            && max_basic_leaf >= 0x24
+                        {
+                            let CpuidResult { ebx: avx10_ebx, .. } =
+                                unsafe { __cpuid_count(0x24_u32, 0) };
+                            let avx10_version = avx10_ebx & 0xff;
+                            enable(avx10_ebx, 17, Feature::avx10_256);
+                            enable(avx10_ebx, 18, Feature::avx10_512);
+
+                            // `avx10.1` implies the AVX-512 features it
+                            // converges, which use 512-bit vectors, so parts
+                            // limited to 256-bit vectors only get `avx10-256`.
+                            // AVX10 parts are not required to report the
+                            // individual AVX-512 bits, so set them here too.
+                            if avx10_version >= 1 {
+                                for f in [
+                                    Feature::avx10_1,
+                                    Feature::avx512f,
+                                    Feature::avx512cd,
+                                    Feature::avx512bw,
+                                    Feature::avx512dq,
+                                    Feature::avx512vl,
+                                    Feature::avx512ifma,
+                                    Feature::avx512vbmi,
+                                    Feature::avx512vbmi2,
+                                    Feature::avx512vnni,
+                                    Feature::avx512bitalg,
+                                    Feature::avx512vpopcntdq,
+                                    Feature::avx512bf16,
+                                    Feature::avx512fp16,
+                                ] {
+                                    enable(avx10_ebx, 18, f);
+                                }
+                            }
+                            if avx10_version >= 2 {
+                                enable(avx10_ebx, 18, Feature::avx10_2);
+                            }
+                        }
+
                         if os_amx_support {
                             enable(extended_features_edx, 24, Feature::amx_tile);
                             enable(extended_features_edx, 25, Feature::amx_int8);
@@ -253,6 +372,14 @@ pub(crate) fn detect_features() -> cache::Initializer {
                         }
                     }
                 }
+
+                // APX's extended general-purpose registers have their own
+                // state component, independent of the vector registers. APX
+                // is only available in 64-bit mode.
+                #[cfg(target_arch = "x86_64")]
+                if os_apx_support {
+                    enable(extended_features_edx_leaf_1, 21, Feature::apxf);
+                }
             }
         }
 
@@ -280,6 +407,32 @@ pub(crate) fn detect_features() -> cache::Initializer {
             enable(extended_proc_info_ecx, 6, Feature::sse4a);
             enable(extended_proc_info_ecx, 21, Feature::tbm);
             enable(extended_proc_info_ecx, 11, Feature::xop);
+            enable(extended_proc_info_ecx, 29, Feature::monitorx);
+        }
+    }
+
+    // CET shadow stacks: `CPUID.(EAX=07H, ECX=0H):ECX.CET_SS[bit 7]` only
+    // reports that the CPU supports them, but they also have to be enabled by
+    // the OS for the current process. `RDSSP` is a NOP that leaves its operand
+    // unchanged when shadow stacks are not enabled, so a non-zero shadow stack
+    // pointer tells us that they are.
+    if bit::test(extended_features_ecx as usize, 7) {
+        #[cfg(target_arch = "x86")]
+        let ssp = {
+            let mut ssp: u32 = 0;
+            unsafe {
+                asm!("rdsspd {:e}", inout(reg) ssp, options(nomem, nostack, preserves_flags))
+            };
+            ssp as u64
+        };
+        #[cfg(target_arch = "x86_64")]
+        let ssp = {
+            let mut ssp: u64 = 0;
+            unsafe { asm!("rdsspq {}", inout(reg) ssp, options(nomem, nostack, preserves_flags)) };
+            ssp
+        };
+        if ssp != 0 {
+            value.set(Feature::shstk as u32);
         }
     }
 
@@ -4,9 +4,25 @@
 #![cfg_attr(target_arch = "aarch64", feature(stdarch_aarch64_feature_detection))]
 #![cfg_attr(target_arch = "powerpc", feature(stdarch_powerpc_feature_detection))]
 #![cfg_attr(target_arch = "powerpc64", feature(stdarch_powerpc_feature_detection))]
+#![cfg_attr(
+    any(target_arch = "riscv32", target_arch = "riscv64"),
+    feature(stdarch_riscv_feature_detection)
+)]
 #![cfg_attr(
     any(target_arch = "x86", target_arch = "x86_64"),
-    feature(sha512_sm_x86, x86_amx_intrinsics, xop_target_feature)
+    feature(
+        sha512_sm_x86,
+        x86_amx_intrinsics,
+        xop_target_feature,
+        fma4_target_feature,
+        keylocker_x86,
+        avx10_target_feature,
+        apx_target_feature,
+        stdarch_x86_system,
+        stdarch_x86_wait,
+        stdarch_x86_memory,
+        stdarch_x86_atomics
+    )
 )]
 #![allow(clippy::unwrap_used, clippy::use_debug, clippy::print_stdout)]
 
@@ -18,7 +34,9 @@
         target_arch = "x86",
         target_arch = "x86_64",
         target_arch = "powerpc",
-        target_arch = "powerpc64"
+        target_arch = "powerpc64",
+        target_arch = "riscv32",
+        target_arch = "riscv64"
     ),
     macro_use
 )]
@@ -51,6 +69,7 @@ fn arm_linux() {
     println!("sha2: {}", is_arm_feature_detected!("sha2"));
     println!("dotprod: {}", is_arm_feature_detected!("dotprod"));
     println!("i8mm: {}", is_arm_feature_detected!("i8mm"));
+    println!("fp16: {}", is_arm_feature_detected!("fp16"));
 }
 
 #[test]
@@ -239,6 +258,54 @@ fn powerpc64_linux_or_freebsd() {
     println!("power8: {}", is_powerpc64_feature_detected!("power8"));
 }
 
+#[test]
+#[cfg(all(
+    any(target_arch = "riscv32", target_arch = "riscv64"),
+    target_os = "linux"
+))]
+fn riscv_linux() {
+    println!("m: {}", is_riscv_feature_detected!("m"));
+    println!("a: {}", is_riscv_feature_detected!("a"));
+    println!("f: {}", is_riscv_feature_detected!("f"));
+    println!("d: {}", is_riscv_feature_detected!("d"));
+    println!("c: {}", is_riscv_feature_detected!("c"));
+    println!("v: {}", is_riscv_feature_detected!("v"));
+    println!("zba: {}", is_riscv_feature_detected!("zba"));
+    println!("zbb: {}", is_riscv_feature_detected!("zbb"));
+    println!("zbc: {}", is_riscv_feature_detected!("zbc"));
+    println!("zbs: {}", is_riscv_feature_detected!("zbs"));
+    println!("zbkb: {}", is_riscv_feature_detected!("zbkb"));
+    println!("zbkc: {}", is_riscv_feature_detected!("zbkc"));
+    println!("zbkx: {}", is_riscv_feature_detected!("zbkx"));
+    println!("zknd: {}", is_riscv_feature_detected!("zknd"));
+    println!("zkne: {}", is_riscv_feature_detected!("zkne"));
+    println!("zknh: {}", is_riscv_feature_detected!("zknh"));
+    println!("zksed: {}", is_riscv_feature_detected!("zksed"));
+    println!("zksh: {}", is_riscv_feature_detected!("zksh"));
+    println!("zkt: {}", is_riscv_feature_detected!("zkt"));
+    println!("zfh: {}", is_riscv_feature_detected!("zfh"));
+    println!("zfhmin: {}", is_riscv_feature_detected!("zfhmin"));
+    println!("zfa: {}", is_riscv_feature_detected!("zfa"));
+    println!("ztso: {}", is_riscv_feature_detected!("ztso"));
+    println!("zacas: {}", is_riscv_feature_detected!("zacas"));
+    println!("zicond: {}", is_riscv_feature_detected!("zicond"));
+    println!("zicboz: {}", is_riscv_feature_detected!("zicboz"));
+    println!("zihintntl: {}", is_riscv_feature_detected!("zihintntl"));
+    println!("zihintpause: {}", is_riscv_feature_detected!("zihintpause"));
+    println!("zvbb: {}", is_riscv_feature_detected!("zvbb"));
+    println!("zvbc: {}", is_riscv_feature_detected!("zvbc"));
+    println!("zvfh: {}", is_riscv_feature_detected!("zvfh"));
+    println!("zvfhmin: {}", is_riscv_feature_detected!("zvfhmin"));
+    println!("zvkb: {}", is_riscv_feature_detected!("zvkb"));
+    println!("zvkg: {}", is_riscv_feature_detected!("zvkg"));
+    println!("zvkned: {}", is_riscv_feature_detected!("zvkned"));
+    println!("zvknha: {}", is_riscv_feature_detected!("zvknha"));
+    println!("zvknhb: {}", is_riscv_feature_detected!("zvknhb"));
+    println!("zvksed: {}", is_riscv_feature_detected!("zvksed"));
+    println!("zvksh: {}", is_riscv_feature_detected!("zvksh"));
+    println!("zvkt: {}", is_riscv_feature_detected!("zvkt"));
+}
+
 #[test]
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 fn x86_all() {
@@ -309,6 +376,34 @@ fn x86_all() {
     println!("amx-fp16: {:?}", is_x86_feature_detected!("amx-fp16"));
     println!("amx-complex: {:?}", is_x86_feature_detected!("amx-complex"));
     println!("xop: {:?}", is_x86_feature_detected!("xop"));
+    println!("fma4: {:?}", is_x86_feature_detected!("fma4"));
+    println!("kl: {:?}", is_x86_feature_detected!("kl"));
+    println!("widekl: {:?}", is_x86_feature_detected!("widekl"));
+    println!("avx10.1: {:?}", is_x86_feature_detected!("avx10.1"));
+    println!("avx10.2: {:?}", is_x86_feature_detected!("avx10.2"));
+    println!("avx10-256: {:?}", is_x86_feature_detected!("avx10-256"));
+    println!("avx10-512: {:?}", is_x86_feature_detected!("avx10-512"));
+    println!("apxf: {:?}", is_x86_feature_detected!("apxf"));
+    println!("shstk: {:?}", is_x86_feature_detected!("shstk"));
+    println!("waitpkg: {:?}", is_x86_feature_detected!("waitpkg"));
+    println!("monitorx: {:?}", is_x86_feature_detected!("monitorx"));
+    println!("movdiri: {:?}", is_x86_feature_detected!("movdiri"));
+    println!("movdir64b: {:?}", is_x86_feature_detected!("movdir64b"));
+    println!("enqcmd: {:?}", is_x86_feature_detected!("enqcmd"));
+    println!("clflushopt: {:?}", is_x86_feature_detected!("clflushopt"));
+    println!("clwb: {:?}", is_x86_feature_detected!("clwb"));
+    println!("cldemote: {:?}", is_x86_feature_detected!("cldemote"));
+    println!("prefetchi: {:?}", is_x86_feature_detected!("prefetchi"));
+    println!("cmpccxadd: {:?}", is_x86_feature_detected!("cmpccxadd"));
+    println!("raoint: {:?}", is_x86_feature_detected!("raoint"));
+    println!("fsgsbase: {:?}", is_x86_feature_detected!("fsgsbase"));
+    println!("rdpid: {:?}", is_x86_feature_detected!("rdpid"));
+    println!("serialize: {:?}", is_x86_feature_detected!("serialize"));
+    println!("hreset: {:?}", is_x86_feature_detected!("hreset"));
+    println!("tsxldtrk: {:?}", is_x86_feature_detected!("tsxldtrk"));
+    println!("uintr: {:?}", is_x86_feature_detected!("uintr"));
+    println!("usermsr: {:?}", is_x86_feature_detected!("usermsr"));
+    println!("user_msr: {:?}", is_x86_feature_detected!("user_msr"));
 }
 
 #[test]
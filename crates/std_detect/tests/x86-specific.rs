@@ -5,7 +5,15 @@
     avx512_target_feature,
     sha512_sm_x86,
     x86_amx_intrinsics,
-    xop_target_feature
+    xop_target_feature,
+    fma4_target_feature,
+    keylocker_x86,
+    avx10_target_feature,
+    apx_target_feature,
+    stdarch_x86_system,
+    stdarch_x86_wait,
+    stdarch_x86_memory,
+    stdarch_x86_atomics
 )]
 
 extern crate cupid;
@@ -94,6 +102,34 @@ fn dump() {
     println!("amx-fp16: {:?}", is_x86_feature_detected!("amx-fp16"));
     println!("amx-complex: {:?}", is_x86_feature_detected!("amx-complex"));
     println!("xop: {:?}", is_x86_feature_detected!("xop"));
+    println!("fma4: {:?}", is_x86_feature_detected!("fma4"));
+    println!("kl: {:?}", is_x86_feature_detected!("kl"));
+    println!("widekl: {:?}", is_x86_feature_detected!("widekl"));
+    println!("avx10.1: {:?}", is_x86_feature_detected!("avx10.1"));
+    println!("avx10.2: {:?}", is_x86_feature_detected!("avx10.2"));
+    println!("avx10-256: {:?}", is_x86_feature_detected!("avx10-256"));
+    println!("avx10-512: {:?}", is_x86_feature_detected!("avx10-512"));
+    println!("apxf: {:?}", is_x86_feature_detected!("apxf"));
+    println!("shstk: {:?}", is_x86_feature_detected!("shstk"));
+    println!("waitpkg: {:?}", is_x86_feature_detected!("waitpkg"));
+    println!("monitorx: {:?}", is_x86_feature_detected!("monitorx"));
+    println!("movdiri: {:?}", is_x86_feature_detected!("movdiri"));
+    println!("movdir64b: {:?}", is_x86_feature_detected!("movdir64b"));
+    println!("enqcmd: {:?}", is_x86_feature_detected!("enqcmd"));
+    println!("clflushopt: {:?}", is_x86_feature_detected!("clflushopt"));
+    println!("clwb: {:?}", is_x86_feature_detected!("clwb"));
+    println!("cldemote: {:?}", is_x86_feature_detected!("cldemote"));
+    println!("prefetchi: {:?}", is_x86_feature_detected!("prefetchi"));
+    println!("cmpccxadd: {:?}", is_x86_feature_detected!("cmpccxadd"));
+    println!("raoint: {:?}", is_x86_feature_detected!("raoint"));
+    println!("fsgsbase: {:?}", is_x86_feature_detected!("fsgsbase"));
+    println!("rdpid: {:?}", is_x86_feature_detected!("rdpid"));
+    println!("serialize: {:?}", is_x86_feature_detected!("serialize"));
+    println!("hreset: {:?}", is_x86_feature_detected!("hreset"));
+    println!("tsxldtrk: {:?}", is_x86_feature_detected!("tsxldtrk"));
+    println!("uintr: {:?}", is_x86_feature_detected!("uintr"));
+    println!("usermsr: {:?}", is_x86_feature_detected!("usermsr"));
+    println!("user_msr: {:?}", is_x86_feature_detected!("user_msr"));
 }
 
 #[cfg(feature = "std_detect_env_override")]
@@ -185,3 +221,20 @@ fn compare_with_cupid() {
     assert_eq!(is_x86_feature_detected!("rtm"), information.rtm(),);
     assert_eq!(is_x86_feature_detected!("movbe"), information.movbe(),);
 }
+
+#[test]
+fn avx10_implies_avx512() {
+    if is_x86_feature_detected!("avx10.2") {
+        assert!(is_x86_feature_detected!("avx10.1"));
+    }
+    if is_x86_feature_detected!("avx10.1") {
+        assert!(is_x86_feature_detected!("avx10-512"));
+        assert!(is_x86_feature_detected!("avx512f"));
+        assert!(is_x86_feature_detected!("avx512vl"));
+        assert!(is_x86_feature_detected!("avx512bw"));
+        assert!(is_x86_feature_detected!("avx512fp16"));
+    }
+    if is_x86_feature_detected!("avx10-512") {
+        assert!(is_x86_feature_detected!("avx10-256"));
+    }
+}
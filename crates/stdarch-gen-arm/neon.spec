@@ -7850,3 +7850,972 @@ validate 510
 
 aarch64 = sri
 generate i64, u64
+
+////////////////////
+// Half-precision floating-point (FEAT_FP16)
+////////////////////
+
+/// Floating-point add
+name = vadd
+fn = simd_add
+a = 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0
+b = 8.0, 7.0, 6.0, 5.0, 4.0, 3.0, 2.0, 1.0
+validate 9.0, 9.0, 9.0, 9.0, 9.0, 9.0, 9.0, 9.0
+target = fp16
+
+arm = vadd.
+aarch64 = fadd
+generate float16x*_t
+
+/// Floating-point subtract
+name = vsub
+fn = simd_sub
+a = 1.0, 4.0, 3.0, 8.0, 5.0, 12.0, 7.0, 16.0
+b = 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0
+validate 0.0, 2.0, 0.0, 4.0, 0.0, 6.0, 0.0, 8.0
+target = fp16
+
+arm = vsub.
+aarch64 = fsub
+generate float16x*_t
+
+/// Floating-point multiply
+name = vmul
+fn = simd_mul
+a = 1.0, 2.0, 1.0, 2.0, 3.0, 4.0, 3.0, 4.0
+b = 2.0, 3.0, 4.0, 5.0, 2.0, 3.0, 4.0, 5.0
+validate 2.0, 6.0, 4.0, 10.0, 6.0, 12.0, 12.0, 20.0
+target = fp16
+
+arm = vmul.
+aarch64 = fmul
+generate float16x*_t
+
+/// Floating-point multiply by scalar
+name = vmul
+out-n-suffix
+multi_fn = simd_mul, a, {vdup-nout-noext, b}
+a = 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0
+b = 2.0
+validate 2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0, 16.0
+target = fp16
+
+arm = vmul
+aarch64 = fmul
+generate float16x4_t:f16:float16x4_t, float16x8_t:f16:float16x8_t
+
+/// Floating-point multiply
+name = vmul
+lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in_exp_len-LANE
+multi_fn = simd_mul, a, {simd_shuffle!, b, b, {dup-out_len-LANE as u32}}
+a = 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0
+b = 2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0
+n = 0
+validate 2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0, 16.0
+target = fp16
+
+arm = vmul
+aarch64 = fmul
+generate float16x4_t, float16x4_t:float16x8_t:float16x4_t, float16x8_t:float16x4_t:float16x8_t, float16x8_t
+
+/// Floating-point divide
+name = vdiv
+fn = simd_div
+a = 2.0, 6.0, 4.0, 10.0, 12.0, 14.0, 16.0, 18.0
+b = 1.0, 2.0, 1.0, 2.0, 4.0, 2.0, 8.0, 4.0
+validate 2.0, 3.0, 4.0, 5.0, 3.0, 7.0, 2.0, 4.5
+target = fp16
+
+aarch64 = fdiv
+generate float16x*_t
+
+/// Floating-point multiply extended
+name = vmulx
+a = 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0
+b = 2.0, 2.0, 2.0, 2.0, 2.0, 2.0, 2.0, 2.0
+validate 2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0, 16.0
+target = fp16
+
+aarch64 = fmulx
+link-aarch64 = fmulx._EXT_
+generate float16x*_t
+
+/// Floating-point square root
+name = vsqrt
+fn = simd_fsqrt
+a = 4.0, 9.0, 16.0, 25.0, 36.0, 49.0, 64.0, 81.0
+validate 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0
+target = fp16
+
+aarch64 = fsqrt
+generate float16x*_t
+
+/// Floating-point absolute value
+name = vabs
+fn = simd_fabs
+a = -0.5, -2.25, -3.5, -6.75, 0.5, 2.25, 3.5, 6.75
+validate 0.5, 2.25, 3.5, 6.75, 0.5, 2.25, 3.5, 6.75
+target = fp16
+
+arm = vabs
+aarch64 = fabs
+generate float16x*_t
+
+/// Floating-point negate
+name = vneg
+fn = simd_neg
+a = 0.0, 1.0, -1.0, 2.0, -2.0, 3.0, -3.0, 4.0
+validate 0.0, -1.0, 1.0, -2.0, 2.0, -3.0, 3.0, -4.0
+target = fp16
+
+arm = vneg.s
+aarch64 = fneg
+generate float16x*_t
+
+/// Floating-point absolute difference
+name = vabd
+a = 1.0, 2.0, 5.0, -4.0, 3.0, 0.5, -1.0, 8.0
+b = 9.0, 3.0, 2.0, 8.0, 3.0, 2.0, 1.0, -8.0
+validate 8.0, 1.0, 3.0, 12.0, 0.0, 1.5, 2.0, 16.0
+target = fp16
+
+arm = vabd.s
+aarch64 = fabd
+link-arm = vabds._EXT_
+link-aarch64 = fabd._EXT_
+generate float16x*_t
+
+/// Floating-point maximum
+name = vmax
+a = 1.0, -2.0, 3.0, -4.0, 5.0, -6.0, 7.0, -8.0
+b = 0.0, 3.0, 2.0, 8.0, 4.0, -7.0, 8.0, 0.0
+validate 1.0, 3.0, 3.0, 8.0, 5.0, -6.0, 8.0, 0.0
+target = fp16
+
+arm = vmax
+aarch64 = fmax
+link-arm = vmaxs._EXT_
+link-aarch64 = fmax._EXT_
+generate float16x*_t
+
+/// Floating-point minimum
+name = vmin
+a = 1.0, -2.0, 3.0, -4.0, 5.0, -6.0, 7.0, -8.0
+b = 0.0, 3.0, 2.0, 8.0, 4.0, -7.0, 8.0, 0.0
+validate 0.0, -2.0, 2.0, -4.0, 4.0, -7.0, 7.0, -8.0
+target = fp16
+
+arm = vmin
+aarch64 = fmin
+link-arm = vmins._EXT_
+link-aarch64 = fmin._EXT_
+generate float16x*_t
+
+/// Floating-point maximum number
+name = vmaxnm
+a = 1.0, 2.0, 3.0, -4.0, 5.0, 6.0, 7.0, -8.0
+b = 8.0, 16.0, -1.0, 6.0, 4.0, 8.0, -2.0, 0.0
+validate 8.0, 16.0, 3.0, 6.0, 5.0, 8.0, 7.0, 0.0
+target = fp16
+
+arm = vmaxnm
+aarch64 = fmaxnm
+link-arm = vmaxnm._EXT_
+link-aarch64 = fmaxnm._EXT_
+generate float16x*_t
+
+/// Floating-point minimum number
+name = vminnm
+a = 1.0, 2.0, 3.0, -4.0, 5.0, 6.0, 7.0, -8.0
+b = 8.0, 16.0, -1.0, 6.0, 4.0, 8.0, -2.0, 0.0
+validate 1.0, 2.0, -1.0, -4.0, 4.0, 6.0, -2.0, -8.0
+target = fp16
+
+arm = vminnm
+aarch64 = fminnm
+link-arm = vminnm._EXT_
+link-aarch64 = fminnm._EXT_
+generate float16x*_t
+
+/// Floating-point fused multiply-add to accumulator
+name = vfma
+multi_fn = vfma-self-_, b, c, a
+a = 8.0, 18.0, 12.0, 10.0, 8.0, 18.0, 12.0, 10.0
+b = 6.0, 4.0, 7.0, 8.0, 6.0, 4.0, 7.0, 8.0
+c = 2.0, 3.0, 4.0, 5.0, 2.0, 3.0, 4.0, 5.0
+validate 20.0, 30.0, 40.0, 50.0, 20.0, 30.0, 40.0, 50.0
+target = fp16
+
+arm = vfma
+aarch64 = fmla
+link-arm = llvm.fma._EXT_
+link-aarch64 = llvm.fma._EXT_
+generate float16x*_t
+
+/// Floating-point fused multiply-subtract from accumulator
+name = vfms
+multi_fn = simd_neg, b:in_t, b
+multi_fn = vfma-self-noext, a, b, c
+a = 20.0, 30.0, 40.0, 50.0, 20.0, 30.0, 40.0, 50.0
+b = 6.0, 4.0, 7.0, 8.0, 6.0, 4.0, 7.0, 8.0
+c = 2.0, 3.0, 4.0, 5.0, 2.0, 3.0, 4.0, 5.0
+validate 8.0, 18.0, 12.0, 10.0, 8.0, 18.0, 12.0, 10.0
+target = fp16
+
+arm = vfms
+aarch64 = fmls
+generate float16x*_t
+
+/// Floating-point fused multiply-add to accumulator
+name = vfma
+n-suffix
+multi_fn = vfma-self-noext, a, b, {vdup-nself-noext, c}
+a = 2.0, 3.0, 4.0, 5.0, 2.0, 3.0, 4.0, 5.0
+b = 6.0, 4.0, 7.0, 8.0, 6.0, 4.0, 7.0, 8.0
+c = 8.0
+validate 50.0, 35.0, 60.0, 69.0, 50.0, 35.0, 60.0, 69.0
+target = fp16
+
+aarch64 = fmla
+generate float16x4_t:float16x4_t:f16:float16x4_t, float16x8_t:float16x8_t:f16:float16x8_t
+
+/// Floating-point fused multiply-subtract from accumulator
+name = vfms
+n-suffix
+multi_fn = vfms-self-noext, a, b, {vdup-nself-noext, c}
+a = 50.0, 35.0, 60.0, 69.0, 50.0, 35.0, 60.0, 69.0
+b = 6.0, 4.0, 7.0, 8.0, 6.0, 4.0, 7.0, 8.0
+c = 8.0
+validate 2.0, 3.0, 4.0, 5.0, 2.0, 3.0, 4.0, 5.0
+target = fp16
+
+aarch64 = fmls
+generate float16x4_t:float16x4_t:f16:float16x4_t, float16x8_t:float16x8_t:f16:float16x8_t
+
+/// Floating-point fused multiply-add to accumulator
+name = vfma
+in2-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_exp_len-LANE
+multi_fn = vfma-out-noext, a, b, {vdup-nout-noext, {simd_extract!, c, LANE as u32}}
+a = 2.0, 3.0, 4.0, 5.0, 2.0, 3.0, 4.0, 5.0
+b = 6.0, 4.0, 7.0, 8.0, 6.0, 4.0, 7.0, 8.0
+c = 2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0
+n = 0
+validate 14.0, 11.0, 18.0, 21.0, 14.0, 11.0, 18.0, 21.0
+target = fp16
+
+aarch64 = fmla
+generate float16x4_t, float16x4_t:float16x4_t:float16x8_t:float16x4_t, float16x8_t:float16x8_t:float16x4_t:float16x8_t, float16x8_t
+
+/// Floating-point fused multiply-subtract to accumulator
+name = vfms
+in2-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_exp_len-LANE
+multi_fn = vfms-out-noext, a, b, {vdup-nout-noext, {simd_extract!, c, LANE as u32}}
+a = 14.0, 11.0, 18.0, 21.0, 14.0, 11.0, 18.0, 21.0
+b = 6.0, 4.0, 7.0, 8.0, 6.0, 4.0, 7.0, 8.0
+c = 2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0
+n = 0
+validate 2.0, 3.0, 4.0, 5.0, 2.0, 3.0, 4.0, 5.0
+target = fp16
+
+aarch64 = fmls
+generate float16x4_t, float16x4_t:float16x4_t:float16x8_t:float16x4_t, float16x8_t:float16x8_t:float16x4_t:float16x8_t, float16x8_t
+
+/// Floating-point compare equal
+name = vceq
+fn = simd_eq
+a = 1.25, 3.5, 5.75, 7.0, 1.25, 3.5, 5.75, 7.0
+b = 1.25, 3.5, 5.75, 7.0, 1.25, 3.5, 5.75, 7.0
+validate TRUE, TRUE, TRUE, TRUE, TRUE, TRUE, TRUE, TRUE
+target = fp16
+
+arm = vceq.
+aarch64 = fcmeq
+generate float16x4_t:uint16x4_t, float16x8_t:uint16x8_t
+
+/// Floating-point compare greater than or equal
+name = vcge
+fn = simd_ge
+a = 1.25, 2.25, 3.5, 4.5, 5.5, 6.75, 7.75, 8.75
+b = 0.25, 1.25, 2.25, 3.5, 4.5, 5.5, 6.75, 7.75
+validate TRUE, TRUE, TRUE, TRUE, TRUE, TRUE, TRUE, TRUE
+target = fp16
+
+arm = vcge.s
+aarch64 = fcmge
+generate float16x4_t:uint16x4_t, float16x8_t:uint16x8_t
+
+/// Floating-point compare greater than
+name = vcgt
+fn = simd_gt
+a = 1.25, 2.25, 3.5, 4.5, 5.5, 6.75, 7.75, 8.75
+b = 0.25, 1.25, 2.25, 3.5, 4.5, 5.5, 6.75, 7.75
+validate TRUE, TRUE, TRUE, TRUE, TRUE, TRUE, TRUE, TRUE
+target = fp16
+
+arm = vcgt.s
+aarch64 = fcmgt
+generate float16x4_t:uint16x4_t, float16x8_t:uint16x8_t
+
+/// Floating-point compare less than or equal
+name = vcle
+fn = simd_le
+a = 0.25, 1.25, 2.25, 3.5, 4.5, 5.5, 6.75, 7.75
+b = 1.25, 2.25, 3.5, 4.5, 5.5, 6.75, 7.75, 8.75
+validate TRUE, TRUE, TRUE, TRUE, TRUE, TRUE, TRUE, TRUE
+target = fp16
+
+arm = vcge.s
+aarch64 = fcmge
+generate float16x4_t:uint16x4_t, float16x8_t:uint16x8_t
+
+/// Floating-point compare less than
+name = vclt
+fn = simd_lt
+a = 0.25, 1.25, 2.25, 3.5, 4.5, 5.5, 6.75, 7.75
+b = 1.25, 2.25, 3.5, 4.5, 5.5, 6.75, 7.75, 8.75
+validate TRUE, TRUE, TRUE, TRUE, TRUE, TRUE, TRUE, TRUE
+target = fp16
+
+arm = vcgt.s
+aarch64 = fcmgt
+generate float16x4_t:uint16x4_t, float16x8_t:uint16x8_t
+
+/// Floating-point absolute compare greater than or equal
+name = vcage
+a = -1.25, 0.0, 1.25, 2.25, 3.5, 4.5, 5.5, 6.5
+b = -1.0, 0.0, 1.0, 2.5, 3.25, 4.75, 5.25, 6.75
+validate TRUE, TRUE, TRUE, FALSE, TRUE, FALSE, TRUE, FALSE
+target = fp16
+
+arm = vacge.s
+aarch64 = facge
+link-arm = vacge._EXT2_._EXT_
+link-aarch64 = facge._EXT2_._EXT_
+generate float16x4_t:uint16x4_t, float16x8_t:uint16x8_t
+
+/// Floating-point absolute compare greater than
+name = vcagt
+a = -1.25, 0.0, 1.25, 2.25, 3.5, 4.5, 5.5, 6.5
+b = -1.0, 0.0, 1.0, 2.5, 3.25, 4.75, 5.25, 6.75
+validate TRUE, FALSE, TRUE, FALSE, TRUE, FALSE, TRUE, FALSE
+target = fp16
+
+arm = vacgt.s
+aarch64 = facgt
+link-arm = vacgt._EXT2_._EXT_
+link-aarch64 = facgt._EXT2_._EXT_
+generate float16x4_t:uint16x4_t, float16x8_t:uint16x8_t
+
+/// Floating-point absolute compare less than or equal
+name = vcale
+multi_fn = vcage-self-noext, b, a
+a = -1.25, 0.0, 1.25, 2.25, 3.5, 4.5, 5.5, 6.5
+b = -1.0, 0.0, 1.0, 2.5, 3.25, 4.75, 5.25, 6.75
+validate FALSE, TRUE, FALSE, TRUE, FALSE, TRUE, FALSE, TRUE
+target = fp16
+
+arm = vacge.s
+aarch64 = facge
+generate float16x4_t:uint16x4_t, float16x8_t:uint16x8_t
+
+/// Floating-point absolute compare less than
+name = vcalt
+multi_fn = vcagt-self-noext, b, a
+a = -1.25, 0.0, 1.25, 2.25, 3.5, 4.5, 5.5, 6.5
+b = -1.0, 0.0, 1.0, 2.5, 3.25, 4.75, 5.25, 6.75
+validate FALSE, FALSE, FALSE, TRUE, FALSE, TRUE, FALSE, TRUE
+target = fp16
+
+arm = vacgt.s
+aarch64 = facgt
+generate float16x4_t:uint16x4_t, float16x8_t:uint16x8_t
+
+/// Floating-point convert to lower precision narrow
+name = vcvt
+double-suffixes
+fn = simd_cast
+a = -1.5, 1.5, 2.25, 3.75
+validate -1.5, 1.5, 2.25, 3.75
+target = fp16
+
+arm = vcvt
+aarch64 = fcvtn
+generate float32x4_t:float16x4_t
+
+/// Floating-point convert to higher precision long
+name = vcvt
+double-suffixes
+fn = simd_cast
+a = -1.5, 1.5, 2.25, 3.75
+validate -1.5, 1.5, 2.25, 3.75
+target = fp16
+
+arm = vcvt
+aarch64 = fcvtl
+generate float16x4_t:float32x4_t
+
+/// Floating-point convert to lower precision narrow
+name = vcvt_high
+noq-double-suffixes
+multi_fn = simd_shuffle!, a, {simd_cast, b}, [0, 1, 2, 3, 4, 5, 6, 7]
+a = -1.5, 1.5, 2.25, 3.75
+b = -2.5, 4.5, 6.25, 7.75
+validate -1.5, 1.5, 2.25, 3.75, -2.5, 4.5, 6.25, 7.75
+target = fp16
+
+aarch64 = fcvtn
+generate float16x4_t:float32x4_t:float16x8_t
+
+/// Floating-point convert to higher precision long
+name = vcvt_high
+noq-double-suffixes
+multi_fn = simd_shuffle!, b:float16x4_t, a, a, [4, 5, 6, 7]
+multi_fn = simd_cast, b
+a = -1.5, 1.5, 2.25, 3.75, -2.5, 4.5, 6.25, 7.75
+validate -2.5, 4.5, 6.25, 7.75
+target = fp16
+
+aarch64 = fcvtl
+generate float16x8_t:float32x4_t
+
+/// Fixed-point convert to floating-point
+name = vcvt
+double-suffixes
+fn = simd_cast
+a = 1, 2, 3, 4, 5, 6, 7, 8
+validate 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0
+target = fp16
+
+arm = vcvt
+aarch64 = scvtf
+generate int16x4_t:float16x4_t, int16x8_t:float16x8_t
+aarch64 = ucvtf
+generate uint16x4_t:float16x4_t, uint16x8_t:float16x8_t
+
+/// Floating-point convert to signed fixed-point, rounding toward zero
+name = vcvt
+double-suffixes
+a = -1.25, 2.25, -2.75, 3.75, -4.5, 5.5, -6.0, 7.0
+validate -1, 2, -2, 3, -4, 5, -6, 7
+target = fp16
+
+arm = vcvt
+aarch64 = fcvtzs
+link-arm = llvm.fptosi.sat._EXT2_._EXT_
+link-aarch64 = llvm.fptosi.sat._EXT2_._EXT_
+generate float16x4_t:int16x4_t, float16x8_t:int16x8_t
+
+/// Floating-point convert to unsigned fixed-point, rounding toward zero
+name = vcvt
+double-suffixes
+a = 1.25, 2.25, 2.75, 3.75, 4.5, 5.5, 6.0, 7.0
+validate 1, 2, 2, 3, 4, 5, 6, 7
+target = fp16
+
+arm = vcvt
+aarch64 = fcvtzu
+link-arm = llvm.fptoui.sat._EXT2_._EXT_
+link-aarch64 = llvm.fptoui.sat._EXT2_._EXT_
+generate float16x4_t:uint16x4_t, float16x8_t:uint16x8_t
+
+/// Reciprocal estimate
+name = vrecpe
+a = 4.0, 3.0, 2.0, 1.0, 4.0, 3.0, 2.0, 1.0
+validate 0.24951171875, 0.3330078125, 0.4990234375, 0.998046875, 0.24951171875, 0.3330078125, 0.4990234375, 0.998046875
+target = fp16
+
+arm = vrecpe
+aarch64 = frecpe
+link-arm = vrecpe._EXT_
+link-aarch64 = frecpe._EXT_
+generate float16x*_t
+
+/// Floating-point reciprocal step
+name = vrecps
+a = 4.0, 3.0, 2.0, 1.0, 4.0, 3.0, 2.0, 1.0
+b = 4.0, 3.0, 2.0, 1.0, 4.0, 3.0, 2.0, 1.0
+validate -14.0, -7.0, -2.0, 1.0, -14.0, -7.0, -2.0, 1.0
+target = fp16
+
+arm = vrecps
+aarch64 = frecps
+link-arm = vrecps._EXT_
+link-aarch64 = frecps._EXT_
+generate float16x*_t
+
+/// Reciprocal square-root estimate
+name = vrsqrte
+a = 1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0
+validate 0.998046875, 0.705078125, 0.576171875, 0.4990234375, 0.998046875, 0.705078125, 0.576171875, 0.4990234375
+target = fp16
+
+arm = vrsqrte
+aarch64 = frsqrte
+link-arm = vrsqrte._EXT_
+link-aarch64 = frsqrte._EXT_
+generate float16x*_t
+
+/// Floating-point reciprocal square root step
+name = vrsqrts
+a = 1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0
+b = 1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0
+validate 1.0, -0.5, -3.0, -6.5, 1.0, -0.5, -3.0, -6.5
+target = fp16
+
+arm = vrsqrts
+aarch64 = frsqrts
+link-arm = vrsqrts._EXT_
+link-aarch64 = frsqrts._EXT_
+generate float16x*_t
+
+/// Floating-point round to integral, to nearest with ties to even
+name = vrndn
+a = -1.5, 0.5, 1.5, 2.5, -2.5, 3.5, -0.5, 4.5
+validate -2.0, 0.0, 2.0, 2.0, -2.0, 4.0, -0.0, 4.0
+target = fp16
+
+arm = vrintn
+aarch64 = frintn
+link-arm = vrintn._EXT_
+link-aarch64 = frintn._EXT_
+generate float16x*_t
+
+/// Floating-point round to integral, toward zero
+name = vrnd
+a = -1.5, 0.5, 1.5, 2.5, -2.5, 3.5, -0.5, 4.5
+validate -1.0, 0.0, 1.0, 2.0, -2.0, 3.0, -0.0, 4.0
+target = fp16
+
+aarch64 = frintz
+link-aarch64 = llvm.trunc._EXT_
+generate float16x*_t
+
+/// Floating-point round to integral, to nearest with ties to away
+name = vrnda
+a = -1.5, 0.5, 1.5, 2.5, -2.5, 3.5, -0.5, 4.5
+validate -2.0, 1.0, 2.0, 3.0, -3.0, 4.0, -1.0, 5.0
+target = fp16
+
+aarch64 = frinta
+link-aarch64 = llvm.round._EXT_
+generate float16x*_t
+
+/// Floating-point round to integral, toward minus infinity
+name = vrndm
+a = -1.5, 0.5, 1.5, 2.5, -2.5, 3.5, -0.5, 4.5
+validate -2.0, 0.0, 1.0, 2.0, -3.0, 3.0, -1.0, 4.0
+target = fp16
+
+aarch64 = frintm
+link-aarch64 = llvm.floor._EXT_
+generate float16x*_t
+
+/// Floating-point round to integral, toward plus infinity
+name = vrndp
+a = -1.5, 0.5, 1.5, 2.5, -2.5, 3.5, -0.5, 4.5
+validate -1.0, 1.0, 2.0, 3.0, -2.0, 4.0, -0.0, 5.0
+target = fp16
+
+aarch64 = frintp
+link-aarch64 = llvm.ceil._EXT_
+generate float16x*_t
+
+/// Floating-point round to integral exact, using current rounding mode
+name = vrndx
+a = -1.5, 0.5, 1.5, 2.5, -2.5, 3.5, -0.5, 4.5
+validate -2.0, 0.0, 2.0, 2.0, -2.0, 4.0, -0.0, 4.0
+target = fp16
+
+aarch64 = frintx
+link-aarch64 = llvm.rint._EXT_
+generate float16x*_t
+
+/// Floating-point round to integral, using current rounding mode
+name = vrndi
+a = -1.5, 0.5, 1.5, 2.5, -2.5, 3.5, -0.5, 4.5
+validate -2.0, 0.0, 2.0, 2.0, -2.0, 4.0, -0.0, 4.0
+target = fp16
+
+aarch64 = frinti
+link-aarch64 = llvm.nearbyint._EXT_
+generate float16x*_t
+
+/// Floating-point add pairwise
+name = vpadd
+a = 1.0, 2.0, 3.0, 4.0
+b = 3.0, 4.0, 5.0, 6.0
+validate 3.0, 7.0, 7.0, 11.0
+target = fp16
+
+arm = vpadd
+aarch64 = faddp
+link-arm = vpadd._EXT_
+link-aarch64 = faddp._EXT_
+generate float16x4_t
+
+/// Floating-point add pairwise
+name = vpadd
+a = 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0
+b = 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0
+validate 3.0, 7.0, 11.0, 15.0, 7.0, 11.0, 15.0, 19.0
+target = fp16
+
+aarch64 = faddp
+link-aarch64 = faddp._EXT_
+generate float16x8_t
+
+/// Floating-point maximum pairwise
+name = vpmax
+a = 1.0, -2.0, 3.0, 4.0
+b = 0.0, 3.0, -4.0, 8.0
+validate 1.0, 4.0, 3.0, 8.0
+target = fp16
+
+arm = vpmax
+aarch64 = fmaxp
+link-arm = vpmaxs._EXT_
+link-aarch64 = fmaxp._EXT_
+generate float16x4_t
+
+/// Floating-point maximum pairwise
+name = vpmax
+a = 1.0, -2.0, 3.0, 4.0, 5.0, 6.0, -7.0, -8.0
+b = 0.0, 3.0, -4.0, 8.0, 9.0, 1.0, 2.0, 2.5
+validate 1.0, 4.0, 6.0, -7.0, 3.0, 8.0, 9.0, 2.5
+target = fp16
+
+aarch64 = fmaxp
+link-aarch64 = fmaxp._EXT_
+generate float16x8_t
+
+/// Floating-point minimum pairwise
+name = vpmin
+a = 1.0, -2.0, 3.0, 4.0
+b = 0.0, 3.0, -4.0, 8.0
+validate -2.0, 3.0, 0.0, -4.0
+target = fp16
+
+arm = vpmin
+aarch64 = fminp
+link-arm = vpmins._EXT_
+link-aarch64 = fminp._EXT_
+generate float16x4_t
+
+/// Floating-point minimum pairwise
+name = vpmin
+a = 1.0, -2.0, 3.0, 4.0, 5.0, 6.0, -7.0, -8.0
+b = 0.0, 3.0, -4.0, 8.0, 9.0, 1.0, 2.0, 2.5
+validate -2.0, 3.0, 5.0, -8.0, 0.0, -4.0, 1.0, 2.0
+target = fp16
+
+aarch64 = fminp
+link-aarch64 = fminp._EXT_
+generate float16x8_t
+
+/// Floating-point maximum number pairwise
+name = vpmaxnm
+a = 1.0, 2.0, 3.0, -4.0, 5.0, 6.0, 7.0, -8.0
+b = 8.0, 16.0, -1.0, 6.0, 4.0, 8.0, -2.0, 0.0
+validate 2.0, 3.0, 16.0, 6.0, 6.0, 7.0, 8.0, 0.0
+target = fp16
+
+aarch64 = fmaxnmp
+link-aarch64 = fmaxnmp._EXT_
+generate float16x8_t
+
+/// Floating-point minimum number pairwise
+name = vpminnm
+a = 1.0, 2.0, 3.0, -4.0, 5.0, 6.0, 7.0, -8.0
+b = 8.0, 16.0, -1.0, 6.0, 4.0, 8.0, -2.0, 0.0
+validate 1.0, -4.0, 5.0, -8.0, 8.0, -1.0, 4.0, -2.0
+target = fp16
+
+aarch64 = fminnmp
+link-aarch64 = fminnmp._EXT_
+generate float16x8_t
+
+/// Floating-point maximum across vector
+name = vmaxv
+a = 1.0, 2.0, 0.0, 1.0, 1.5, 0.5, 1.0, 0.25
+validate 2.0
+target = fp16
+
+aarch64 = fmaxv
+link-aarch64 = fmaxv._EXT2_._EXT_
+generate float16x4_t:f16, float16x8_t:f16
+
+/// Floating-point minimum across vector
+name = vminv
+a = 1.0, 2.0, 0.0, 1.0, 1.5, 0.5, 1.0, 0.25
+validate 0.0
+target = fp16
+
+aarch64 = fminv
+link-aarch64 = fminv._EXT2_._EXT_
+generate float16x4_t:f16, float16x8_t:f16
+
+/// Floating-point maximum number across vector
+name = vmaxnmv
+a = 1.0, 2.0, 0.0, 1.0, 1.5, 0.5, 1.0, 0.25
+validate 2.0
+target = fp16
+
+aarch64 = fmaxnmv
+link-aarch64 = fmaxnmv._EXT2_._EXT_
+generate float16x4_t:f16, float16x8_t:f16
+
+/// Floating-point minimum number across vector
+name = vminnmv
+a = 1.0, 2.0, 0.0, 1.0, 1.5, 0.5, 1.0, 0.25
+validate 0.0
+target = fp16
+
+aarch64 = fminnmv
+link-aarch64 = fminnmv._EXT2_._EXT_
+generate float16x4_t:f16, float16x8_t:f16
+
+/// Set all vector lanes to the same value
+name = vdup
+lane-suffixes
+constn = N
+multi_fn = static_assert_imm-in_exp_len-N
+multi_fn = simd_shuffle!, a, a, {dup-out_len-N as u32}
+a = 1.0, 1.0, 1.0, 4.0, 1.0, 1.0, 1.0, 4.0
+n = HFLEN
+validate 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0
+target = fp16
+
+arm = vdup.l
+aarch64 = dup
+generate float16x*_t, float16x8_t:float16x4_t, float16x4_t:float16x8_t
+
+/// Floating-point fused multiply-add long to accumulator (low part)
+name = vfmlal_low
+in1-rot-suffix
+a = 1.0, 1.0, 1.0, 1.0
+b = 1.0, 2.0, 3.0, 4.0, 3.0, 4.0, 5.0, 6.0
+c = 2.0, 2.0, 2.0, 2.0, 2.0, 2.0, 2.0, 2.0
+validate 3.0, 5.0, 7.0, 9.0
+target = fhm
+
+aarch64 = fmlal
+link-aarch64 = fmlal._EXT_._EXT3_
+generate float32x2_t:float16x4_t:float16x4_t:float32x2_t, float32x4_t:float16x8_t:float16x8_t:float32x4_t
+
+/// Floating-point fused multiply-add long to accumulator (high part)
+name = vfmlal_high
+in1-rot-suffix
+a = 1.0, 1.0, 1.0, 1.0
+b = 1.0, 2.0, 3.0, 4.0, 3.0, 4.0, 5.0, 6.0
+c = 2.0, 2.0, 2.0, 2.0, 2.0, 2.0, 2.0, 2.0
+validate 7.0, 9.0, 11.0, 13.0
+target = fhm
+
+aarch64 = fmlal2
+link-aarch64 = fmlal2._EXT_._EXT3_
+generate float32x2_t:float16x4_t:float16x4_t:float32x2_t, float32x4_t:float16x8_t:float16x8_t:float32x4_t
+
+/// Floating-point fused multiply-subtract long from accumulator (low part)
+name = vfmlsl_low
+in1-rot-suffix
+a = 10.0, 10.0, 10.0, 10.0
+b = 1.0, 2.0, 3.0, 4.0, 3.0, 4.0, 5.0, 6.0
+c = 2.0, 2.0, 2.0, 2.0, 2.0, 2.0, 2.0, 2.0
+validate 8.0, 6.0, 4.0, 2.0
+target = fhm
+
+aarch64 = fmlsl
+link-aarch64 = fmlsl._EXT_._EXT3_
+generate float32x2_t:float16x4_t:float16x4_t:float32x2_t, float32x4_t:float16x8_t:float16x8_t:float32x4_t
+
+/// Floating-point fused multiply-subtract long from accumulator (high part)
+name = vfmlsl_high
+in1-rot-suffix
+a = 10.0, 10.0, 10.0, 10.0
+b = 1.0, 2.0, 3.0, 4.0, 3.0, 4.0, 5.0, 6.0
+c = 2.0, 2.0, 2.0, 2.0, 2.0, 2.0, 2.0, 2.0
+validate 4.0, 2.0, 0.0, -2.0
+target = fhm
+
+aarch64 = fmlsl2
+link-aarch64 = fmlsl2._EXT_._EXT3_
+generate float32x2_t:float16x4_t:float16x4_t:float32x2_t, float32x4_t:float16x8_t:float16x8_t:float32x4_t
+
+/// Floating-point fused multiply-add long to accumulator (low part)
+name = vfmlal_low
+in1-rot-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_exp_len-LANE
+multi_fn = self-in1rot-noext, a, b, {vdup-nself-noext, {simd_extract!, c, LANE as u32}}
+a = 1.0, 1.0, 1.0, 1.0
+b = 1.0, 2.0, 3.0, 4.0, 3.0, 4.0, 5.0, 6.0
+c = 2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0
+n = 0
+validate 3.0, 5.0, 7.0, 9.0
+target = fhm
+
+aarch64 = fmlal
+generate float32x2_t:float16x4_t:float16x4_t:float32x2_t, float32x2_t:float16x4_t:float16x8_t:float32x2_t, float32x4_t:float16x8_t:float16x4_t:float32x4_t, float32x4_t:float16x8_t:float16x8_t:float32x4_t
+
+/// Floating-point fused multiply-add long to accumulator (high part)
+name = vfmlal_high
+in1-rot-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_exp_len-LANE
+multi_fn = self-in1rot-noext, a, b, {vdup-nself-noext, {simd_extract!, c, LANE as u32}}
+a = 1.0, 1.0, 1.0, 1.0
+b = 1.0, 2.0, 3.0, 4.0, 3.0, 4.0, 5.0, 6.0
+c = 2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0
+n = 0
+validate 7.0, 9.0, 11.0, 13.0
+target = fhm
+
+aarch64 = fmlal2
+generate float32x2_t:float16x4_t:float16x4_t:float32x2_t, float32x2_t:float16x4_t:float16x8_t:float32x2_t, float32x4_t:float16x8_t:float16x4_t:float32x4_t, float32x4_t:float16x8_t:float16x8_t:float32x4_t
+
+/// Floating-point fused multiply-subtract long from accumulator (low part)
+name = vfmlsl_low
+in1-rot-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_exp_len-LANE
+multi_fn = self-in1rot-noext, a, b, {vdup-nself-noext, {simd_extract!, c, LANE as u32}}
+a = 10.0, 10.0, 10.0, 10.0
+b = 1.0, 2.0, 3.0, 4.0, 3.0, 4.0, 5.0, 6.0
+c = 2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0
+n = 0
+validate 8.0, 6.0, 4.0, 2.0
+target = fhm
+
+aarch64 = fmlsl
+generate float32x2_t:float16x4_t:float16x4_t:float32x2_t, float32x2_t:float16x4_t:float16x8_t:float32x2_t, float32x4_t:float16x8_t:float16x4_t:float32x4_t, float32x4_t:float16x8_t:float16x8_t:float32x4_t
+
+/// Floating-point fused multiply-subtract long from accumulator (high part)
+name = vfmlsl_high
+in1-rot-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_exp_len-LANE
+multi_fn = self-in1rot-noext, a, b, {vdup-nself-noext, {simd_extract!, c, LANE as u32}}
+a = 10.0, 10.0, 10.0, 10.0
+b = 1.0, 2.0, 3.0, 4.0, 3.0, 4.0, 5.0, 6.0
+c = 2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0
+n = 0
+validate 4.0, 2.0, 0.0, -2.0
+target = fhm
+
+aarch64 = fmlsl2
+generate float32x2_t:float16x4_t:float16x4_t:float32x2_t, float32x2_t:float16x4_t:float16x8_t:float32x2_t, float32x4_t:float16x8_t:float16x4_t:float32x4_t, float32x4_t:float16x8_t:float16x8_t:float32x4_t
+
+////////////////////
+// BFloat16 (FEAT_BF16)
+////////////////////
+
+// BFloat16 vectors are tested through their bit patterns, e.g. 0x3F80 is 1.0.
+
+/// BFloat16 floating-point dot product
+name = vbfdot
+out-suffix
+a = 1.0, 2.0, 3.0, 4.0
+b = 0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100
+c = 0x3F80, 0x3F80, 0x4000, 0x4000, 0x3F80, 0x3F80, 0x4000, 0x4000
+validate 4.0, 16.0, 14.0, 34.0
+target = bf16
+
+aarch64 = bfdot
+link-aarch64 = bfdot._EXT_._EXT3_
+generate float32x2_t:bfloat16x4_t:bfloat16x4_t:float32x2_t, float32x4_t:bfloat16x8_t:bfloat16x8_t:float32x4_t
+
+/// BFloat16 floating-point dot product
+name = vbfdot
+out-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_bfdot-LANE
+multi_fn = transmute, c:merge2_t2, c
+multi_fn = simd_shuffle!, c:out_t, c, c, {dup-out_len-LANE as u32}
+multi_fn = vbfdot-out-noext, a, b, {transmute, c}
+a = 1.0, 2.0, 3.0, 4.0
+b = 0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100
+c = 0x3F80, 0x3F80, 0x4000, 0x4000, 0x3F80, 0x3F80, 0x4000, 0x4000
+n = 0
+validate 4.0, 9.0, 14.0, 19.0
+target = bf16
+
+aarch64 = bfdot
+generate float32x2_t:bfloat16x4_t:bfloat16x4_t:float32x2_t, float32x2_t:bfloat16x4_t:bfloat16x8_t:float32x2_t
+generate float32x4_t:bfloat16x8_t:bfloat16x4_t:float32x4_t, float32x4_t:bfloat16x8_t:bfloat16x8_t:float32x4_t
+
+/// BFloat16 floating-point matrix multiply-accumulate
+name = vbfmmla
+out-suffix
+a = 1.0, 2.0, 3.0, 4.0
+b = 0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100
+c = 0x3F80, 0x3F80, 0x3F80, 0x3F80, 0x3F80, 0x4000, 0x3F80, 0x4000
+validate 11.0, 18.0, 29.0, 44.0
+target = bf16
+
+aarch64 = bfmmla
+link-aarch64 = bfmmla
+generate float32x4_t:bfloat16x8_t:bfloat16x8_t:float32x4_t
+
+/// BFloat16 floating-point widening multiply-add long (bottom)
+name = vbfmlalb
+out-suffix
+a = 1.0, 2.0, 3.0, 4.0
+b = 0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100
+c = 0x3F80, 0x3F80, 0x4000, 0x4000, 0x3F80, 0x3F80, 0x4000, 0x4000
+validate 2.0, 8.0, 8.0, 18.0
+target = bf16
+
+aarch64 = bfmlalb
+link-aarch64 = bfmlalb
+generate float32x4_t:bfloat16x8_t:bfloat16x8_t:float32x4_t
+
+/// BFloat16 floating-point widening multiply-add long (top)
+name = vbfmlalt
+out-suffix
+a = 1.0, 2.0, 3.0, 4.0
+b = 0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100
+c = 0x3F80, 0x3F80, 0x4000, 0x4000, 0x3F80, 0x3F80, 0x4000, 0x4000
+validate 3.0, 10.0, 9.0, 20.0
+target = bf16
+
+aarch64 = bfmlalt
+link-aarch64 = bfmlalt
+generate float32x4_t:bfloat16x8_t:bfloat16x8_t:float32x4_t
+
+/// BFloat16 floating-point widening multiply-add long (bottom)
+name = vbfmlalb
+out-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_exp_len-LANE
+multi_fn = vbfmlalb-out-noext, a, b, {simd_shuffle!, c, c, {dup-in_len-LANE as u32}}
+a = 1.0, 2.0, 3.0, 4.0
+b = 0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100
+c = 0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100
+n = 0
+validate 2.0, 5.0, 8.0, 11.0
+target = bf16
+
+aarch64 = bfmlalb
+generate float32x4_t:bfloat16x8_t:bfloat16x4_t:float32x4_t, float32x4_t:bfloat16x8_t:bfloat16x8_t:float32x4_t
+
+/// BFloat16 floating-point widening multiply-add long (top)
+name = vbfmlalt
+out-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_exp_len-LANE
+multi_fn = vbfmlalt-out-noext, a, b, {simd_shuffle!, c, c, {dup-in_len-LANE as u32}}
+a = 1.0, 2.0, 3.0, 4.0
+b = 0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100
+c = 0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100
+n = 0
+validate 3.0, 6.0, 9.0, 12.0
+target = bf16
+
+aarch64 = bfmlalt
+generate float32x4_t:bfloat16x8_t:bfloat16x4_t:float32x4_t, float32x4_t:bfloat16x8_t:bfloat16x8_t:float32x4_t
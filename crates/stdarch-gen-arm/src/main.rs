@@ -42,17 +42,15 @@ const INT_TYPES_64: [&str; 2] = ["int64x1_t", "int64x2_t"];
 const FLOAT_TYPES: [&str; 2] = [
     //"float8x8_t", not supported by rust
     //"float8x16_t", not supported by rust
-    //"float16x4_t", not supported by rust
-    //"float16x8_t", not supported by rust
     "float32x2_t",
     "float32x4_t",
 ];
 
+const FLOAT_TYPES_16: [&str; 2] = ["float16x4_t", "float16x8_t"];
+
 const FLOAT_TYPES_64: [&str; 2] = [
     //"float8x8_t", not supported by rust
     //"float8x16_t", not supported by rust
-    //"float16x4_t", not supported by rust
-    //"float16x8_t", not supported by rust
     "float64x1_t",
     "float64x2_t",
 ];
@@ -94,7 +92,8 @@ fn type_bits(t: &str) -> usize {
         "int8x8_t" | "int8x16_t" | "uint8x8_t" | "uint8x16_t" | "poly8x8_t" | "poly8x16_t"
         | "i8" | "u8" => 8,
         "int16x4_t" | "int16x8_t" | "uint16x4_t" | "uint16x8_t" | "poly16x4_t" | "poly16x8_t"
-        | "i16" | "u16" => 16,
+        | "i16" | "u16" | "float16x4_t" | "float16x8_t" | "f16" | "bfloat16x4_t"
+        | "bfloat16x8_t" => 16,
         "int32x2_t" | "int32x4_t" | "uint32x2_t" | "uint32x4_t" | "i32" | "u32" | "float32x2_t"
         | "float32x4_t" | "f32" => 32,
         "int64x1_t" | "int64x2_t" | "uint64x1_t" | "uint64x2_t" | "poly64x1_t" | "poly64x2_t"
@@ -149,6 +148,8 @@ fn type_to_suffix(t: &str) -> &str {
         "uint64x2_t" => "q_u64",
         "float16x4_t" => "_f16",
         "float16x8_t" => "q_f16",
+        "bfloat16x4_t" => "_bf16",
+        "bfloat16x8_t" => "q_bf16",
         "float32x2_t" => "_f32",
         "float32x4_t" => "q_f32",
         "float64x1_t" => "_f64",
@@ -245,6 +246,7 @@ fn type_to_suffix(t: &str) -> &str {
         "u16" => "h_u16",
         "u32" => "s_u32",
         "u64" => "d_u64",
+        "f16" => "h_f16",
         "f32" => "s_f32",
         "f64" => "d_f64",
         "p8" => "b_p8",
@@ -365,6 +367,14 @@ fn type_to_rot_suffix(c_name: &str, suf: &str) -> String {
     }
 }
 
+fn type_to_rot_lane_suffix(c_name: &str, out_t: &str, in_t: &str) -> String {
+    let ns: Vec<_> = c_name.split('_').collect();
+    assert_eq!(ns.len(), 2);
+    let lane = type_to_lane_suffixes(out_t, in_t, false);
+    let (lane, elem) = lane.rsplit_once('_').unwrap();
+    format!("{}{lane}_{}_{elem}", ns[0], ns[1])
+}
+
 fn type_to_signed(t: &str) -> String {
     t.replace("uint", "int").replace("poly", "int")
 }
@@ -422,7 +432,8 @@ fn type_to_noq_suffix(t: &str) -> &str {
         "uint16x4_t" | "uint16x8_t" | "u16" => "_u16",
         "uint32x2_t" | "uint32x4_t" | "u32" => "_u32",
         "uint64x1_t" | "uint64x2_t" | "u64" => "_u64",
-        "float16x4_t" | "float16x8_t" => "_f16",
+        "float16x4_t" | "float16x8_t" | "f16" => "_f16",
+        "bfloat16x4_t" | "bfloat16x8_t" => "_bf16",
         "float32x2_t" | "float32x4_t" | "f32" => "_f32",
         "float64x1_t" | "float64x2_t" | "f64" => "_f64",
         "poly8x8_t" | "poly8x16_t" => "_p8",
@@ -456,6 +467,8 @@ enum Suffix {
     OutLane,
     Rot,
     RotLane,
+    In1Rot,
+    In1RotLane,
 }
 
 #[derive(Clone, Copy)]
@@ -472,6 +485,9 @@ enum TargetFeature {
     Rdm,
     Sm4,
     Ftts,
+    Fp16,
+    Fhm,
+    Bf16,
 }
 
 impl TargetFeature {
@@ -492,6 +508,9 @@ impl TargetFeature {
             Self::Rdm => "rdm",
             Self::Sm4 => "neon,sm4",
             Self::Ftts => "neon,frintts",
+            Self::Fp16 => "neon,fp16",
+            Self::Fhm => "neon,fp16,fhm",
+            Self::Bf16 => "neon,bf16",
         }
     }
 
@@ -511,11 +530,15 @@ impl TargetFeature {
             Self::Fcma => "neon,v8,fcma",
             Self::Dotprod => "neon,v8,dotprod",
             Self::I8MM => "neon,v8,i8mm",
+            Self::Fp16 => "neon,v8,fp16",
             // Features not supported on 32-bit "arm".
             Self::Sha3 => unimplemented!(),
             Self::Rdm => unimplemented!(),
             Self::Sm4 => unimplemented!(),
             Self::Ftts => unimplemented!(),
+            Self::Fhm => unimplemented!(),
+            // rustc does not support the "bf16" target feature on 32-bit "arm" yet.
+            Self::Bf16 => unimplemented!(),
         }
     }
 
@@ -536,6 +559,8 @@ impl TargetFeature {
             Rdm => "stable(feature = \"rdm_intrinsics\", since = \"1.62.0\")",
             Sm4 => "unstable(feature = \"stdarch_neon_sm4\", issue = \"117226\")",
             Ftts => "unstable(feature = \"stdarch_neon_ftts\", issue = \"117227\")",
+            Fp16 | Fhm => "unstable(feature = \"stdarch_neon_f16\", issue = \"136306\")",
+            Bf16 => "unstable(feature = \"stdarch_neon_bf16\", issue = \"none\")",
         }
     }
 
@@ -563,12 +588,15 @@ impl TargetFeature {
             Self::Fcma => "neon,fcma",
             Self::Dotprod => "neon,dotprod",
             Self::I8MM => "neon,i8mm",
+            Self::Fp16 => "neon,fp16",
 
             // Features not supported on 32-bit "arm".
             Self::Sha3 => unimplemented!(),
             Self::Rdm => unimplemented!(),
             Self::Sm4 => unimplemented!(),
             Self::Ftts => unimplemented!(),
+            Self::Fhm => unimplemented!(),
+            Self::Bf16 => unimplemented!(),
         }
     }
 
@@ -720,6 +748,8 @@ fn type_to_global_type(t: &str) -> &str {
         "uint64x2_t" | "uint64x2x2_t" | "uint64x2x3_t" | "uint64x2x4_t" => "u64x2",
         "float16x4_t" => "f16x4",
         "float16x8_t" => "f16x8",
+        "bfloat16x4_t" => "u16x4",
+        "bfloat16x8_t" => "u16x8",
         "float32x2_t" | "float32x2x2_t" | "float32x2x3_t" | "float32x2x4_t" => "f32x2",
         "float32x4_t" | "float32x4x2_t" | "float32x4x3_t" | "float32x4x4_t" => "f32x4",
         "float64x1_t" | "float64x1x2_t" | "float64x1x3_t" | "float64x1x4_t" => "f64",
@@ -738,6 +768,7 @@ fn type_to_global_type(t: &str) -> &str {
         "u16" => "u16",
         "u32" => "u32",
         "u64" => "u64",
+        "f16" => "f16",
         "f32" => "f32",
         "f64" => "f64",
         "p8" => "p8",
@@ -769,6 +800,7 @@ fn type_to_native_type(t: &str) -> String {
             "int" => format!("i{}", &s[0][3..]),
             "uin" => format!("u{}", &s[0][4..]),
             "flo" => format!("f{}", &s[0][5..]),
+            "bfl" => format!("bf{}", &s[0][6..]),
             "pol" => format!("u{}", &s[0][4..]),
             _ => panic!("unknown type: {t}"),
         },
@@ -822,6 +854,7 @@ fn type_to_half(t: &str) -> &str {
         "uint64x2_t" => "uint64x1_t",
         "poly8x16_t" => "poly8x8_t",
         "poly16x8_t" => "poly16x4_t",
+        "float16x8_t" => "float16x4_t",
         "float32x4_t" => "float32x2_t",
         "float64x2_t" => "float64x1_t",
         _ => panic!("unknown half type for {t}"),
@@ -833,7 +866,8 @@ fn type_with_merged_lanes(t: &str, elements_per_lane: usize) -> String {
     let prefix_len = t.find(|c: char| c.is_ascii_digit()).unwrap_or(t.len());
     format!(
         "{prefix}{bits}x{len}_t",
-        prefix = &t[0..prefix_len],
+        // Pairs of bfloat16 elements are accessed as single-precision lanes.
+        prefix = t[0..prefix_len].trim_start_matches('b'),
         bits = type_bits(t) * elements_per_lane,
         len = type_len(t) / elements_per_lane
     )
@@ -955,6 +989,7 @@ fn max_val(t: &str) -> &'static str {
         "i16" => "0x7F_FF",
         "i32" => "0x7F_FF_FF_FF",
         "i64" => "0x7F_FF_FF_FF_FF_FF_FF_FF",
+        "f16" => "65504.0",
         "f32" => "3.40282347e+38",
         "f64" => "1.7976931348623157e+308",
         _ => panic!("No TRUE for type {t}"),
@@ -971,6 +1006,7 @@ fn min_val(t: &str) -> &'static str {
         "i16" => "-32768",
         "i32" => "-2147483648",
         "i64" => "-9223372036854775808",
+        "f16" => "-65504.0",
         "f32" => "-3.40282347e+38",
         "f64" => "-1.7976931348623157e+308",
         _ => panic!("No TRUE for type {t}"),
@@ -1187,7 +1223,7 @@ fn type_to_ext(t: &str, v: bool, r: bool, pi8: bool) -> String {
         _ => format!(".p0{native}"),
     };
     let sub_type = match &native[0..1] {
-        "i" | "f" => native,
+        "i" | "f" | "b" => native,
         "u" => native.replace('u', "i"),
         _ => panic!("unknown type: {t}"),
     };
@@ -1331,6 +1367,8 @@ fn gen_aarch64(
         ),
         Rot => type_to_rot_suffix(current_name, type_to_suffix(out_t)),
         RotLane => type_to_rot_suffix(current_name, &type_to_lane_suffixes(out_t, in_t[2], false)),
+        In1Rot => type_to_rot_suffix(current_name, type_to_suffix(in_t[1])),
+        In1RotLane => type_to_rot_lane_suffix(current_name, out_t, in_t[2]),
     };
     let current_fn = if let Some(current_fn) = current_fn.clone() {
         if link_aarch64.is_some() {
@@ -1997,6 +2035,8 @@ fn gen_arm(
         ),
         Rot => type_to_rot_suffix(current_name, type_to_suffix(out_t)),
         RotLane => type_to_rot_suffix(current_name, &type_to_lane_suffixes(out_t, in_t[2], false)),
+        In1Rot => type_to_rot_suffix(current_name, type_to_suffix(in_t[1])),
+        In1RotLane => type_to_rot_lane_suffix(current_name, out_t, in_t[2]),
     };
     let current_aarch64 = current_aarch64
         .clone()
@@ -2850,6 +2890,7 @@ fn get_call(
             "in2_exp_len" => type_exp_len(in_t[2], 1),
             "in2_rot" => type_exp_len(in_t[2], 2),
             "in2_dot" => type_exp_len(in_t[2], 4),
+            "in2_bfdot" => type_exp_len(in_t[2], 2),
             _ => 0,
         };
         return if len == 0 {
@@ -3009,6 +3050,8 @@ fn get_call(
                 re = Some((re_params[0].clone(), type_to_signed(out_t).to_string()));
             } else if re_params[1] == "merge4_t2" {
                 re = Some((re_params[0].clone(), type_with_merged_lanes(in_t[2], 4)));
+            } else if re_params[1] == "merge2_t2" {
+                re = Some((re_params[0].clone(), type_with_merged_lanes(in_t[2], 2)));
             } else if re_params[1] == "half" {
                 re = Some((re_params[0].clone(), type_to_half(in_t[1]).to_string()));
             } else if re_params[1] == "in_ntt" {
@@ -3143,6 +3186,8 @@ fn get_call(
             fn_name.push_str(type_to_suffix(native_type_to_type(out_t)));
         } else if fn_format[1] == "rot" {
             fn_name = type_to_rot_suffix(&fn_name, type_to_suffix(out_t));
+        } else if fn_format[1] == "in1rot" {
+            fn_name = type_to_rot_suffix(&fn_name, type_to_suffix(in_t[1]));
         } else {
             fn_name.push_str(&fn_format[1]);
         };
@@ -3352,6 +3397,10 @@ mod test {
             suffix = Rot;
         } else if line.starts_with("rot-lane-suffixes") {
             suffix = RotLane;
+        } else if line.starts_with("in1-rot-suffix") {
+            suffix = In1Rot;
+        } else if line.starts_with("in1-rot-lane-suffixes") {
+            suffix = In1RotLane;
         } else if let Some(line) = line.strip_prefix("a = ") {
             a = line.split(',').map(|v| v.trim().to_string()).collect();
         } else if let Some(line) = line.strip_prefix("b = ") {
@@ -3393,6 +3442,9 @@ mod test {
                     "rdm" => Rdm,
                     "sm4" => Sm4,
                     "frintts" => Ftts,
+                    "fp16" => Fp16,
+                    "fhm" => Fhm,
+                    "bf16" => Bf16,
                     _ => Default,
                 },
                 _ => Default,
@@ -3409,6 +3461,7 @@ mod test {
                     "int*_t" => INT_TYPES.iter().map(|v| v.to_string()).collect(),
                     "int64x*_t" => INT_TYPES_64.iter().map(|v| v.to_string()).collect(),
                     "float*_t" => FLOAT_TYPES.iter().map(|v| v.to_string()).collect(),
+                    "float16x*_t" => FLOAT_TYPES_16.iter().map(|v| v.to_string()).collect(),
                     "float64x*_t" => FLOAT_TYPES_64.iter().map(|v| v.to_string()).collect(),
                     _ => vec![v],
                 })
@@ -202,6 +202,8 @@ fn to_type(t: &syn::Type) -> proc_macro2::TokenStream {
             "_MM_MANTISSA_NORM_ENUM" => quote! { &MM_MANTISSA_NORM_ENUM },
             "_MM_MANTISSA_SIGN_ENUM" => quote! { &MM_MANTISSA_SIGN_ENUM },
             "_MM_PERM_ENUM" => quote! { &MM_PERM_ENUM },
+            "_CMPCCX_ENUM" => quote! { &CMPCCX_ENUM },
+            "__tile1024i" => quote! { &TILE1024I },
             "bool" => quote! { &BOOL },
             "bf16" => quote! { &BF16 },
             "f16" => quote! { &F16 },
@@ -290,6 +292,10 @@ fn to_type(t: &syn::Type) -> proc_macro2::TokenStream {
             "uint64x2x2_t" => quote! { &U64X2X2 },
             "uint64x2x3_t" => quote! { &U64X2X3 },
             "uint64x2x4_t" => quote! { &U64X2X4 },
+            "bfloat16x4_t" => quote! { &BF16X4 },
+            "bfloat16x8_t" => quote! { &BF16X8 },
+            "float16x4_t" => quote! { &F16X4 },
+            "float16x8_t" => quote! { &F16X8 },
             "float32x2_t" => quote! { &F32X2 },
             "float32x2x2_t" => quote! { &F32X2X2 },
             "float32x2x3_t" => quote! { &F32X2X3 },
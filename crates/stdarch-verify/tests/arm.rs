@@ -28,9 +28,12 @@ static U32: Type = Type::PrimUnsigned(32);
 static U64: Type = Type::PrimUnsigned(64);
 static U8: Type = Type::PrimUnsigned(8);
 static NEVER: Type = Type::Never;
+static BOOL: Type = Type::Bool;
 static GENERICT: Type = Type::GenericParam("T");
 static GENERICU: Type = Type::GenericParam("U");
 
+static BF16X4: Type = Type::BF(16, 4, 1);
+static BF16X8: Type = Type::BF(16, 8, 1);
 static F16X4: Type = Type::F(16, 4, 1);
 static F16X4X2: Type = Type::F(16, 4, 2);
 static F16X4X3: Type = Type::F(16, 4, 3);
@@ -164,7 +167,9 @@ enum Type {
     U(u8, u8, u8),
     P(u8, u8, u8),
     F(u8, u8, u8),
+    BF(u8, u8, u8),
     Never,
+    Bool,
 }
 
 stdarch_verify::arm_functions!(static FUNCTIONS);
@@ -232,6 +237,8 @@ fn verify_all_signatures() {
                 "vcombine_u32",
                 "vcombine_u64",
                 "vcombine_p64",
+                "vcombine_f16",
+                "vcombine_bf16",
                 "vcombine_f32",
                 "vcombine_p8",
                 "vcombine_p16",
@@ -460,6 +467,8 @@ fn verify_all_signatures() {
                     && !rust.file.ends_with("v8.rs\"")
                     && !rust.file.ends_with("tme.rs\"")
                     && !rust.file.ends_with("mte.rs\"")
+                    && !rust.file.ends_with("sve.rs\"")
+                    && !rust.file.ends_with("sme.rs\"")
                     && !rust.file.ends_with("ex.rs\"")
                     && !skip_intrinsic_verify.contains(&rust.name)
                 {
@@ -482,6 +491,37 @@ fn verify_all_signatures() {
     assert!(all_valid);
 }
 
+#[test]
+fn verify_sme_signatures() {
+    // The SME intrinsics are not part of `arm_intrinsics.json` yet, so the
+    // prototypes of those that only take and return scalars are listed here.
+    // Reference: https://arm-software.github.io/acle/main/acle.html#sme-language-extensions-and-intrinsics
+    let json = r#"[
+        {"name": "__arm_in_streaming_mode", "arguments": [], "return_type": {"value": "bool"}, "instructions": [["MRS"]]},
+        {"name": "svcntsb", "arguments": [], "return_type": {"value": "uint64_t"}, "instructions": [["RDSVL"]]},
+        {"name": "svcntsh", "arguments": [], "return_type": {"value": "uint64_t"}, "instructions": [["RDSVL"]]},
+        {"name": "svcntsw", "arguments": [], "return_type": {"value": "uint64_t"}, "instructions": [["RDSVL"]]},
+        {"name": "svcntsd", "arguments": [], "return_type": {"value": "uint64_t"}, "instructions": [["RDSVL"]]}
+    ]"#;
+    let intrinsics: Vec<JsonIntrinsic> = serde_json::from_str(json).unwrap();
+    let map = parse_intrinsics(intrinsics);
+
+    let mut all_valid = true;
+    for (name, arm) in &map {
+        let Some(rust) = FUNCTIONS.iter().find(|f| f.name == name) else {
+            println!("missing rust definition for {name:?}");
+            all_valid = false;
+            continue;
+        };
+        if let Err(e) = matches(rust, arm) {
+            println!("failed to verify `{}`", rust.name);
+            println!("  * {e}");
+            all_valid = false;
+        }
+    }
+    assert!(all_valid);
+}
+
 fn matches(rust: &Function, arm: &Intrinsic) -> Result<(), String> {
     if rust.ret != arm.ret.as_ref() {
         bail!("mismatched return value")
@@ -624,6 +664,9 @@ fn parse_ty(s: &str) -> Type {
 
 fn parse_ty_base(s: &str) -> &'static Type {
     match s {
+        "bfloat16x4_t" => &BF16X4,
+        "bfloat16x8_t" => &BF16X8,
+        "bool" => &BOOL,
         "float16_t" => &F16,
         "float16x4_t" => &F16X4,
         "float16x4x2_t" => &F16X4X2,
@@ -39,6 +39,7 @@ static U64: Type = Type::PrimUnsigned(64);
 static U128: Type = Type::PrimUnsigned(128);
 static USIZE: Type = Type::PrimUnsigned(SS);
 static ORDERING: Type = Type::Ordering;
+static BOOL: Type = Type::Bool;
 
 static M128: Type = Type::M128;
 static M128BH: Type = Type::M128BH;
@@ -63,6 +64,8 @@ static MM_CMPINT_ENUM: Type = Type::MM_CMPINT_ENUM;
 static MM_MANTISSA_NORM_ENUM: Type = Type::MM_MANTISSA_NORM_ENUM;
 static MM_MANTISSA_SIGN_ENUM: Type = Type::MM_MANTISSA_SIGN_ENUM;
 static MM_PERM_ENUM: Type = Type::MM_PERM_ENUM;
+static CMPCCX_ENUM: Type = Type::CMPCCX_ENUM;
+static TILE1024I: Type = Type::TILE1024I;
 
 static TUPLE: Type = Type::Tuple;
 static CPUID: Type = Type::CpuidResult;
@@ -99,10 +102,13 @@ enum Type {
     MM_MANTISSA_NORM_ENUM,
     MM_MANTISSA_SIGN_ENUM,
     MM_PERM_ENUM,
+    CMPCCX_ENUM,
+    TILE1024I,
     Tuple,
     CpuidResult,
     Never,
     Ordering,
+    Bool,
 }
 
 stdarch_verify::x86_functions!(static FUNCTIONS);
@@ -178,6 +184,15 @@ fn verify_all_signatures() {
 
     let mut all_valid = true;
     'outer: for rust in FUNCTIONS {
+        // XOP and FMA4 are AMD-specific and are not listed with intel. No
+        // current processor supports them, so most of their intrinsics are
+        // only checked with `assert_instr` and have no run-time test.
+        if let Some(feature) = rust.target_feature {
+            if feature.contains("xop") || feature.contains("fma4") {
+                continue;
+            }
+        }
+
         if !rust.has_test {
             // FIXME: this list should be almost empty
             let skip = [
@@ -279,6 +294,31 @@ fn verify_all_signatures() {
                 "_mm_cvtsi64x_sd",
                 "_bextr2_u64",
                 "_mm_tzcnt_64",
+                // Privileged, loads the Key Locker internal wrapping key
+                "_mm_loadiwkey",
+                // CET_SS: these need a shadow stack set up by the OS, or are
+                // privileged
+                "_saveprevssp",
+                "_rstorssp",
+                "_wrssd",
+                "_wrssq",
+                "_wrussd",
+                "_wrussq",
+                "_setssbsy",
+                "_clrssbsy",
+                // MONITOR/MWAIT are usually only available at privilege level 0
+                "_mm_monitor",
+                "_mm_mwait",
+                // ENQCMD needs a device work queue
+                "_enqcmd",
+                "_enqcmds",
+                // HRESET can only be executed at privilege level 0
+                "_hreset",
+                // SENDUIPI needs a user-interrupt target table set up by the OS
+                "_senduipi",
+                // USER_MSR needs the OS to allow access to the MSR
+                "_urdmsr",
+                "_uwrmsr",
             ];
             if !skip.contains(&rust.name) {
                 println!(
@@ -307,7 +347,14 @@ fn verify_all_signatures() {
             "__cpuid" |
             "__get_cpuid_max" |
             // Not listed with intel, but manually verified
-            "cmpxchg16b"
+            "cmpxchg16b" |
+            // Not an intrinsic, requests the AMX tile data state from Linux
+            "_tile_request_permission" |
+            // Not an intrinsic, computes a deadline for `_umwait`/`_tpause`
+            "_tsc_deadline" |
+            // AMD-specific, not listed with intel
+            "_mm_monitorx" |
+            "_mm_mwaitx"
             => continue,
             // Intel requires the mask argument for _mm_shuffle_ps to be an
             // unsigned integer, but all other _mm_shuffle_.. intrinsics
@@ -461,6 +508,33 @@ fn matches(rust: &Function, intel: &Intrinsic) -> Result<(), String> {
             continue;
         }
 
+        // These aren't target features known to rustc, so their intrinsics
+        // are implemented with inline assembly without a `#[target_feature]`
+        // attribute.
+        if matches!(
+            &cpuid[..],
+            "CET_SS"
+                | "WAITPKG"
+                | "MOVDIRI"
+                | "MOVDIR64B"
+                | "ENQCMD"
+                | "CLFLUSHOPT"
+                | "CLWB"
+                | "CLDEMOTE"
+                | "PREFETCHI"
+                | "CMPCCXADD"
+                | "RAO_INT"
+                | "FSGSBASE"
+                | "RDPID"
+                | "SERIALIZE"
+                | "HRESET"
+                | "TSXLDTRK"
+                | "UINTR"
+                | "USER_MSR"
+        ) {
+            continue;
+        }
+
         // Some CPUs support VAES/GFNI/VPCLMULQDQ without AVX512, even though
         // the Intel documentation states that those instructions require
         // AVX512VL.
@@ -498,6 +572,9 @@ fn matches(rust: &Function, intel: &Intrinsic) -> Result<(), String> {
             // The XML file names FP16 as "avx512_fp16", while Rust calls
             // it "avx512fp16".
             "avx512_fp16" => String::from("avx512fp16"),
+            // The XML file names VP2INTERSECT as "avx512_vp2intersect", while
+            // Rust calls it "avx512vp2intersect".
+            "avx512_vp2intersect" => String::from("avx512vp2intersect"),
             // The XML file names AVX-VNNI as "avx_vnni", while Rust calls
             // it "avxvnni"
             "avx_vnni" => String::from("avxvnni"),
@@ -513,6 +590,15 @@ fn matches(rust: &Function, intel: &Intrinsic) -> Result<(), String> {
             // The XML file names AVX-VNNI_INT16 as "avx_vnni_int16", while Rust calls
             // it "avxvnniint16"
             "avx_vnni_int16" => String::from("avxvnniint16"),
+            // The XML file names Key Locker as "keylocker", while Rust calls
+            // it "kl"
+            "keylocker" => String::from("kl"),
+            // The XML file names Key Locker Wide as "keylocker_wide", while Rust calls
+            // it "widekl"
+            "keylocker_wide" => String::from("widekl"),
+            // The XML file names MONITOR/MWAIT as "monitor", while LLVM and
+            // Rust consider them part of "sse3"
+            "monitor" => String::from("sse3"),
             _ => cpuid,
         };
         let fixed_cpuid = fixup_cpuid(cpuid);
@@ -572,6 +658,21 @@ fn matches(rust: &Function, intel: &Intrinsic) -> Result<(), String> {
         if intel.parameters[0].type_ != "void" {
             bail!("rust has 0 arguments, intel has one for")
         }
+    } else if intel.parameters.len() == 1 && intel.parameters[0].type_ == "_Float16 _Complex" {
+        // Rust has no complex type, so the `set1_pch` intrinsics take the real
+        // and imaginary parts of their argument separately.
+        if rust.arguments.len() != 2 {
+            bail!("wrong number of arguments on {}", rust.name);
+        }
+        for a in rust.arguments {
+            equate(
+                a,
+                "_Float16",
+                &intel.parameters[0].etype,
+                &intel.name,
+                false,
+            )?;
+        }
     } else {
         // Otherwise we want all parameters to be exactly the same
         if rust.arguments.len() != intel.parameters.len() {
@@ -666,6 +767,13 @@ fn matches(rust: &Function, intel: &Intrinsic) -> Result<(), String> {
         // more info.
         "_rdtsc" | "__rdtscp" => true,
 
+        // This returns a `usize`, which matches both the 32-bit and the 64-bit
+        // version of the intrinsic.
+        "_get_ssp" => true,
+
+        // The deadline is passed in `edx:eax`, so these work on 32-bit too.
+        "_umwait" | "_tpause" => true,
+
         _ => false,
     };
     if any_i64 && !any_i64_exempt && !rust.file.contains("x86_64") {
@@ -825,6 +933,10 @@ fn equate(
         (&Type::MM_MANTISSA_NORM_ENUM, "_MM_MANTISSA_NORM_ENUM") => {}
         (&Type::MM_MANTISSA_SIGN_ENUM, "_MM_MANTISSA_SIGN_ENUM") => {}
         (&Type::MM_PERM_ENUM, "_MM_PERM_ENUM") => {}
+        (&Type::CMPCCX_ENUM, "_CMPCCX_ENUM" | "const int") => {}
+
+        (&Type::TILE1024I, "__tile1024i") => {}
+        (&Type::MutPtr(&Type::TILE1024I), "__tile1024i*") => {}
 
         // This is a macro (?) in C which seems to mutate its arguments, but
         // that means that we're taking pointers to arguments in rust